@@ -1,3 +1,5 @@
+use crate::signals::{Emitter, Signal};
+
 /// The context of the Craby Module.
 pub struct Context {
     /// This is a unique identifier(pointer address) for the current TurboModule instance.
@@ -18,4 +20,9 @@ impl Context {
             data_path: data_path.to_string(),
         }
     }
+
+    /// Returns the emitter of the module's signals that can be moved to the other threads.
+    pub fn emitter<S: Signal>(&self) -> Emitter<S> {
+        Emitter::new(self.id)
+    }
 }
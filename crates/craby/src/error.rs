@@ -0,0 +1,106 @@
+/// Marks the start of the encoded `Error` in the FFI error message.
+const ENCODED_PREFIX: char = '\u{1e}';
+/// Separates the fields of the encoded `Error` (code, details and message).
+const ENCODED_SEPARATOR: char = '\u{1f}';
+
+/// Error code of the `Error`.
+///
+/// Implemented for the string enums of the `@throws` tag, and the string types.
+///
+/// ```typescript
+/// export enum DownloadError {
+///   NotFound = 'NOT_FOUND',
+///   Timeout = 'TIMEOUT',
+/// }
+///
+/// /**
+///  * @throws DownloadError
+///  */
+/// download(url: string): Promise<void>;
+/// ```
+pub trait ErrorCode {
+    /// Returns the code that is set to the `code` property of the JavaScript error.
+    fn code(&self) -> &str;
+}
+
+impl ErrorCode for &str {
+    fn code(&self) -> &str {
+        self
+    }
+}
+
+impl ErrorCode for String {
+    fn code(&self) -> &str {
+        self
+    }
+}
+
+/// Error that is delivered to JavaScript with the error code.
+///
+/// The Promise is rejected with the JavaScript `Error` that has the `code` and `details` (if exist) properties.
+///
+/// ```rust,ignore
+/// fn download(&mut self, url: &str) -> Promise<Void> {
+///     Err(Error::new(DownloadError::NotFound, "File not found")
+///         .with_details(url)
+///         .into())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub code: String,
+    pub message: String,
+    pub details: Option<String>,
+}
+
+impl Error {
+    /// Creates a new `Error` with the error code and message.
+    pub fn new(code: impl ErrorCode, message: impl AsRef<str>) -> Self {
+        Error {
+            code: code.code().to_string(),
+            message: message.as_ref().to_string(),
+            details: None,
+        }
+    }
+
+    /// Sets the details of the error.
+    pub fn with_details(mut self, details: impl AsRef<str>) -> Self {
+        self.details = Some(details.as_ref().to_string());
+        self
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.code, self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Encodes the `Error` into the FFI error message that is decoded by the C++ side.
+///
+/// The other errors are returned as is.
+///
+/// ```text
+/// \x1e{code}\x1f{details}\x1f{message}
+/// ```
+#[doc(hidden)]
+pub fn encode(err: anyhow::Error) -> anyhow::Error {
+    let Some(Error {
+        code,
+        message,
+        details,
+    }) = err.downcast_ref::<Error>()
+    else {
+        return err;
+    };
+
+    // The separator is not allowed in the code and details (the message is the last field)
+    let strip = |s: &str| s.replace(ENCODED_SEPARATOR, "");
+    anyhow::anyhow!(
+        "{ENCODED_PREFIX}{code}{ENCODED_SEPARATOR}{details}{ENCODED_SEPARATOR}{message}",
+        code = strip(code),
+        details = details.as_deref().map(strip).unwrap_or_default(),
+    )
+}
@@ -4,12 +4,18 @@ pub mod macros;
 /// This module provides the prelude for Craby Modules.
 pub mod prelude {
     pub use crate::context::*;
+    pub use crate::error::Error;
     pub use crate::types::*;
     pub use craby_macro::craby_module;
 }
 
 pub mod context;
+pub mod error;
+pub mod panic;
+pub mod signals;
 pub mod types;
 
+pub use error::Error;
+
 // craby_marco crate
 pub use craby_macro;
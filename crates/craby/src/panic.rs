@@ -0,0 +1,46 @@
+use std::{
+    any::Any,
+    sync::{Mutex, Once},
+};
+
+static HOOK: Once = Once::new();
+static LAST_MESSAGE: Mutex<Option<String>> = Mutex::new(None);
+
+/// Returns the message of the panic payload.
+#[doc(hidden)]
+pub fn message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        (*s).to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "Unknown panic occurred".to_string()
+    }
+}
+
+/// Sets the panic hook for the `panic = "abort"` mode.
+///
+/// The hook records the panic message and reports it (to JavaScript) before the process is aborted.
+/// The previous hook is called after the report. The hook is set only once.
+#[doc(hidden)]
+pub fn set_hook(report: fn(&str)) {
+    HOOK.call_once(|| {
+        let prev_hook = std::panic::take_hook();
+
+        std::panic::set_hook(Box::new(move |info| {
+            let message = message(info.payload());
+
+            if let Ok(mut last_message) = LAST_MESSAGE.lock() {
+                *last_message = Some(message.clone());
+            }
+
+            report(&message);
+            prev_hook(info);
+        }));
+    });
+}
+
+/// Returns the message of the last panic recorded by the panic hook.
+pub fn last_message() -> Option<String> {
+    LAST_MESSAGE.lock().ok().and_then(|message| message.clone())
+}
@@ -0,0 +1,101 @@
+use std::{
+    collections::BTreeMap,
+    marker::PhantomData,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        RwLock,
+    },
+};
+
+/// Generation of the module registered with the id (pointer address of the TurboModule).
+///
+/// The id may be reused by the next TurboModule instance, so the emitters of the previous instance are
+/// distinguished by the generation (also checked by the `SignalManager` on the C++ side).
+static GENERATIONS: RwLock<BTreeMap<usize, u64>> = RwLock::new(BTreeMap::new());
+static NEXT_GENERATION: AtomicU64 = AtomicU64::new(1);
+
+/// Signal enum of the module.
+///
+/// Implemented for the generated signal enums (eg. `MyModuleSignal`).
+pub trait Signal {
+    /// Emits the signal to the TurboModule of the id (dropped if the generation does not match).
+    #[doc(hidden)]
+    fn emit_to(self, id: usize, generation: u64);
+}
+
+/// Registers the new generation of the module id and returns it.
+#[doc(hidden)]
+pub fn register(id: usize) -> u64 {
+    let generation = NEXT_GENERATION.fetch_add(1, Ordering::Relaxed);
+
+    if let Ok(mut generations) = GENERATIONS.write() {
+        generations.insert(id, generation);
+    }
+
+    generation
+}
+
+/// Unregisters the module id (called when the TurboModule is invalidated).
+#[doc(hidden)]
+pub fn unregister(id: usize) {
+    if let Ok(mut generations) = GENERATIONS.write() {
+        generations.remove(&id);
+    }
+}
+
+/// Returns the current generation of the module id (`0` if unregistered).
+#[doc(hidden)]
+pub fn generation(id: usize) -> u64 {
+    GENERATIONS
+        .read()
+        .ok()
+        .and_then(|generations| generations.get(&id).copied())
+        .unwrap_or_default()
+}
+
+/// Emitter handle that emits the signals without the module reference.
+///
+/// The emitter can be cloned and moved to the other threads.
+/// Emitting is a no-op once the module is unregistered (eg. the TurboModule is invalidated).
+///
+/// ```rust,ignore
+/// let emitter = self.emitter();
+///
+/// std::thread::spawn(move || {
+///     emitter.emit(MyModuleSignal::OnProgress(0.5));
+/// });
+/// ```
+pub struct Emitter<S> {
+    id: usize,
+    generation: u64,
+    _signal: PhantomData<fn(S)>,
+}
+
+impl<S: Signal> Emitter<S> {
+    /// Creates a new emitter for the module of the id.
+    pub fn new(id: usize) -> Self {
+        Emitter {
+            id,
+            generation: generation(id),
+            _signal: PhantomData,
+        }
+    }
+
+    /// Emits the signal to the module (dropped if the module is unregistered).
+    pub fn emit(&self, signal: S) {
+        if self.generation == 0 || generation(self.id) != self.generation {
+            return;
+        }
+
+        // The id may be reused in the meantime, so the generation is checked again on the C++ side
+        signal.emit_to(self.id, self.generation);
+    }
+}
+
+impl<S> Clone for Emitter<S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S> Copy for Emitter<S> {}
@@ -1,9 +1,23 @@
 pub type Boolean = bool;
 pub type Number = f64;
 pub type String = std::string::String;
+pub type BigInt = i64;
+pub type BigUint64 = u64;
 pub type ArrayBuffer = std::vec::Vec<u8>;
+pub type Int8Array = std::vec::Vec<i8>;
+pub type Uint8Array = std::vec::Vec<u8>;
+pub type Int16Array = std::vec::Vec<i16>;
+pub type Uint16Array = std::vec::Vec<u16>;
+pub type Int32Array = std::vec::Vec<i32>;
+pub type Uint32Array = std::vec::Vec<u32>;
+pub type Float32Array = std::vec::Vec<f32>;
+pub type Float64Array = std::vec::Vec<f64>;
+pub type BigInt64Array = std::vec::Vec<i64>;
+pub type BigUint64Array = std::vec::Vec<u64>;
 pub type Array<T> = std::vec::Vec<T>;
+pub type Map<T> = std::collections::HashMap<std::string::String, T>;
 pub type Promise<T> = std::result::Result<T, anyhow::Error>;
+pub type Result<T, E = anyhow::Error> = std::result::Result<T, E>;
 pub type Void = ();
 
 /// JavaScript-like Promise utilities.
@@ -68,3 +82,264 @@ impl<T> Nullable<T> {
         self.val
     }
 }
+
+/// JavaScript function passed as a method argument.
+///
+/// The handle can be cloned and sent to other threads. Each call is dispatched to the JavaScript thread,
+/// and the function is released when the last handle is dropped.
+///
+/// Multiple parameters are passed as a tuple.
+///
+/// ```typescript
+/// onProgress: (progress: number) => void;   // Callback<Number>
+/// onData: (id: number, data: string) => void; // Callback<(Number, String)>
+/// onDone: () => void;                        // Callback<()>
+/// ```
+pub struct Callback<T> {
+    f: std::sync::Arc<dyn Fn(T) + Send + Sync>,
+}
+
+impl<T> Callback<T> {
+    /// Creates a new `Callback` with the function that invokes the JavaScript function.
+    pub fn new(f: impl Fn(T) + Send + Sync + 'static) -> Self {
+        Callback {
+            f: std::sync::Arc::new(f),
+        }
+    }
+
+    /// Invokes the JavaScript function with the arguments.
+    ///
+    /// This does not block the current thread. The function is invoked asynchronously on the JavaScript thread.
+    pub fn call(&self, args: T) {
+        (self.f)(args)
+    }
+}
+
+impl<T> Clone for Callback<T> {
+    fn clone(&self) -> Self {
+        Callback { f: self.f.clone() }
+    }
+}
+
+/// Event of the stream that is delivered to the JavaScript async iterator.
+pub enum StreamEvent<T> {
+    /// Yields the item.
+    Item(T),
+    /// Completes the iteration.
+    Close,
+    /// Rejects the pending `next()` call with the error message.
+    Error(String),
+}
+
+/// JavaScript async iterator returned by the `AsyncIterable<T>` method.
+///
+/// The handle is passed as the trailing `stream` argument and can be cloned and sent to other threads.
+/// Items are buffered until the JavaScript side pulls them, and the stream is closed when the last handle is dropped.
+/// If the method panics (or the last handle is dropped by the panicking thread), the iteration is rejected with the error.
+///
+/// ```typescript
+/// readChunks(path: string): AsyncIterable<ArrayBuffer>; // fn read_chunks(&mut self, path: &str, stream: Stream<ArrayBuffer>)
+/// ```
+pub struct Stream<T> {
+    inner: std::sync::Arc<StreamInner<T>>,
+}
+
+struct StreamInner<T> {
+    f: Box<dyn Fn(StreamEvent<T>) -> bool + Send + Sync>,
+}
+
+impl<T> Drop for StreamInner<T> {
+    fn drop(&mut self) {
+        // Dropping the last handle while unwinding must not complete the iteration as if it succeeded
+        if std::thread::panicking() {
+            (self.f)(StreamEvent::Error(
+                "Stream is dropped by the panicking thread".to_string(),
+            ));
+        } else {
+            (self.f)(StreamEvent::Close);
+        }
+    }
+}
+
+impl<T> Stream<T> {
+    /// Creates a new `Stream` with the function that delivers the events to the JavaScript async iterator.
+    pub fn new(f: impl Fn(StreamEvent<T>) -> bool + Send + Sync + 'static) -> Self {
+        Stream {
+            inner: std::sync::Arc::new(StreamInner { f: Box::new(f) }),
+        }
+    }
+
+    /// Pushes the item to the stream.
+    ///
+    /// This blocks the current thread while the buffer is full (the JavaScript side is not consuming the items).
+    /// Returns `false` if the stream is already closed or the iteration is cancelled (eg. `break` in `for await...of`).
+    pub fn send(&self, item: T) -> bool {
+        (self.inner.f)(StreamEvent::Item(item))
+    }
+
+    /// Completes the iteration after the buffered items are consumed.
+    ///
+    /// Returns `false` if the stream is already closed.
+    pub fn close(&self) -> bool {
+        (self.inner.f)(StreamEvent::Close)
+    }
+
+    /// Rejects the iteration with the error message after the buffered items are consumed.
+    ///
+    /// Returns `false` if the stream is already closed.
+    pub fn error(&self, err: impl AsRef<str>) -> bool {
+        (self.inner.f)(StreamEvent::Error(err.as_ref().to_string()))
+    }
+}
+
+impl<T> Clone for Stream<T> {
+    fn clone(&self) -> Self {
+        Stream {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+/// Cancellation token of the JavaScript `AbortSignal` passed as a method argument.
+///
+/// The handle can be cloned and sent to other threads. It is cancelled when the JavaScript `AbortSignal` is aborted,
+/// and the pending Promise is rejected with an `AbortError` regardless of the method result.
+///
+/// The optional signal (`signal?: AbortSignal`) is never cancelled when it is omitted.
+///
+/// ```typescript
+/// download(url: string, signal?: AbortSignal): Promise<void>; // fn download(&mut self, url: &str, signal: AbortSignal) -> Promise<Void>
+/// ```
+#[derive(Clone, Default)]
+pub struct AbortSignal {
+    inner: std::sync::Arc<AbortSignalInner>,
+}
+
+#[derive(Default)]
+struct AbortSignalInner {
+    cancelled: std::sync::atomic::AtomicBool,
+    listeners: std::sync::Mutex<Vec<Box<dyn FnOnce() + Send>>>,
+}
+
+impl AbortSignal {
+    /// Creates a new `AbortSignal` that is not cancelled.
+    pub fn new() -> Self {
+        AbortSignal::default()
+    }
+
+    /// Returns `true` if the signal is cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.inner
+            .cancelled
+            .load(std::sync::atomic::Ordering::Acquire)
+    }
+
+    /// Registers the function that is called once when the signal is cancelled.
+    ///
+    /// The function is called immediately if the signal is already cancelled.
+    /// Otherwise it is called on the JavaScript thread, so it should not block.
+    pub fn on_cancel(&self, f: impl FnOnce() + Send + 'static) {
+        let mut listeners = self.inner.listeners.lock().unwrap();
+        if self.is_cancelled() {
+            drop(listeners);
+            f();
+        } else {
+            listeners.push(Box::new(f));
+        }
+    }
+
+    /// Cancels the signal and calls the registered functions.
+    ///
+    /// This is called when the JavaScript `AbortSignal` is aborted.
+    pub fn cancel(&self) {
+        let listeners = {
+            let mut listeners = self.inner.listeners.lock().unwrap();
+            if self
+                .inner
+                .cancelled
+                .swap(true, std::sync::atomic::Ordering::AcqRel)
+            {
+                return;
+            }
+            std::mem::take(&mut *listeners)
+        };
+        listeners.into_iter().for_each(|f| f());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    /// Delivered events of the stream (`None` for `Close`, the message for `Error`)
+    type Events = Arc<Mutex<Vec<Option<String>>>>;
+
+    fn recorded_stream() -> (Stream<i32>, Events) {
+        let events = Arc::new(Mutex::new(vec![]));
+        let recorded = events.clone();
+        let stream = Stream::new(move |event| {
+            let mut events = recorded.lock().unwrap();
+            // Ignores the events after the stream is closed (same as the C++ side)
+            if !events.is_empty() {
+                return false;
+            }
+            match event {
+                StreamEvent::Item(..) => return true,
+                StreamEvent::Close => events.push(None),
+                StreamEvent::Error(message) => events.push(Some(message)),
+            }
+            true
+        });
+
+        (stream, events)
+    }
+
+    #[test]
+    fn test_stream_close() {
+        let (stream, events) = recorded_stream();
+        let handle = stream.clone();
+
+        assert!(handle.send(1));
+        drop(handle);
+        drop(stream);
+
+        assert_eq!(*events.lock().unwrap(), vec![None]);
+    }
+
+    #[test]
+    fn test_stream_panic() {
+        let (stream, events) = recorded_stream();
+
+        // Same as the generated FFI function of the stream method
+        let ret = crate::catch_panic!({
+            let stream = stream.clone();
+            stream.send(1);
+            panic!("Boom");
+        })
+        .map_err(|err| {
+            stream.error(err.to_string());
+            err
+        });
+        drop(stream);
+
+        assert!(ret.is_err());
+        assert_eq!(*events.lock().unwrap(), vec![Some("Boom".to_string())]);
+    }
+
+    #[test]
+    fn test_stream_dropped_while_panicking() {
+        let (stream, events) = recorded_stream();
+        let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(move || {
+            let _stream = stream;
+            panic!("Boom");
+        }));
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![Some(
+                "Stream is dropped by the panicking thread".to_string()
+            )]
+        );
+    }
+}
@@ -51,6 +51,18 @@ pub fn crate_libs(config: &CompleteConfig, build_targets: &[Target]) -> Result<(
         replace_cxx_header(&signal_path)?;
     }
 
+    let callbacks_path = jni_base_path.join("include").join("CrabyCallbacks.h");
+    debug!("Post-processing CrabyCallbacks.h: {:?}", callbacks_path);
+    if callbacks_path.try_exists()? {
+        replace_cxx_header(&callbacks_path)?;
+    }
+
+    let streams_path = jni_base_path.join("include").join("CrabyStreams.h");
+    debug!("Post-processing CrabyStreams.h: {:?}", streams_path);
+    if streams_path.try_exists()? {
+        replace_cxx_header(&streams_path)?;
+    }
+
     let cxx_path = jni_base_path.join("include").join("cxx.h");
     debug!("Post-processing cxx.h: {:?}", cxx_path);
     if cxx_path.try_exists()? {
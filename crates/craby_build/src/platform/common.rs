@@ -2,11 +2,11 @@ use std::{fs, path::PathBuf};
 
 use log::debug;
 
-pub fn replace_cxx_header(signal_path: &PathBuf) -> Result<(), anyhow::Error> {
-    debug!("Replacing cxx header in: {:?}", signal_path);
-    let signals_h = fs::read_to_string(signal_path)?;
-    let signals_h = signals_h.replace("\"rust/cxx.h\"", "\"cxx.h\"");
-    fs::write(signal_path, signals_h)?;
+pub fn replace_cxx_header(header_path: &PathBuf) -> Result<(), anyhow::Error> {
+    debug!("Replacing cxx header in: {:?}", header_path);
+    let header = fs::read_to_string(header_path)?;
+    let header = header.replace("\"rust/cxx.h\"", "\"cxx.h\"");
+    fs::write(header_path, header)?;
     Ok(())
 }
 
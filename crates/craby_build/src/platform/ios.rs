@@ -82,6 +82,16 @@ pub fn crate_libs(config: &CompleteConfig, build_targets: &[Target]) -> Result<(
         replace_cxx_header(&signal_path)?;
     }
 
+    let callbacks_path = ios_base_path.join("include").join("CrabyCallbacks.h");
+    if callbacks_path.try_exists()? {
+        replace_cxx_header(&callbacks_path)?;
+    }
+
+    let streams_path = ios_base_path.join("include").join("CrabyStreams.h");
+    if streams_path.try_exists()? {
+        replace_cxx_header(&streams_path)?;
+    }
+
     let cxx_path = ios_base_path.join("include").join("cxx.h");
     if cxx_path.try_exists()? {
         replace_cxx_iter_template(&cxx_path)?;
@@ -65,6 +65,8 @@ pub fn perform(opts: CodegenOptions) -> anyhow::Result<()> {
         root: opts.project_root.clone(),
         schemas,
         android_package_name: config.android.package_name,
+        panic_strategy: config.project.panic,
+        thread_pool: config.thread_pool,
     };
 
     debug!("Cleaning up...");
@@ -1,12 +1,16 @@
-use std::{fs, path::PathBuf};
+use std::{
+    collections::{btree_map::Entry, BTreeMap},
+    fs,
+    path::PathBuf,
+};
 
 use craby_common::{constants::SPEC_FILE_PREFIX, utils::fs::collect_files};
 use log::debug;
 
 use crate::{
     parser::{
-        native_spec_parser::try_parse_schema,
-        types::ParseError,
+        native_spec_parser::try_parse_schema_file,
+        types::{ParseError, TypeAnnotation},
         utils::{render_report, RenderReportOptions},
     },
     types::Schema,
@@ -38,7 +42,7 @@ pub fn codegen<'a>(opts: CodegenOptions<'a>) -> Result<Vec<Schema>, anyhow::Erro
             let src = fs::read_to_string(path)?;
             let src = src.as_str();
 
-            match try_parse_schema(src) {
+            match try_parse_schema_file(path, src) {
                 Ok(schemas) => Ok(schemas),
                 Err(ParseError::Oxc { diagnostics }) => {
                     render_report(
@@ -51,6 +55,21 @@ pub fn codegen<'a>(opts: CodegenOptions<'a>) -> Result<Vec<Schema>, anyhow::Erro
                     );
                     anyhow::bail!("Failed to parse schema");
                 }
+                Err(ParseError::Import {
+                    path,
+                    src,
+                    diagnostics,
+                }) => {
+                    render_report(
+                        diagnostics,
+                        RenderReportOptions {
+                            project_root: opts.project_root,
+                            path: &path,
+                            src: &src,
+                        },
+                    );
+                    anyhow::bail!("Failed to parse schema");
+                }
                 Err(ParseError::General(e)) => {
                     anyhow::bail!(e);
                 }
@@ -60,8 +79,46 @@ pub fn codegen<'a>(opts: CodegenOptions<'a>) -> Result<Vec<Schema>, anyhow::Erro
 
     let mut schemas = collected_schemas.into_iter().flatten().collect::<Vec<_>>();
     schemas.sort_by_key(|v| v.module_name.to_lowercase());
+    try_assert_shared_types(&schemas)?;
 
     debug!("Collected schemas: {:?}", schemas);
 
     Ok(schemas)
 }
+
+/// Ensures that the types with the same name across the schemas (eg. imported from the shared module) have the same definition.
+///
+/// The shared types are emitted only once in the generated code.
+fn try_assert_shared_types(schemas: &[Schema]) -> Result<(), anyhow::Error> {
+    let mut types = BTreeMap::new();
+
+    for schema in schemas {
+        for type_annotation in schema
+            .aliases
+            .iter()
+            .chain(&schema.enums)
+            .chain(&schema.unions)
+        {
+            let name = match type_annotation {
+                TypeAnnotation::Object(obj) => &obj.name,
+                TypeAnnotation::Enum(enum_type) => &enum_type.name,
+                TypeAnnotation::Union(union_type) => &union_type.name,
+                _ => continue,
+            };
+
+            match types.entry(name) {
+                Entry::Vacant(e) => drop(e.insert(type_annotation.to_id())),
+                Entry::Occupied(e) if *e.get() != type_annotation.to_id() => {
+                    anyhow::bail!(
+                        "Type `{}` has conflicting definitions across the modules ({})",
+                        name,
+                        schema.module_name
+                    );
+                }
+                Entry::Occupied(_) => {}
+            }
+        }
+    }
+
+    Ok(())
+}
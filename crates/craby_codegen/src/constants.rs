@@ -8,12 +8,41 @@ pub mod specs {
     pub const REGISTRY_GET: &str = "get";
     pub const REGISTRY_GET_ENFORCING: &str = "getEnforcing";
 
+    pub const RESERVED_TYPE_ARRAY: &str = "Array";
     pub const RESERVED_TYPE_ARRAY_BUFFER: &str = "ArrayBuffer";
+    pub const RESERVED_TYPE_INT8_ARRAY: &str = "Int8Array";
+    pub const RESERVED_TYPE_UINT8_ARRAY: &str = "Uint8Array";
+    pub const RESERVED_TYPE_INT16_ARRAY: &str = "Int16Array";
+    pub const RESERVED_TYPE_UINT16_ARRAY: &str = "Uint16Array";
+    pub const RESERVED_TYPE_INT32_ARRAY: &str = "Int32Array";
+    pub const RESERVED_TYPE_UINT32_ARRAY: &str = "Uint32Array";
+    pub const RESERVED_TYPE_FLOAT32_ARRAY: &str = "Float32Array";
+    pub const RESERVED_TYPE_FLOAT64_ARRAY: &str = "Float64Array";
+    pub const RESERVED_TYPE_BIG_INT64_ARRAY: &str = "BigInt64Array";
+    pub const RESERVED_TYPE_BIG_UINT64_ARRAY: &str = "BigUint64Array";
+    pub const RESERVED_TYPE_BIG_UINT64: &str = "BigUint64";
     pub const RESERVED_TYPE_PROMISE: &str = "Promise";
+    pub const RESERVED_TYPE_RECORD: &str = "Record";
+    pub const RESERVED_TYPE_ASYNC_ITERABLE: &str = "AsyncIterable";
+    pub const RESERVED_TYPE_ABORT_SIGNAL: &str = "AbortSignal";
 
     /// `it_` is reserved for the `shared_ptr` of the module
     pub const RESERVED_ARG_NAME_MODULE: &str = "it_";
 
+    /// `stream` is reserved for the stream handle of the `AsyncIterable` methods
+    pub const RESERVED_ARG_NAME_STREAM: &str = "stream";
+
     /// `emit` is reserved for signals
     pub const RESERVED_METHOD_NAME_MODULE: &str = "emit";
+
+    /// Reserved for the emitter handle and the listener presence API of signals
+    pub const RESERVED_METHOD_NAMES_SIGNAL: [&str; 4] = [
+        "emitter",
+        "hasListeners",
+        "onListenerAdded",
+        "onListenerRemoved",
+    ];
+
+    /// `getConstants` is called once at the module construction and the result is cached
+    pub const RESERVED_METHOD_NAME_CONSTANTS: &str = "getConstants";
 }
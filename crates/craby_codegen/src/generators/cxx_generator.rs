@@ -1,6 +1,10 @@
-use std::fs;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+};
 
 use craby_common::{
+    config::{PanicStrategy, ThreadPoolConfig, ThreadPoolSize},
     constants::{cxx_bridge_include_dir, cxx_dir},
     utils::string::{camel_case, flat_case, pascal_case, snake_case},
 };
@@ -8,6 +12,7 @@ use indoc::formatdoc;
 
 use crate::{
     constants::specs::RESERVED_ARG_NAME_MODULE,
+    parser::types::{JsDoc, TypeAnnotation},
     platform::cxx::CxxMethod,
     types::{CodegenContext, CxxModuleName, CxxNamespace, Schema},
     utils::indent_str,
@@ -27,6 +32,12 @@ pub enum CxxFileType {
     UtilsHpp,
     /// CrabySignals.h
     SignalsH,
+    /// CrabyCallbacks.h
+    CallbacksH,
+    /// CrabyStreams.h
+    StreamsH,
+    /// CrabyPanics.h
+    PanicsH,
 }
 
 impl CxxTemplate {
@@ -52,22 +63,24 @@ impl CxxTemplate {
         Ok(res)
     }
 
-    /// Returns the cxx JSI method definition.
+    /// Returns the cxx JSI method definition with the JSDoc comment of the method.
     ///
     /// ```cpp
+    /// // Multiplies the given numbers
     /// static facebook::jsi::Value
     /// myFunc(facebook::jsi::Runtime &rt,
     ///        facebook::react::TurboModule &turboModule,
     ///        const facebook::jsi::Value args[], size_t count);
     /// ```
-    fn cxx_method_def(&self, name: &str) -> String {
+    fn cxx_method_def(&self, name: &str, doc: &JsDoc) -> String {
         let method_name = camel_case(name);
         formatdoc! {
             r#"
-            static facebook::jsi::Value
+            {doc}static facebook::jsi::Value
             {method_name}(facebook::jsi::Runtime &rt,
                 facebook::react::TurboModule &turboModule,
                 const facebook::jsi::Value args[], size_t count);"#,
+            doc = doc.to_comment("//"),
         }
     }
 
@@ -92,7 +105,7 @@ impl CxxTemplate {
     ///     std::shared_ptr<react::CallInvoker> jsInvoker)
     ///     : TurboModule(CxxMyTestModule::kModuleName, jsInvoker) {
     ///   callInvoker_ = std::move(jsInvoker);
-    ///   threadPool_ = std::make_shared<craby::utils::ThreadPool>(10);
+    ///   threadPool_ = std::make_shared<craby::utils::ThreadPool>(10, "MyTestModule");
     ///   methodMap_["multiply"] = MethodMetadata{2, &CxxMyTestModule::multiply};
     /// }
     /// jsi::Value CxxMyTestModule::multiply(jsi::Runtime &rt,
@@ -148,6 +161,8 @@ impl CxxTemplate {
         &self,
         schema: &Schema,
         project_name: &str,
+        panic_strategy: PanicStrategy,
+        thread_pool: &ThreadPoolConfig,
     ) -> Result<(String, String), anyhow::Error> {
         let cxx_ns = CxxNamespace::from(project_name);
         let cxx_mod = CxxModuleName::from(&schema.module_name);
@@ -165,9 +180,10 @@ impl CxxTemplate {
             .map(|method| format!("methodMap_[\"{}\"] = {};", method.name, method.metadata))
             .collect::<Vec<_>>();
 
-        let mut method_defs = cxx_methods
+        let mut method_defs = schema
+            .methods
             .iter()
-            .map(|method| self.cxx_method_def(&method.name))
+            .map(|method| self.cxx_method_def(&method.name, &method.doc))
             .collect::<Vec<_>>();
 
         // Functions implementations
@@ -186,27 +202,84 @@ impl CxxTemplate {
             .collect::<Vec<_>>();
 
         let (register_stmt, unregister_stmt) = if !schema.signals.is_empty() {
-            // Get signal enum type
-            let signal_enum_name = if !schema.signals.is_empty() {
-                Some(format!("{}Signal", schema.module_name))
-            } else {
-                None
-            };
-            
-            let register_stmt = if let Some(ref signal_enum) = signal_enum_name {
-                formatdoc! {
-                    r#"
-                    uintptr_t id = reinterpret_cast<uintptr_t>(this);
-                    auto& manager = {cxx_ns}::signals::SignalManager::getInstance();
-                    manager.registerDelegate(id,
-                      [this](const std::string& name, void* signal) {{
-                        this->emit(name, reinterpret_cast<bridging::{signal_enum}*>(signal));
+            let signal_enum = format!("{}Signal", schema.module_name);
+
+            // Each signal is delivered through its own channel with the delivery policy
+            //
+            // ```cpp
+            // signalChannels_["onProgress"] = std::make_shared<craby::mymodule::utils::SignalChannel>(
+            //   jsInvoker,
+            //   craby::mymodule::utils::SignalPolicy{.throttle = std::chrono::milliseconds(16)},
+            //   deliver("onProgress"));
+            // ```
+            let signal_channels = schema
+                .signals
+                .iter()
+                .map(|signal| {
+                    let mut policy = vec![];
+                    if let Some(throttle) = signal.throttle {
+                        policy.push(format!(".throttle = std::chrono::milliseconds({throttle})"));
+                    }
+                    if signal.latest_only {
+                        policy.push(".latestOnly = true".to_string());
+                    }
+                    if let Some(buffer) = signal.buffer {
+                        policy.push(format!(".bufferSize = {buffer}"));
+                    }
+
+                    formatdoc! {
+                        r#"
+                        signalChannels_["{signal_name}"] = std::make_shared<{cxx_ns}::utils::SignalChannel>(
+                          jsInvoker,
+                          {cxx_ns}::utils::SignalPolicy{{{policy}}},
+                          deliver("{signal_name}"));"#,
+                        signal_name = signal.name,
+                        policy = policy.join(", "),
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            let register_stmt = formatdoc! {
+                r#"
+                auto deliver = [this](std::string name) {{
+                  return [this, name](jsi::Runtime &rt, const jsi::Value &payload) {{
+                    std::vector<std::shared_ptr<jsi::Function>> listeners;
+                    {{
+                      std::lock_guard<std::mutex> lock(listenersMutex_);
+                      auto it = listenersMap_.find(name);
+                      if (it != listenersMap_.end()) {{
+                        for (auto &[_, listener] : it->second) {{
+                          listeners.push_back(listener);
+                        }}
                       }}
-                    );"#,
-                    signal_enum = signal_enum,
-                }
-            } else {
-                String::new()
+                    }}
+
+                    for (auto &listener : listeners) {{
+                      try {{
+                        listener->call(rt, payload);
+                      }} catch (const jsi::JSError &err) {{
+                        throw err;
+                      }} catch (const std::exception &err) {{
+                        throw jsi::JSError(rt, {cxx_ns}::utils::errorMessage(err));
+                      }}
+                    }}
+                  }};
+                }};
+                {signal_channels}
+
+                uintptr_t id = reinterpret_cast<uintptr_t>(this);
+                auto& manager = {cxx_ns}::signals::SignalManager::getInstance();
+                manager.registerDelegate(id, bridging::register_signals(id),
+                  [this](const std::string& name, void* signal) {{
+                    this->emit(name, reinterpret_cast<bridging::{signal_enum}*>(signal));
+                  }},
+                  [this](const std::string& name) {{
+                    std::lock_guard<std::mutex> lock(listenersMutex_);
+                    auto it = listenersMap_.find(name);
+                    return it != listenersMap_.end() && !it->second.empty();
+                  }}
+                );"#,
             };
 
             let unregister_stmt = formatdoc! {
@@ -214,7 +287,13 @@ impl CxxTemplate {
                 // Unregister from signal manager
                 uintptr_t id = reinterpret_cast<uintptr_t>(this);
                 auto& manager = {cxx_ns}::signals::SignalManager::getInstance();
-                manager.unregisterDelegate(id);"#,
+                manager.unregisterDelegate(id);
+                bridging::unregister_signals(id);
+
+                // Drop the pending emissions and stop the timer threads
+                for (auto &[_, channel] : signalChannels_) {{
+                  channel->close();
+                }}"#,
             };
 
             for signal in &schema.signals {
@@ -225,13 +304,7 @@ impl CxxTemplate {
                     r#"methodMap_["{signal_name}"] = MethodMetadata{{1, &{cxx_mod}::{cxx_signal_name}}};"#,
                 });
 
-                method_defs.push(formatdoc! {
-                    r#"
-                    static facebook::jsi::Value
-                    {signal_name}(facebook::jsi::Runtime &rt,
-                        facebook::react::TurboModule &turboModule,
-                        const facebook::jsi::Value args[], size_t count);"#,
-                });
+                method_defs.push(self.cxx_method_def(signal_name, &signal.doc));
 
                 method_impls.push(formatdoc! {
                     r#"
@@ -253,25 +326,40 @@ impl CxxTemplate {
                         auto id = thisModule.nextListenerId_.fetch_add(1);
                         auto name = "{signal_name}";
 
-                        if (thisModule.listenersMap_.find(name) == thisModule.listenersMap_.end()) {{
-                          thisModule.listenersMap_[name] = std::unordered_map<size_t, std::shared_ptr<facebook::jsi::Function>>();
-                        }}
-
+                        // The listeners are read from the other threads (eg. `has_listeners`)
                         {{
                           std::lock_guard<std::mutex> lock(thisModule.listenersMutex_);
                           thisModule.listenersMap_[name].emplace(id, callbackRef);
                         }}
+                        thisModule.signalChannels_.at(name)->subscribe();
+                        {cxx_ns}::bridging::{mod_name}_on_listener_added(*{it}, rust::Str(name));
 
                         auto modulePtr = &thisModule;
                         auto cleanup = [modulePtr, name, id] {{
-                          std::lock_guard<std::mutex> lock(modulePtr->listenersMutex_);
-                          auto eventMap = modulePtr->listenersMap_.find(name);
-                          if (eventMap != modulePtr->listenersMap_.end()) {{
-                            auto it = eventMap->second.find(id);
-                            if (it != eventMap->second.end()) {{
-                              eventMap->second.erase(it);
+                          bool removed = false;
+                          bool lastRemoved = false;
+                          {{
+                            std::lock_guard<std::mutex> lock(modulePtr->listenersMutex_);
+                            auto eventMap = modulePtr->listenersMap_.find(name);
+                            if (eventMap != modulePtr->listenersMap_.end()) {{
+                              auto it = eventMap->second.find(id);
+                              if (it != eventMap->second.end()) {{
+                                eventMap->second.erase(it);
+                                removed = true;
+                                lastRemoved = eventMap->second.empty();
+                              }}
                             }}
                           }}
+
+                          // Buffer the emissions again after the last listener is removed
+                          if (removed && lastRemoved) {{
+                            modulePtr->signalChannels_.at(name)->unsubscribe();
+                          }}
+
+                          // The hook is called once per listener (not called after the module is invalidated)
+                          if (removed && !modulePtr->invalidated_) {{
+                            {cxx_ns}::bridging::{mod_name}_on_listener_removed(*modulePtr->module_, rust::Str(name));
+                          }}
                           return jsi::Value::undefined();
                         }};
 
@@ -280,7 +368,13 @@ impl CxxTemplate {
                           jsi::PropNameID::forAscii(rt, "cleanup"),
                           0,
                           [cleanup](jsi::Runtime& rt, const jsi::Value&, const jsi::Value*, size_t) -> jsi::Value {{
-                            return cleanup();
+                            try {{
+                              return cleanup();
+                            }} catch (const jsi::JSError &err) {{
+                              throw err;
+                            }} catch (const std::exception &err) {{
+                              throw jsi::JSError(rt, {cxx_ns}::utils::errorMessage(err));
+                            }}
                           }}
                         );
                       }} catch (const jsi::JSError &err) {{
@@ -290,170 +384,181 @@ impl CxxTemplate {
                       }}
                     }}"#,
                     it = RESERVED_ARG_NAME_MODULE,
+                    mod_name = snake_case(&schema.module_name),
                 });
             }
 
-            let signal_enum_name = if !schema.signals.is_empty() {
-                Some(format!("{}Signal", schema.module_name))
-            } else {
-                None
-            };
-            
-            method_defs.insert(0, if let Some(ref signal_enum) = signal_enum_name {
-              format!("void emit(std::string name, bridging::{}* signal);", signal_enum)
-            } else {
-                "void emit(std::string name);".to_string()
-            });
+            method_defs.insert(
+                0,
+                format!("void emit(std::string name, bridging::{signal_enum}* signal);"),
+            );
 
-            // Generate payload extraction conditions dynamically
-            let payload_extraction = if signal_enum_name.is_some() {
-                let mut conditions: Vec<String> = schema.signals
-                    .iter()
-                    .filter_map(|signal| {
-                        signal.payload_type.as_ref().map(|_| {
-                            let function_name = format!("get_{}_payload", snake_case(&signal.name));
-                            formatdoc! {
-                                r#"else if (name == "{signal_name}") {{
-                                  auto payload = craby::{project_ns}::bridging::{function_name}(*signalPtr);
-                                  data = react::bridging::toJs(rt, payload);
-                                }}"#,
-                                signal_name = signal.name,
-                                function_name = function_name,
-                            }
-                        })
-                    })
-                    .collect();
-                
-                if !conditions.is_empty() {
-                    // Replace first "else if" with "if"
-                    if let Some(first) = conditions.first_mut() {
-                        *first = first.replace("else if", "if");
+            // Converts the payload of the signal into `jsi::Value` on the delivery (JS thread)
+            let payload_extraction = schema
+                .signals
+                .iter()
+                .filter(|signal| signal.payload_type.is_some())
+                .map(|signal| {
+                    formatdoc! {
+                        r#"
+                        if (name == "{signal_name}") {{
+                          auto payload = craby::{project_ns}::bridging::{function_name}(*signalPtr);
+                          return react::bridging::toJs(rt, payload);
+                        }}"#,
+                        signal_name = signal.name,
+                        function_name = format!(
+                            "{}_get_{}_payload",
+                            snake_case(&schema.module_name),
+                            snake_case(&signal.name)
+                        ),
                     }
-                    let joined = conditions.join(" ");
-                    indent_str(&joined, 10)
-                } else {
-                    String::new()
-                }
+                })
+                .collect::<Vec<_>>();
+            let (payload_captures, payload_extraction) = if payload_extraction.is_empty() {
+                ("", String::new())
             } else {
-                String::new()
+                (
+                    "signalPtr, name",
+                    indent_str(&payload_extraction.join("\n"), 4) + "\n",
+                )
             };
 
             method_impls.insert(
                 0,
-                if let Some(ref signal_enum) = signal_enum_name {
-                    formatdoc! {
-                        r#"
-                        void {cxx_mod}::emit(std::string name, bridging::{signal_enum}* signal) {{
-                          std::vector<std::shared_ptr<facebook::jsi::Function>> listeners;
-                          {{
-                            std::lock_guard<std::mutex> lock(listenersMutex_);
-                            auto it = listenersMap_.find(name);
-                            if (it != listenersMap_.end()) {{
-                              for (auto &[_, listener] : it->second) {{
-                                listeners.push_back(listener);
-                              }}
-                            }}
-                          }}
-
-                          // Prepare payload: extract from signal or use undefined
-                          auto payloadPtr = std::make_shared<facebook::jsi::Value>();
-                          
-                          if (signal == nullptr) {{
-                            *payloadPtr = facebook::jsi::Value::undefined();
-                          }} else {{
-                            // Use shared_ptr to manage signal lifetime across async callbacks
-                            auto signalPtr = std::shared_ptr<bridging::{signal_enum}>(
-                              signal,
-                              [](bridging::{signal_enum}* ptr) {{
-                                // Use Rust FFI function to drop signal memory
-                                if (ptr != nullptr) {{
-                                  craby::{project_ns}::bridging::drop_signal(ptr);
-                                }}
-                              }}
-                            );
-
-                            // Extract payload using FFI function and convert to jsi::Value
-                            // We'll need to capture signalPtr in the lambda
-                            for (auto& listener : listeners) {{
-                              try {{
-                                callInvoker_->invokeAsync([listener, signalPtr, name](jsi::Runtime &rt) {{
-                                  jsi::Value data = jsi::Value::undefined();
-                        {payload_extraction}
-                                  listener->call(rt, data);
-                                }});
-                              }} catch (const std::exception& err) {{
-                                // Noop
-                              }}
-                            }}
-                            return;
+                formatdoc! {
+                    r#"
+                    void {cxx_mod}::emit(std::string name, bridging::{signal_enum}* signal) {{
+                      // Use shared_ptr to manage signal lifetime across async callbacks
+                      auto signalPtr = std::shared_ptr<bridging::{signal_enum}>(
+                        signal,
+                        [](bridging::{signal_enum}* ptr) {{
+                          // Use Rust FFI function to drop signal memory
+                          if (ptr != nullptr) {{
+                            craby::{project_ns}::bridging::{drop_signal_fn}(ptr);
                           }}
+                        }}
+                      );
 
-                          for (auto& listener : listeners) {{
-                            try {{
-                              callInvoker_->invokeAsync([listener, payloadPtr](jsi::Runtime &rt) {{
-                                try {{
-                                  listener->call(rt, *payloadPtr);
-                                }} catch (const jsi::JSError &err) {{
-                                  throw err;
-                                }} catch (const std::exception &err) {{
-                                  throw jsi::JSError(rt, {cxx_ns}::utils::errorMessage(err));
-                                }}
-                              }});
-                            }} catch (const std::exception& err) {{
-                              // Noop
-                            }}
-                          }}
-                        }}"#,
-                        signal_enum = signal_enum,
-                        project_ns = project_ns,
-                        cxx_mod = cxx_mod,
-                        cxx_ns = cxx_ns,
-                        payload_extraction = payload_extraction,
-                    }
-                } else {
-                    formatdoc! {
-                        r#"
-                        void {cxx_mod}::emit(std::string name) {{
-                          std::vector<std::shared_ptr<facebook::jsi::Function>> listeners;
-                          {{
-                            std::lock_guard<std::mutex> lock(listenersMutex_);
-                            auto it = listenersMap_.find(name);
-                            if (it != listenersMap_.end()) {{
-                              for (auto &[_, listener] : it->second) {{
-                                listeners.push_back(listener);
-                              }}
-                            }}
-                          }}
+                      auto it = signalChannels_.find(name);
+                      if (it == signalChannels_.end()) {{
+                        return;
+                      }}
 
-                          for (auto& listener : listeners) {{
-                            try {{
-                              callInvoker_->invokeAsync([listener, payloadPtr](jsi::Runtime &rt) {{
-                                try {{
-                                  listener->call(rt, *payloadPtr);
-                                }} catch (const jsi::JSError &err) {{
-                                  throw err;
-                                }} catch (const std::exception &err) {{
-                                  throw jsi::JSError(rt, {cxx_ns}::utils::errorMessage(err));
-                                }}
-                              }});
-                            }} catch (const std::exception& err) {{
-                              // Noop
-                            }}
-                          }}
-                        }}"#,
-                    }
-                }
+                      it->second->emit([{payload_captures}](jsi::Runtime &rt) -> jsi::Value {{
+                    {payload_extraction}    return jsi::Value::undefined();
+                      }});
+                    }}"#,
+                    drop_signal_fn = format!("{}_drop_signal", snake_case(&schema.module_name)),
+                },
             );
 
-
             (register_stmt, unregister_stmt)
         } else {
             (String::from("// No signals"), String::from("// No signals"))
         };
 
+        // Constants are retrieved once at the module construction and cached
+        //
+        // ```cpp
+        // constants_ = craby::mymodule::bridging::getConstants(*module_);
+        // ```
+        let (constants_stmt, constants_member) =
+            match schema.methods.iter().find(|method| method.is_constants()) {
+                Some(method) => (
+                    format!(
+                        "\n  constants_ = {cxx_ns}::bridging::{}(*module_);",
+                        camel_case(&method.name)
+                    ),
+                    format!("\n  {} constants_;", method.ret_type.as_cxx_type(&cxx_ns)?),
+                ),
+                None => (String::new(), String::new()),
+            };
+
+        // The streams are cancelled before the thread pool shutdown to release the blocked producers
+        //
+        // ```cpp
+        // streams_.cancelAll();
+        // ```
+        let (cancel_streams_stmt, streams_member) = if schema.collect_stream_types().is_empty() {
+            (String::new(), String::new())
+        } else {
+            (
+                "\n\n  // Cancel streams\n  streams_.cancelAll();".to_string(),
+                format!("\n  {cxx_ns}::streams::StreamRegistry streams_;"),
+            )
+        };
+
+        // The signal channels are created once at the module construction (read-only afterwards)
+        let signals_member = if schema.signals.is_empty() {
+            String::new()
+        } else {
+            format!(
+                "\n  std::unordered_map<std::string, std::shared_ptr<{cxx_ns}::utils::SignalChannel>> signalChannels_;"
+            )
+        };
+
+        // The panics are reported to JS by the panic hook before the process is aborted
+        //
+        // ```cpp
+        // craby::mymodule::panics::PanicReporter::getInstance().registerReporter(/* ... */);
+        // ```
+        let (register_reporter_stmt, unregister_reporter_stmt) = match panic_strategy {
+            PanicStrategy::Unwind => (String::new(), String::new()),
+            PanicStrategy::Abort => (
+                formatdoc! {
+                    r#"
+
+                    {cxx_ns}::panics::PanicReporter::getInstance().registerReporter(
+                      reinterpret_cast<uintptr_t>(this),
+                      [callInvoker = jsInvoker](const std::string &message) {{
+                        {cxx_ns}::utils::reportPanic(callInvoker, message);
+                      }});"#,
+                },
+                formatdoc! {
+                    r#"
+
+                    // Unregister from panic reporter
+                    {cxx_ns}::panics::PanicReporter::getInstance().unregisterReporter(
+                      reinterpret_cast<uintptr_t>(this));"#,
+                },
+            ),
+        };
+
+        // The worker threads are named with the prefix of the thread pool config (default: the module name)
+        //
+        // ```cpp
+        // threadPool_ = std::make_shared<craby::mymodule::utils::ThreadPool>(10, "MyTestModule");
+        // serialQueues_["storage"] = std::make_shared<craby::mymodule::utils::ThreadPool>(1, "MyTestModule-storage");
+        // ```
+        let thread_name = thread_pool.name.as_deref().unwrap_or(&schema.module_name);
+        let thread_pool_size = match thread_pool.size {
+            ThreadPoolSize::Auto => format!("{cxx_ns}::utils::ThreadPool::hardwareConcurrency()"),
+            ThreadPoolSize::Fixed(size) => size.to_string(),
+        };
+        let serial_queues = schema
+            .methods
+            .iter()
+            .filter_map(|method| method.serial.as_deref())
+            .collect::<BTreeSet<_>>();
+        let (serial_queues_stmt, shutdown_serial_queues_stmt, serial_queues_member) =
+            if serial_queues.is_empty() {
+                (String::new(), String::new(), String::new())
+            } else {
+                (
+                    serial_queues
+                        .iter()
+                        .map(|queue| {
+                            format!("\n  serialQueues_[\"{queue}\"] = std::make_shared<{cxx_ns}::utils::ThreadPool>(1, \"{thread_name}-{queue}\");")
+                        })
+                        .collect::<String>(),
+                    "\n\n  // Shutdown serial queues\n  for (auto &[_, queue] : serialQueues_) {\n    queue->shutdown();\n  }".to_string(),
+                    format!("\n  std::unordered_map<std::string, std::shared_ptr<{cxx_ns}::utils::ThreadPool>> serialQueues_;"),
+                )
+            };
+
         let rs_module_name = pascal_case(&schema.module_name);
-        let register_stmts = indent_str(&register_stmt, 2);
-        let unregister_stmts = indent_str(&unregister_stmt, 2);
+        let register_stmts = indent_str(&(register_stmt + &register_reporter_stmt), 2);
+        let unregister_stmts = indent_str(&(unregister_stmt + &unregister_reporter_stmt), 2);
         let method_mapping_stmts = indent_str(&method_maps.join("\n"), 2);
         let method_impls = method_impls.join("\n\n");
         let cpp = formatdoc! {
@@ -470,8 +575,8 @@ impl CxxTemplate {
                   reinterpret_cast<uintptr_t>(this),
                   rust::Str(dataPath.data(), dataPath.size())).into_raw(),
                 []({cxx_ns}::bridging::{rs_module_name} *ptr) {{ rust::Box<{cxx_ns}::bridging::{rs_module_name}>::from_raw(ptr); }}
-              );
-              threadPool_ = std::make_shared<{cxx_ns}::utils::ThreadPool>(10);
+              );{constants_stmt}
+              threadPool_ = std::make_shared<{cxx_ns}::utils::ThreadPool>({thread_pool_size}, "{thread_name}");{serial_queues_stmt}
             {method_mapping_stmts}
             }}
 
@@ -485,12 +590,15 @@ impl CxxTemplate {
               }}
 
               invalidated_.store(true);
-              listenersMap_.clear();
+              {{
+                std::lock_guard<std::mutex> lock(listenersMutex_);
+                listenersMap_.clear();
+              }}
             
-            {unregister_stmts}
+            {unregister_stmts}{cancel_streams_stmt}
 
               // Shutdown thread pool
-              threadPool_->shutdown();
+              threadPool_->shutdown();{shutdown_serial_queues_stmt}
             }}
             
             {method_impls}"#,
@@ -520,7 +628,7 @@ impl CxxTemplate {
                 std::string,
                 std::unordered_map<size_t, std::shared_ptr<facebook::jsi::Function>>>
                 listenersMap_;
-              std::shared_ptr<{cxx_ns}::utils::ThreadPool> threadPool_;
+              std::shared_ptr<{cxx_ns}::utils::ThreadPool> threadPool_;{serial_queues_member}{constants_member}{streams_member}{signals_member}
             }};"#,
             turbo_module_name = schema.module_name,
         };
@@ -603,11 +711,14 @@ impl CxxTemplate {
     /// } // namespace facebook
     /// ```
     fn cxx_bridging(&self, ctx: &CodegenContext) -> Result<String, anyhow::Error> {
+        // Types shared between the modules are specialized only once
+        let mut specialized = BTreeSet::new();
         let bridging_templates = ctx
             .schemas
             .iter()
             .flat_map(|schema| schema.as_cxx_bridging_templates(&ctx.project_name))
             .flatten()
+            .filter(|template| specialized.insert(template.clone()))
             .collect::<Vec<_>>();
 
         let cxx_bridging = formatdoc! {
@@ -620,6 +731,7 @@ impl CxxTemplate {
             #include <variant>
             #include <iterator>
             #include <algorithm>
+            #include <cstring>
 
             using namespace facebook;
 
@@ -684,6 +796,75 @@ impl CxxTemplate {
               }}
             }};
 
+            template <>
+            struct Bridging<int64_t> {{
+              static int64_t fromJs(jsi::Runtime& rt, const jsi::Value &value, std::shared_ptr<CallInvoker> callInvoker) {{
+                return value.asBigInt(rt).asInt64(rt);
+              }}
+
+              static jsi::Value toJs(jsi::Runtime& rt, int64_t value) {{
+                return jsi::Value(rt, jsi::BigInt::fromInt64(rt, value));
+              }}
+            }};
+
+            template <>
+            struct Bridging<uint64_t> {{
+              static uint64_t fromJs(jsi::Runtime& rt, const jsi::Value &value, std::shared_ptr<CallInvoker> callInvoker) {{
+                return value.asBigInt(rt).asUint64(rt);
+              }}
+
+              static jsi::Value toJs(jsi::Runtime& rt, uint64_t value) {{
+                return jsi::Value(rt, jsi::BigInt::fromUint64(rt, value));
+              }}
+            }};
+
+            template <typename T>
+            struct TypedArrayBridging {{
+              static rust::Vec<T> fromJs(jsi::Runtime& rt, const jsi::Value &value, const char* name) {{
+                auto obj = value.asObject(rt);
+                auto ctor = obj.getProperty(rt, "constructor");
+
+                if (!ctor.isObject() || ctor.asObject(rt).getProperty(rt, "name").asString(rt).utf8(rt) != name) {{
+                  throw jsi::JSError(rt, "Invalid typed array value (" + std::string(name) + ")");
+                }}
+
+                auto arrayBuffer = obj.getPropertyAsObject(rt, "buffer").getArrayBuffer(rt);
+                auto byteOffset = static_cast<size_t>(obj.getProperty(rt, "byteOffset").asNumber());
+                auto size = static_cast<size_t>(obj.getProperty(rt, "byteLength").asNumber());
+
+                if (size % sizeof(T) != 0 || byteOffset + size > arrayBuffer.size(rt)) {{
+                  throw jsi::JSError(rt, "Invalid typed array length (" + std::string(name) + ")");
+                }}
+
+                uint8_t* data = arrayBuffer.data(rt) + byteOffset;
+                size_t len = size / sizeof(T);
+                rust::Vec<T> vec;
+                vec.reserve(len);
+
+                for (size_t i = 0; i < len; i++) {{
+                  T element;
+                  std::memcpy(&element, data + i * sizeof(T), sizeof(T));
+                  vec.push_back(element);
+                }}
+
+                return vec;
+              }}
+
+              static jsi::Value toJs(jsi::Runtime& rt, const rust::Vec<T>& vec, const char* name) {{
+                auto data = reinterpret_cast<const uint8_t*>(vec.data());
+                rust::Vec<uint8_t> bytes;
+                bytes.reserve(vec.size() * sizeof(T));
+
+                std::copy(data, data + vec.size() * sizeof(T), std::back_inserter(bytes));
+
+                auto buffer = std::make_shared<{flat_name}::RustVecBuffer>(std::move(bytes));
+                auto arrayBuffer = jsi::ArrayBuffer(rt, buffer);
+                auto ctor = rt.global().getPropertyAsFunction(rt, name);
+
+                return ctor.callAsConstructor(rt, arrayBuffer);
+              }}
+            }};
+
             template <>
             struct Bridging<rust::Vec<uint8_t>> {{
               static rust::Vec<uint8_t> fromJs(jsi::Runtime& rt, const jsi::Value &value, std::shared_ptr<CallInvoker> callInvoker) {{
@@ -731,6 +912,7 @@ impl CxxTemplate {
                 return arr;
               }}
             }};
+
             {bridging_templates}
             }} // namespace react
             }} // namespace facebook"#,
@@ -752,6 +934,7 @@ impl CxxTemplate {
     /// #include "ffi.rs.h"
     /// #include <condition_variable>
     /// #include <functional>
+    /// #include <jsi/jsi.h>
     /// #include <mutex>
     /// #include <queue>
     /// #include <thread>
@@ -768,12 +951,28 @@ impl CxxTemplate {
     ///   std::condition_variable condition;
     ///   std::queue<std::function<void()>> tasks;
     ///   std::vector<std::thread> workers;
+    ///
+    ///   static std::string threadNameOf(const std::string &name, size_t index) {
+    ///     // ...
+    ///   }
+    ///
+    ///   static void setThreadName(const std::string &name) {
+    ///     // ...
+    ///   }
     /// }
     ///
     /// public:
-    ///   ThreadPool(size_t num_threads = 10) : stop(false) {
+    ///   static size_t hardwareConcurrency() {
+    ///     return std::max<size_t>(std::thread::hardware_concurrency(), 1);
+    ///   }
+    ///
+    ///   ThreadPool(size_t num_threads = 10, const std::string &name = "") : stop(false) {
     ///     for (size_t i = 0; i < num_threads; ++i) {
-    ///       workers.emplace_back([this] {
+    ///       auto threadName = num_threads > 1 ? threadNameOf(name, i) : name;
+    ///
+    ///       workers.emplace_back([this, threadName] {
+    ///         setThreadName(threadName);
+    ///
     ///         while (true) {
     ///           std::function<void()> task;
     ///
@@ -833,23 +1032,113 @@ impl CxxTemplate {
     ///   return std::string(rs_err ? rs_err->what() : err.what());
     /// }
     ///
+    /// // Logs the warning through the React Native logger (`console.warn`)
+    /// inline void warn(facebook::jsi::Runtime &rt, const std::string &message) {
+    ///   // ...
+    /// }
+    ///
+    /// // Creates the JS error with the `code` and `details` properties of the typed error
+    /// inline facebook::jsi::Value createError(facebook::jsi::Runtime &rt, const std::string &message) {
+    ///   // ...
+    /// }
+    ///
+    /// // Promise that is settled on the JS thread
+    /// template <typename T> class Promise {
+    ///   // ...
+    /// };
+    ///
+    /// // Settles the `next()` promises of the JS async iterator with the items of the stream
+    /// template <typename S> class AsyncIterator {
+    ///   // ...
+    /// };
+    ///
+    /// template <typename S>
+    /// facebook::jsi::Value createAsyncIterator(facebook::jsi::Runtime &rt, /* ... */) {
+    ///   // ...
+    /// }
+    ///
+    /// // Delivery policy of the signal (JSDoc tags of the signal)
+    /// struct SignalPolicy {
+    ///   // ...
+    /// };
+    ///
+    /// // Delivers the emissions of the signal to the JS listeners
+    /// class SignalChannel : public std::enable_shared_from_this<SignalChannel> {
+    ///   // ...
+    /// };
+    ///
+    /// // Reports the Rust panic to JS before the process is aborted (`panic = "abort"`)
+    /// inline void reportPanic(const std::shared_ptr<facebook::react::CallInvoker> &callInvoker, const std::string &message) {
+    ///   // ...
+    /// }
+    ///
     /// } // namespace utils
     /// } // namespace mymodule
     /// } // namespace craby
     /// ```
-    fn cxx_utils(&self, project_name: &str) -> Result<String, anyhow::Error> {
+    fn cxx_utils(
+        &self,
+        project_name: &str,
+        panic_strategy: PanicStrategy,
+    ) -> Result<String, anyhow::Error> {
         let flat_name = flat_case(project_name);
 
+        // The process is aborted right after the report, so it waits for the JS thread to handle the report.
+        // The report cannot be handled if the JS thread itself is panicked (eg. synchronous methods).
+        let report_panic = match panic_strategy {
+            PanicStrategy::Unwind => String::new(),
+            PanicStrategy::Abort => formatdoc! {
+                r#"
+
+                inline void reportPanic(const std::shared_ptr<facebook::react::CallInvoker> &callInvoker,
+                                        const std::string &message) {{
+                  auto reported = std::make_shared<std::promise<void>>();
+                  auto future = reported->get_future();
+
+                  callInvoker->invokeAsync([message, reported](facebook::jsi::Runtime &rt) {{
+                    try {{
+                      auto error = rt.global().getPropertyAsFunction(rt, "Error").callAsConstructor(
+                          rt, facebook::jsi::String::createFromUtf8(rt, "Rust panic: " + message));
+                      auto errorUtils = rt.global().getProperty(rt, "ErrorUtils");
+
+                      if (errorUtils.isObject()) {{
+                        errorUtils.asObject(rt).getPropertyAsFunction(rt, "reportFatalError").call(rt, error);
+                      }} else {{
+                        auto console = rt.global().getPropertyAsObject(rt, "console");
+                        console.getPropertyAsFunction(rt, "error").call(rt, error);
+                      }}
+                    }} catch (...) {{
+                      // Noop
+                    }}
+                    reported->set_value();
+                  }});
+
+                  future.wait_for(std::chrono::seconds(1));
+                }}
+                "#,
+            },
+        };
+
         Ok(formatdoc! {
             r#"
             #pragma once
 
             #include "cxx.h"
             #include "ffi.rs.h"
+            #include <ReactCommon/CallInvoker.h>
+            #include <algorithm>
+            #include <chrono>
             #include <condition_variable>
+            #include <deque>
             #include <functional>
+            #include <future>
+            #include <jsi/jsi.h>
+            #include <memory>
             #include <mutex>
+            #include <optional>
+            #include <pthread.h>
             #include <queue>
+            #include <string>
             #include <thread>
             #include <vector>
 
@@ -865,10 +1154,37 @@ impl CxxTemplate {
               std::queue<std::function<void()>> tasks;
               std::vector<std::thread> workers;
 
+              // The index suffix is kept within the thread name length limit (15 characters on Linux)
+              static std::string threadNameOf(const std::string &name, size_t index) {{
+                auto suffix = "-" + std::to_string(index);
+                return name.substr(0, 15 - std::min<size_t>(suffix.size(), 15)) + suffix;
+              }}
+
+              static void setThreadName(const std::string &name) {{
+                if (name.empty()) {{
+                  return;
+                }}
+            #if defined(__APPLE__)
+                pthread_setname_np(name.c_str());
+            #elif defined(__linux__)
+                pthread_setname_np(pthread_self(), name.substr(0, 15).c_str());
+            #endif
+              }}
+
             public:
-              ThreadPool(size_t num_threads = 10) : stop(false) {{
+              // Number of the hardware threads (`size = "auto"` of the thread pool config)
+              static size_t hardwareConcurrency() {{
+                return std::max<size_t>(std::thread::hardware_concurrency(), 1);
+              }}
+
+              ThreadPool(size_t num_threads = 10, const std::string &name = "") : stop(false) {{
                 for (size_t i = 0; i < num_threads; ++i) {{
-                  workers.emplace_back([this] {{
+                  // The workers are named with the index (eg. `MyModule-0`) except the single worker (serial queue)
+                  auto threadName = num_threads > 1 ? threadNameOf(name, i) : name;
+
+                  workers.emplace_back([this, threadName] {{
+                    setThreadName(threadName);
+
                     while (true) {{
                       std::function<void()> task;
 
@@ -929,58 +1245,574 @@ impl CxxTemplate {
               return std::string(rs_err ? rs_err->what() : err.what());
             }}
 
-            }} // namespace utils
-            }} // namespace {flat_name}
-            }} // namespace craby"#,
-        })
-    }
+            inline void warn(facebook::jsi::Runtime &rt, const std::string &message) {{
+              auto console = rt.global().getPropertyAsObject(rt, "console");
+              console.getPropertyAsFunction(rt, "warn").call(
+                  rt, facebook::jsi::String::createFromUtf8(rt, message));
+            }}
 
-    /// Generates the signal manager header file for event emission.
-    ///
-    /// # Generated Code
-    ///
-    /// ```cpp
-    /// #pragma once
-    ///
-    /// #include "rust/cxx.h"
-    /// #include <functional>
-    /// #include <memory>
-    /// #include <mutex>
-    /// #include <unordered_map>
-    ///
-    /// namespace craby {
-    /// namespace mymodule {
-    /// namespace signals {
-    ///
-    /// class SignalManager {
-    /// public:
-    ///   static SignalManager& getInstance() {
-    ///     static SignalManager instance;
-    ///     return instance;
-    ///   }
-    ///
-    ///   void emit(uintptr_t id, rust::Str name) const {
-    ///     std::lock_guard<std::mutex> lock(mutex_);
-    ///     auto it = delegates_.find(id);
-    ///     if (it != delegates_.end()) {
-    ///       it->second(std::string(name));
-    ///     }
-    ///   }
-    ///
-    ///   void registerDelegate(uintptr_t id, Delegate delegate) const {
-    ///     std::lock_guard<std::mutex> lock(mutex_);
-    ///     delegates_.insert_or_assign(id, delegate);
+            // Creates the JS error from the error message
+            //
+            // The typed error is encoded by the Rust side (`\x1e{{code}}\x1f{{details}}\x1f{{message}}`),
+            // and the `code` and `details` (if not empty) properties are set to the error.
+            inline facebook::jsi::Value createError(facebook::jsi::Runtime &rt, const std::string &message) {{
+              auto errorCtor = rt.global().getPropertyAsFunction(rt, "Error");
+
+              if (message.rfind('\x1e', 0) == 0) {{
+                auto codeEnd = message.find('\x1f', 1);
+                auto detailsEnd = codeEnd == std::string::npos ? std::string::npos : message.find('\x1f', codeEnd + 1);
+
+                if (detailsEnd != std::string::npos) {{
+                  auto error = errorCtor.callAsConstructor(
+                      rt, facebook::jsi::String::createFromUtf8(rt, message.substr(detailsEnd + 1)));
+                  auto errorObj = error.asObject(rt);
+                  errorObj.setProperty(
+                      rt, "code", facebook::jsi::String::createFromUtf8(rt, message.substr(1, codeEnd - 1)));
+
+                  if (detailsEnd > codeEnd + 1) {{
+                    errorObj.setProperty(
+                        rt, "details",
+                        facebook::jsi::String::createFromUtf8(rt, message.substr(codeEnd + 1, detailsEnd - codeEnd - 1)));
+                  }}
+
+                  return error;
+                }}
+              }}
+
+              return errorCtor.callAsConstructor(rt, facebook::jsi::String::createFromUtf8(rt, message));
+            }}
+
+            inline facebook::jsi::Value createPromise(
+                facebook::jsi::Runtime &rt,
+                std::function<void(facebook::jsi::Runtime &,
+                                   std::shared_ptr<facebook::jsi::Function>,
+                                   std::shared_ptr<facebook::jsi::Function>)>
+                    executor) {{
+              auto executorFn = facebook::jsi::Function::createFromHostFunction(
+                  rt, facebook::jsi::PropNameID::forAscii(rt, "executor"), 2,
+                  [executor](facebook::jsi::Runtime &rt, const facebook::jsi::Value &,
+                             const facebook::jsi::Value *args, size_t) -> facebook::jsi::Value {{
+                    executor(rt,
+                             std::make_shared<facebook::jsi::Function>(args[0].asObject(rt).asFunction(rt)),
+                             std::make_shared<facebook::jsi::Function>(args[1].asObject(rt).asFunction(rt)));
+                    return facebook::jsi::Value::undefined();
+                  }});
+
+              return rt.global().getPropertyAsFunction(rt, "Promise").callAsConstructor(rt, executorFn);
+            }}
+
+            inline facebook::jsi::Value createIteratorResult(facebook::jsi::Runtime &rt,
+                                                             facebook::jsi::Value value,
+                                                             bool done) {{
+              facebook::jsi::Object result(rt);
+              result.setProperty(rt, "value", std::move(value));
+              result.setProperty(rt, "done", done);
+              return facebook::jsi::Value(std::move(result));
+            }}
+
+            template <typename S> class AsyncIterator {{
+            public:
+              using Item = typename S::Item;
+              using Converter = std::function<facebook::jsi::Value(facebook::jsi::Runtime &, Item &)>;
+
+              AsyncIterator(std::shared_ptr<S> stream, Converter converter)
+                  : stream_(std::move(stream)), converter_(std::move(converter)) {{}}
+
+              ~AsyncIterator() {{
+                // Release the producer when the iterator is garbage collected
+                stream_->cancel();
+              }}
+
+              void next(facebook::jsi::Runtime &rt,
+                        std::shared_ptr<facebook::jsi::Function> resolve,
+                        std::shared_ptr<facebook::jsi::Function> reject) {{
+                requests_.push_back({{std::move(resolve), std::move(reject)}});
+                flush(rt);
+              }}
+
+              void cancel(facebook::jsi::Runtime &rt) {{
+                stream_->cancel();
+                flush(rt);
+              }}
+
+              // Settles the pending `next()` promises in order (JS thread only)
+              void flush(facebook::jsi::Runtime &rt) {{
+                while (!requests_.empty()) {{
+                  std::optional<Item> item;
+                  std::string error;
+                  auto poll = stream_->poll(item, error);
+
+                  if (poll == S::Poll::Pending) {{
+                    return;
+                  }}
+
+                  auto request = std::move(requests_.front());
+                  requests_.pop_front();
+
+                  if (poll == S::Poll::Ready) {{
+                    request.resolve->call(rt, createIteratorResult(rt, converter_(rt, *item), false));
+                  }} else if (poll == S::Poll::Error) {{
+                    // Decodes the typed error (`code` and `details` properties) like the Promise methods
+                    request.reject->call(rt, createError(rt, error));
+                  }} else {{
+                    request.resolve->call(
+                        rt, createIteratorResult(rt, facebook::jsi::Value::undefined(), true));
+                  }}
+                }}
+              }}
+
+            private:
+              struct Request {{
+                std::shared_ptr<facebook::jsi::Function> resolve;
+                std::shared_ptr<facebook::jsi::Function> reject;
+              }};
+
+              std::shared_ptr<S> stream_;
+              Converter converter_;
+              std::deque<Request> requests_;
+            }};
+
+            template <typename S>
+            facebook::jsi::Value createAsyncIterator(
+                facebook::jsi::Runtime &rt,
+                std::shared_ptr<S> stream,
+                std::shared_ptr<facebook::react::CallInvoker> callInvoker,
+                typename AsyncIterator<S>::Converter converter) {{
+              auto iterator = std::make_shared<AsyncIterator<S>>(stream, std::move(converter));
+              std::weak_ptr<AsyncIterator<S>> weakIterator = iterator;
+
+              // The items are pushed from the worker thread, so the requests are settled on the JS thread
+              stream->setNotifier([weakIterator, callInvoker] {{
+                callInvoker->invokeAsync([weakIterator](facebook::jsi::Runtime &rt) {{
+                  if (auto iterator = weakIterator.lock()) {{
+                    iterator->flush(rt);
+                  }}
+                }});
+              }});
+
+              facebook::jsi::Object object(rt);
+              object.setProperty(
+                  rt, "next",
+                  facebook::jsi::Function::createFromHostFunction(
+                      rt, facebook::jsi::PropNameID::forAscii(rt, "next"), 0,
+                      [iterator](facebook::jsi::Runtime &rt, const facebook::jsi::Value &,
+                                 const facebook::jsi::Value *, size_t) -> facebook::jsi::Value {{
+                        return createPromise(
+                            rt, [iterator](facebook::jsi::Runtime &rt,
+                                           std::shared_ptr<facebook::jsi::Function> resolve,
+                                           std::shared_ptr<facebook::jsi::Function> reject) {{
+                              iterator->next(rt, std::move(resolve), std::move(reject));
+                            }});
+                      }}));
+
+              // Called when the iteration is stopped early (eg. `break` in `for await...of`)
+              object.setProperty(
+                  rt, "return",
+                  facebook::jsi::Function::createFromHostFunction(
+                      rt, facebook::jsi::PropNameID::forAscii(rt, "return"), 0,
+                      [iterator](facebook::jsi::Runtime &rt, const facebook::jsi::Value &,
+                                 const facebook::jsi::Value *, size_t) -> facebook::jsi::Value {{
+                        iterator->cancel(rt);
+                        return createPromise(
+                            rt, [](facebook::jsi::Runtime &rt,
+                                   std::shared_ptr<facebook::jsi::Function> resolve,
+                                   std::shared_ptr<facebook::jsi::Function>) {{
+                              resolve->call(
+                                  rt, createIteratorResult(rt, facebook::jsi::Value::undefined(), true));
+                            }});
+                      }}));
+
+              // `for await...of` gets the iterator with `Symbol.asyncIterator` (or `@@asyncIterator` if not supported)
+              auto self = facebook::jsi::Function::createFromHostFunction(
+                  rt, facebook::jsi::PropNameID::forAscii(rt, "asyncIterator"), 0,
+                  [](facebook::jsi::Runtime &rt, const facebook::jsi::Value &thisVal,
+                     const facebook::jsi::Value *, size_t) -> facebook::jsi::Value {{
+                    return facebook::jsi::Value(rt, thisVal);
+                  }});
+              auto asyncIterator =
+                  rt.global().getPropertyAsObject(rt, "Symbol").getProperty(rt, "asyncIterator");
+
+              if (asyncIterator.isSymbol()) {{
+                rt.global()
+                    .getPropertyAsObject(rt, "Reflect")
+                    .getPropertyAsFunction(rt, "set")
+                    .call(rt, object, asyncIterator, self);
+              }} else {{
+                object.setProperty(rt, "@@asyncIterator", self);
+              }}
+
+              return facebook::jsi::Value(std::move(object));
+            }}
+
+            template <typename T> std::shared_ptr<T> intoShared(rust::Box<T> box) {{
+              return std::shared_ptr<T>(box.into_raw(), [](T *ptr) {{ rust::Box<T>::from_raw(ptr); }});
+            }}
+
+            inline facebook::jsi::Value createAbortError(facebook::jsi::Runtime &rt) {{
+              auto error = rt.global().getPropertyAsFunction(rt, "Error").callAsConstructor(
+                  rt, facebook::jsi::String::createFromAscii(rt, "The operation was aborted"));
+              error.asObject(rt).setProperty(rt, "name", "AbortError");
+              return error;
+            }}
+
+            // Promise that is settled on the JS thread
+            //
+            // Rejected with the typed error (see `createError`), or an `AbortError` when the JS `AbortSignal` is aborted.
+            template <typename T> class Promise {{
+            public:
+              using Converter = std::function<facebook::jsi::Value(facebook::jsi::Runtime &, T &)>;
+
+              Promise(std::shared_ptr<facebook::react::CallInvoker> callInvoker, Converter converter)
+                  : callInvoker_(std::move(callInvoker)), state_(std::make_shared<State>()) {{
+                state_->converter = std::move(converter);
+              }}
+
+              // Creates the promise object (JS thread only)
+              facebook::jsi::Value get(facebook::jsi::Runtime &rt) {{
+                return createPromise(
+                    rt, [state = state_](facebook::jsi::Runtime &,
+                                         std::shared_ptr<facebook::jsi::Function> resolve,
+                                         std::shared_ptr<facebook::jsi::Function> reject) {{
+                      state->resolve = std::move(resolve);
+                      state->reject = std::move(reject);
+                    }});
+              }}
+
+              // Aborts the controller and rejects the promise with an `AbortError`
+              // when the JS `AbortSignal` is aborted (JS thread only)
+              template <typename C>
+              void listen(facebook::jsi::Runtime &rt,
+                          const facebook::jsi::Value &signal,
+                          const std::shared_ptr<C> &controller) {{
+                // The omitted signal is never aborted
+                if (signal.isUndefined() || signal.isNull()) {{
+                  return;
+                }}
+
+                // The listener is retained by the JS signal, so it does not extend the lifetime of the call
+                std::weak_ptr<C> weakController = controller;
+                std::weak_ptr<State> weakState = state_;
+                auto onAbort = [weakController, weakState](facebook::jsi::Runtime &rt) {{
+                  if (auto controller = weakController.lock()) {{
+                    controller->abort();
+                  }}
+                  if (auto state = weakState.lock()) {{
+                    settle(rt, *state, [](facebook::jsi::Runtime &rt, State &state) {{
+                      state.reject->call(rt, createAbortError(rt));
+                    }});
+                  }}
+                }};
+
+                auto signalObj = signal.asObject(rt);
+                auto aborted = signalObj.getProperty(rt, "aborted");
+                if (aborted.isBool() && aborted.getBool()) {{
+                  onAbort(rt);
+                  return;
+                }}
+
+                auto listener = facebook::jsi::Function::createFromHostFunction(
+                    rt, facebook::jsi::PropNameID::forAscii(rt, "onAbort"), 0,
+                    [onAbort](facebook::jsi::Runtime &rt, const facebook::jsi::Value &,
+                              const facebook::jsi::Value *, size_t) -> facebook::jsi::Value {{
+                      onAbort(rt);
+                      return facebook::jsi::Value::undefined();
+                    }});
+                signalObj.getPropertyAsFunction(rt, "addEventListener")
+                    .callWithThis(rt, signalObj, "abort", listener);
+              }}
+
+              void resolve(T value) {{
+                dispatch([value = std::move(value)](facebook::jsi::Runtime &rt, State &state) mutable {{
+                  state.resolve->call(rt, state.converter(rt, value));
+                }});
+              }}
+
+              void reject(std::string message) {{
+                dispatch([message](facebook::jsi::Runtime &rt, State &state) {{
+                  state.reject->call(rt, createError(rt, message));
+                }});
+              }}
+
+            private:
+              struct State {{
+                bool settled = false;
+                std::shared_ptr<facebook::jsi::Function> resolve;
+                std::shared_ptr<facebook::jsi::Function> reject;
+                Converter converter;
+              }};
+
+              template <typename F>
+              static void settle(facebook::jsi::Runtime &rt, State &state, F &&f) {{
+                if (state.settled || !state.resolve) {{
+                  return;
+                }}
+                state.settled = true;
+                f(rt, state);
+              }}
+
+              // Settles the promise on the JS thread (from any thread, once)
+              template <typename F> void dispatch(F &&f) {{
+                if (!state_) {{
+                  return;
+                }}
+
+                // The state is moved to the JS thread to release the JS functions on the JS thread
+                callInvoker_->invokeAsync(
+                    [state = std::move(state_), f = std::forward<F>(f)](facebook::jsi::Runtime &rt) mutable {{
+                      settle(rt, *state, f);
+                    }});
+              }}
+
+              std::shared_ptr<facebook::react::CallInvoker> callInvoker_;
+              std::shared_ptr<State> state_;
+            }};
+
+            // Delivery policy of the signal (JSDoc tags of the signal)
+            struct SignalPolicy {{
+              // Minimum interval between the deliveries (`@throttle`)
+              std::chrono::milliseconds throttle{{0}};
+              // Delivers only the latest of the pending emissions (`@latestOnly`)
+              bool latestOnly = false;
+              // Number of emissions kept until the first listener subscribes (`@buffer`)
+              size_t bufferSize = 0;
+            }};
+
+            // Delivers the emissions of the signal to the JS listeners
+            //
+            // The pending emissions are delivered together by a single JS thread call
+            // instead of a call per emission, and coalesced into the latest one if throttled.
+            class SignalChannel : public std::enable_shared_from_this<SignalChannel> {{
+            public:
+              using Payload = std::function<facebook::jsi::Value(facebook::jsi::Runtime &)>;
+              using Deliver = std::function<void(facebook::jsi::Runtime &, const facebook::jsi::Value &)>;
+
+              SignalChannel(std::shared_ptr<facebook::react::CallInvoker> callInvoker,
+                            SignalPolicy policy,
+                            Deliver deliver)
+                  : callInvoker_(std::move(callInvoker)), policy_(policy), deliver_(std::move(deliver)) {{}}
+
+              ~SignalChannel() {{
+                close();
+              }}
+
+              // Emits the payload (from any thread)
+              void emit(Payload payload) {{
+                std::lock_guard<std::mutex> lock(mutex_);
+                if (closed_) {{
+                  return;
+                }}
+
+                // Buffered until the first listener subscribes (dropped if not buffered)
+                if (!subscribed_) {{
+                  if (policy_.bufferSize > 0) {{
+                    pending_.push_back(std::move(payload));
+                    if (pending_.size() > policy_.bufferSize) {{
+                      pending_.pop_front();
+                    }}
+                  }}
+                  return;
+                }}
+
+                if (coalesce()) {{
+                  pending_.clear();
+                }}
+                pending_.push_back(std::move(payload));
+                schedule();
+              }}
+
+              // Starts the delivery and flushes the buffered emissions (when the first listener subscribes)
+              void subscribe() {{
+                std::lock_guard<std::mutex> lock(mutex_);
+                if (closed_ || subscribed_) {{
+                  return;
+                }}
+
+                subscribed_ = true;
+                if (coalesce() && pending_.size() > 1) {{
+                  pending_.erase(pending_.begin(), pending_.end() - 1);
+                }}
+                if (!pending_.empty()) {{
+                  schedule();
+                }}
+              }}
+
+              // Stops the delivery and buffers the emissions again (when the last listener is removed)
+              void unsubscribe() {{
+                std::lock_guard<std::mutex> lock(mutex_);
+                subscribed_ = false;
+                pending_.clear();
+              }}
+
+              // Drops the pending emissions and stops the timer thread
+              void close() {{
+                {{
+                  std::lock_guard<std::mutex> lock(mutex_);
+                  closed_ = true;
+                  pending_.clear();
+                }}
+                timerCondition_.notify_all();
+
+                if (timer_.joinable()) {{
+                  if (timer_.get_id() == std::this_thread::get_id()) {{
+                    timer_.detach();
+                  }} else {{
+                    timer_.join();
+                  }}
+                }}
+              }}
+
+            private:
+              bool coalesce() const {{
+                return policy_.latestOnly || policy_.throttle.count() > 0;
+              }}
+
+              // Schedules the delivery on the JS thread, delayed until the throttle interval is elapsed
+              void schedule() {{
+                if (scheduled_) {{
+                  return;
+                }}
+                scheduled_ = true;
+
+                auto deadline = lastDelivery_ + policy_.throttle;
+                if (std::chrono::steady_clock::now() < deadline) {{
+                  // The delayed deliveries are posted by a single timer thread (started on the first delay)
+                  deadline_ = deadline;
+                  if (!timer_.joinable()) {{
+                    timer_ = std::thread([this] {{ runTimer(); }});
+                  }}
+                  timerCondition_.notify_one();
+                }} else {{
+                  post();
+                }}
+              }}
+
+              void runTimer() {{
+                std::unique_lock<std::mutex> lock(mutex_);
+                while (!closed_) {{
+                  if (!deadline_) {{
+                    timerCondition_.wait(lock);
+                  }} else if (timerCondition_.wait_until(lock, *deadline_) == std::cv_status::timeout) {{
+                    deadline_.reset();
+                    post();
+                  }}
+                }}
+              }}
+
+              // The pending emissions are kept if the delivery cannot be posted, and posted by the next emission
+              void post() {{
+                std::weak_ptr<SignalChannel> weakSelf = weak_from_this();
+                try {{
+                  callInvoker_->invokeAsync([weakSelf](facebook::jsi::Runtime &rt) {{
+                    if (auto self = weakSelf.lock()) {{
+                      self->flush(rt);
+                    }}
+                  }});
+                }} catch (const std::exception &err) {{
+                  scheduled_ = false;
+                  postError_ = err.what();
+                }}
+              }}
+
+              // Delivers the pending emissions (JS thread only)
+              void flush(facebook::jsi::Runtime &rt) {{
+                std::deque<Payload> payloads;
+                std::string postError;
+                {{
+                  std::lock_guard<std::mutex> lock(mutex_);
+                  if (closed_) {{
+                    return;
+                  }}
+
+                  payloads.swap(pending_);
+                  postError.swap(postError_);
+                  scheduled_ = false;
+                  lastDelivery_ = std::chrono::steady_clock::now();
+                }}
+
+                if (!postError.empty()) {{
+                  warn(rt, "Signal delivery was delayed: " + postError);
+                }}
+
+                for (auto &payload : payloads) {{
+                  deliver_(rt, payload(rt));
+                }}
+              }}
+
+              std::shared_ptr<facebook::react::CallInvoker> callInvoker_;
+              SignalPolicy policy_;
+              Deliver deliver_;
+              std::mutex mutex_;
+              std::deque<Payload> pending_;
+              std::chrono::steady_clock::time_point lastDelivery_;
+              std::optional<std::chrono::steady_clock::time_point> deadline_;
+              std::condition_variable timerCondition_;
+              std::thread timer_;
+              std::string postError_;
+              bool subscribed_ = false;
+              bool scheduled_ = false;
+              bool closed_ = false;
+            }};
+            {report_panic}
+            }} // namespace utils
+            }} // namespace {flat_name}
+            }} // namespace craby"#,
+        })
+    }
+
+    /// Generates the signal manager header file for event emission.
+    ///
+    /// Each module has its own entry point (`emit{Module}Signal`) that accepts the module's signal type.
+    ///
+    /// # Generated Code
+    ///
+    /// ```cpp
+    /// #pragma once
+    ///
+    /// #include "rust/cxx.h"
+    /// #include <functional>
+    /// #include <memory>
+    /// #include <mutex>
+    /// #include <unordered_map>
+    ///
+    /// namespace craby {
+    /// namespace mymodule {
+    /// namespace bridging {
+    /// struct MyModuleSignal;
+    /// void my_module_drop_signal(MyModuleSignal *signal) noexcept;
+    /// } // namespace bridging
+    ///
+    /// namespace signals {
+    ///
+    /// using Delegate = std::function<void(const std::string& signalName, void* signal)>;
+    /// using ListenerQuery = std::function<bool(const std::string& signalName)>;
+    ///
+    /// class SignalManager {
+    /// public:
+    ///   static SignalManager& getInstance() {
+    ///     static SignalManager instance;
+    ///     return instance;
+    ///   }
+    ///
+    ///   void emitMyModuleSignal(uintptr_t id, uint64_t generation, rust::Str name, craby::mymodule::bridging::MyModuleSignal* signal) const {
+    ///     if (!dispatch(id, generation, name, reinterpret_cast<void*>(signal))) {
+    ///       craby::mymodule::bridging::my_module_drop_signal(signal);
+    ///     }
+    ///   }
+    ///
+    ///   bool hasListeners(uintptr_t id, rust::Str name) const {
+    ///     // ...
+    ///   }
+    ///
+    ///   void registerDelegate(uintptr_t id, uint64_t generation, Delegate delegate, ListenerQuery hasListeners) const {
+    ///     // ...
     ///   }
     ///
     ///   void unregisterDelegate(uintptr_t id) const {
-    ///     std::lock_guard<std::mutex> lock(mutex_);
-    ///     delegates_.erase(id);
+    ///     // ...
     ///   }
     ///
     /// private:
-    ///   SignalManager() = default;
-    ///   mutable std::unordered_map<uintptr_t, Delegate> delegates_;
-    ///   mutable std::mutex mutex_;
+    ///   // ...
     /// };
     ///
     /// } // namespace signals
@@ -988,126 +1820,602 @@ impl CxxTemplate {
     /// } // namespace craby
     /// ```
     fn cxx_signals(&self, project_name: &str, schemas: &[Schema]) -> Result<String, anyhow::Error> {
-      let flat_name = flat_case(project_name);
-      
-      // Find schema with first signal
-      let signal_schema = schemas.iter().find(|s| !s.signals.is_empty());
-      let signal_enum = signal_schema.map(|s| format!("{}Signal", s.module_name));
-      let cxx_mod = signal_schema.map(|s| format!("Cxx{}", pascal_case(&s.module_name)));
-      
-      Ok(formatdoc! {
-          r#"
-          #pragma once
-
-          #include "rust/cxx.h"
-          #include <functional>
-          #include <memory>
-          #include <mutex>
-          #include <unordered_map>
-
-          {forward_declarations}
-
-          namespace craby {{
-          namespace {flat_name} {{
-          namespace signals {{
-
-          {signal_delegate_typedef}
-
-          class SignalManager {{
-          public:
-            static SignalManager& getInstance() {{
-              static SignalManager instance;
-              return instance;
-            }}
+        let flat_name = flat_case(project_name);
+        // (signal enum, drop function)
+        let signal_enums = schemas
+            .iter()
+            .filter(|schema| !schema.signals.is_empty())
+            .map(|schema| {
+                (
+                    format!("{}Signal", schema.module_name),
+                    format!("{}_drop_signal", snake_case(&schema.module_name)),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let forward_decls = signal_enums
+            .iter()
+            .map(|(signal_enum, drop_fn)| {
+                format!("struct {signal_enum};\nvoid {drop_fn}({signal_enum} *signal) noexcept;")
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
 
-            {emit_impl}
+        let emit_impls = signal_enums
+            .iter()
+            .map(|(signal_enum, drop_fn)| {
+                formatdoc! {
+                    r#"
+                    void emit{signal_enum}(uintptr_t id, uint64_t generation, rust::Str name, craby::{flat_name}::bridging::{signal_enum}* signal) const {{
+                      // The signal is dropped if the module is unregistered or replaced (eg. emitted by the detached emitter)
+                      if (!dispatch(id, generation, name, reinterpret_cast<void*>(signal))) {{
+                        craby::{flat_name}::bridging::{drop_fn}(signal);
+                      }}
+                    }}"#,
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        let emit_impls = indent_str(&emit_impls, 2);
 
-            {register_delegate_impl}
+        Ok(formatdoc! {
+            r#"
+            #pragma once
 
-            void unregisterDelegate(uintptr_t id) const {{
-              std::lock_guard<std::mutex> lock(mutex_);
-              delegates_.erase(id);
+            #include "rust/cxx.h"
+            #include <functional>
+            #include <memory>
+            #include <mutex>
+            #include <unordered_map>
+
+            namespace craby {{
+            namespace {flat_name} {{
+            namespace bridging {{
+            {forward_decls}
+            }} // namespace bridging
+
+            namespace signals {{
+
+            using Delegate = std::function<void(const std::string& signalName, void* signal)>;
+            using ListenerQuery = std::function<bool(const std::string& signalName)>;
+
+            class SignalManager {{
+            public:
+              static SignalManager& getInstance() {{
+                static SignalManager instance;
+                return instance;
+              }}
+
+            {emit_impls}
+
+              // Returns `true` if the signal of the module has any JS listener
+              bool hasListeners(uintptr_t id, rust::Str name) const {{
+                std::lock_guard<std::mutex> lock(mutex_);
+                auto it = listenerQueries_.find(id);
+                return it != listenerQueries_.end() && it->second(std::string(name));
+              }}
+
+              void registerDelegate(uintptr_t id, uint64_t generation, Delegate delegate, ListenerQuery hasListeners) const {{
+                std::lock_guard<std::mutex> lock(mutex_);
+                generations_.insert_or_assign(id, generation);
+                delegates_.insert_or_assign(id, delegate);
+                listenerQueries_.insert_or_assign(id, hasListeners);
+              }}
+
+              void unregisterDelegate(uintptr_t id) const {{
+                std::lock_guard<std::mutex> lock(mutex_);
+                generations_.erase(id);
+                delegates_.erase(id);
+                listenerQueries_.erase(id);
+              }}
+
+            private:
+              SignalManager() = default;
+
+              // The signal is passed to the delegate of the module as is (type-erased)
+              //
+              // The id (address) may be reused by the next module, so the generation of the emitter must match
+              bool dispatch(uintptr_t id, uint64_t generation, rust::Str name, void* signal) const {{
+                std::lock_guard<std::mutex> lock(mutex_);
+                auto gen = generations_.find(id);
+                if (gen == generations_.end() || gen->second != generation) {{
+                  return false;
+                }}
+                auto it = delegates_.find(id);
+                if (it == delegates_.end()) {{
+                  return false;
+                }}
+                it->second(std::string(name), signal);
+                return true;
+              }}
+
+              mutable std::unordered_map<uintptr_t, uint64_t> generations_;
+              mutable std::unordered_map<uintptr_t, Delegate> delegates_;
+              mutable std::unordered_map<uintptr_t, ListenerQuery> listenerQueries_;
+              mutable std::mutex mutex_;
+            }};
+
+            inline const SignalManager& getSignalManager() {{
+              return SignalManager::getInstance();
             }}
 
-          private:
-            SignalManager() = default;
-            {delegates_map}
-            mutable std::mutex mutex_;
-          }};
-
-          inline const SignalManager& getSignalManager() {{
-            return SignalManager::getInstance();
-          }}
-
-          }} // namespace signals
-          }} // namespace {flat_name}
-          }} // namespace craby"#,
-          flat_name = flat_name,
-          forward_declarations = if let (Some(ref enum_name), Some(ref mod_name)) = (&signal_enum, &cxx_mod) {
-              formatdoc! {
-                  r#"
-                  namespace craby {{
-                  namespace {flat_name} {{
-                  namespace bridging {{
-                    struct {enum_name};
+            }} // namespace signals
+            }} // namespace {flat_name}
+            }} // namespace craby"#,
+        })
+    }
+
+    /// Generates the callback handle header file for function parameters.
+    ///
+    /// # Generated Code
+    ///
+    /// ```cpp
+    /// #pragma once
+    ///
+    /// #include "rust/cxx.h"
+    /// #include <functional>
+    /// #include <utility>
+    ///
+    /// namespace craby {
+    /// namespace mymodule {
+    /// namespace bridging {
+    /// struct MyStruct;
+    /// } // namespace bridging
+    ///
+    /// namespace callbacks {
+    ///
+    /// template <typename... Args>
+    /// class Callback {
+    ///   // ...
+    /// };
+    ///
+    /// using MyStructCallback = Callback<craby::mymodule::bridging::MyStruct>;
+    /// using NumberCallback = Callback<double>;
+    ///
+    /// } // namespace callbacks
+    /// } // namespace mymodule
+    /// } // namespace craby
+    /// ```
+    fn cxx_callbacks(
+        &self,
+        project_name: &str,
+        schemas: &[Schema],
+    ) -> Result<String, anyhow::Error> {
+        let cxx_ns = CxxNamespace::from(project_name);
+        let flat_name = flat_case(project_name);
+        let mut aliases = BTreeMap::new();
+        let mut forward_decls = BTreeMap::new();
+
+        for schema in schemas {
+            for type_annotation in schema.collect_callback_types() {
+                let params = &type_annotation.as_function().unwrap().params;
+                let param_types = params
+                    .iter()
+                    .map(|param| param.as_cxx_type(&cxx_ns))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                for param in params {
+                    collect_forward_decls(param, &mut forward_decls)?;
+                }
+
+                aliases.insert(
+                    type_annotation.as_rs_struct_name()?,
+                    param_types.join(", "),
+                );
+            }
+        }
+
+        let forward_decls = forward_decls.into_values().collect::<Vec<_>>().join("\n");
+        let aliases = aliases
+            .into_iter()
+            .map(|(name, param_types)| format!("using {name} = Callback<{param_types}>;"))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Ok(formatdoc! {
+            r#"
+            #pragma once
+
+            #include "rust/cxx.h"
+            #include <functional>
+            #include <utility>
+
+            namespace craby {{
+            namespace {flat_name} {{
+            namespace bridging {{
+            {forward_decls}
+            }} // namespace bridging
+
+            namespace callbacks {{
+
+            template <typename... Args>
+            class Callback {{
+            public:
+              using Invoker = std::function<void(Args...)>;
+              using Releaser = std::function<void()>;
+
+              Callback(Invoker invoker, Releaser releaser)
+                  : invoker_(std::move(invoker)), releaser_(std::move(releaser)) {{}}
+
+              Callback(const Callback &) = delete;
+              Callback &operator=(const Callback &) = delete;
+
+              ~Callback() {{
+                // Drop the invoker first so that the releaser holds the last reference of the JS function
+                invoker_ = nullptr;
+                releaser_();
+              }}
+
+              void call(Args... args) const {{
+                invoker_(std::move(args)...);
+              }}
+
+            private:
+              Invoker invoker_;
+              Releaser releaser_;
+            }};
+
+            {aliases}
+
+            }} // namespace callbacks
+            }} // namespace {flat_name}
+            }} // namespace craby"#,
+        })
+    }
+
+    /// Generates the stream handle header file for `AsyncIterable` return types.
+    ///
+    /// # Generated Code
+    ///
+    /// ```cpp
+    /// #pragma once
+    ///
+    /// #include "rust/cxx.h"
+    /// #include <condition_variable>
+    /// #include <deque>
+    /// // ...
+    ///
+    /// namespace craby {
+    /// namespace mymodule {
+    /// namespace bridging {
+    /// struct MyStruct;
+    /// } // namespace bridging
+    ///
+    /// namespace streams {
+    ///
+    /// template <typename T>
+    /// class Stream : public StreamBase {
+    ///   // ...
+    /// };
+    ///
+    /// using MyStructStream = Stream<craby::mymodule::bridging::MyStruct>;
+    /// using NumberStream = Stream<double>;
+    ///
+    /// } // namespace streams
+    /// } // namespace mymodule
+    /// } // namespace craby
+    /// ```
+    fn cxx_streams(&self, project_name: &str, schemas: &[Schema]) -> Result<String, anyhow::Error> {
+        let cxx_ns = CxxNamespace::from(project_name);
+        let flat_name = flat_case(project_name);
+        let mut aliases = BTreeMap::new();
+        let mut forward_decls = BTreeMap::new();
+
+        for schema in schemas {
+            for type_annotation in schema.collect_stream_types() {
+                let item_type = type_annotation.as_stream().unwrap();
+                collect_forward_decls(item_type, &mut forward_decls)?;
+                aliases.insert(
+                    type_annotation.as_rs_struct_name()?,
+                    item_type.as_cxx_type(&cxx_ns)?,
+                );
+            }
+        }
+
+        let forward_decls = forward_decls.into_values().collect::<Vec<_>>().join("\n");
+        let aliases = aliases
+            .into_iter()
+            .map(|(name, item_type)| format!("using {name} = Stream<{item_type}>;"))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Ok(formatdoc! {
+            r#"
+            #pragma once
+
+            #include "rust/cxx.h"
+            #include <algorithm>
+            #include <condition_variable>
+            #include <deque>
+            #include <functional>
+            #include <memory>
+            #include <mutex>
+            #include <optional>
+            #include <string>
+            #include <utility>
+            #include <vector>
+
+            namespace craby {{
+            namespace {flat_name} {{
+            namespace bridging {{
+            {forward_decls}
+            }} // namespace bridging
+
+            namespace streams {{
+
+            class StreamBase {{
+            public:
+              enum class Poll {{ Ready, Pending, Done, Error }};
+
+              virtual ~StreamBase() = default;
+              virtual void cancel() const = 0;
+            }};
+
+            template <typename T>
+            class Stream : public StreamBase {{
+            public:
+              using Item = T;
+              using Notifier = std::function<void()>;
+
+              explicit Stream(size_t capacity = 16) : capacity_(capacity) {{}}
+
+              Stream(const Stream &) = delete;
+              Stream &operator=(const Stream &) = delete;
+
+              void setNotifier(Notifier notifier) const {{
+                std::lock_guard<std::mutex> lock(mutex_);
+                notifier_ = std::move(notifier);
+              }}
+
+              // Blocks the producer while the buffer is full (the consumer is not pulling the items)
+              bool send(T item) const {{
+                Notifier notifier;
+                {{
+                  std::unique_lock<std::mutex> lock(mutex_);
+                  condition_.wait(lock, [this] {{ return finished_ || items_.size() < capacity_; }});
+                  if (finished_) {{
+                    return false;
                   }}
-                  namespace modules {{
-                    class {mod_name};
+                  items_.push_back(std::move(item));
+                  notifier = notifier_;
+                }}
+
+                if (notifier) {{
+                  notifier();
+                }}
+                return true;
+              }}
+
+              bool close() const {{
+                return finish(std::nullopt);
+              }}
+
+              bool error(rust::Str message) const {{
+                return finish(std::string(message));
+              }}
+
+              // Drops the buffered items and releases the blocked producer
+              void cancel() const override {{
+                {{
+                  std::lock_guard<std::mutex> lock(mutex_);
+                  finished_ = true;
+                  items_.clear();
+                  error_.reset();
+                  notifier_ = nullptr;
+                }}
+                condition_.notify_all();
+              }}
+
+              // The buffered items are pulled before the completion (or the error) of the stream
+              Poll poll(std::optional<T> &item, std::string &error) const {{
+                std::lock_guard<std::mutex> lock(mutex_);
+                if (!items_.empty()) {{
+                  item.emplace(std::move(items_.front()));
+                  items_.pop_front();
+                  condition_.notify_one();
+                  return Poll::Ready;
+                }}
+
+                if (error_) {{
+                  error = std::move(*error_);
+                  error_.reset();
+                  return Poll::Error;
+                }}
+
+                return finished_ ? Poll::Done : Poll::Pending;
+              }}
+
+            private:
+              bool finish(std::optional<std::string> error) const {{
+                Notifier notifier;
+                {{
+                  std::lock_guard<std::mutex> lock(mutex_);
+                  if (finished_) {{
+                    return false;
                   }}
+                  finished_ = true;
+                  error_ = std::move(error);
+                  notifier = notifier_;
+                }}
+
+                condition_.notify_all();
+                if (notifier) {{
+                  notifier();
+                }}
+                return true;
+              }}
+
+              size_t capacity_;
+              mutable std::mutex mutex_;
+              mutable std::condition_variable condition_;
+              mutable std::deque<T> items_;
+              mutable bool finished_ = false;
+              mutable std::optional<std::string> error_;
+              mutable Notifier notifier_;
+            }};
+
+            class StreamRegistry {{
+            public:
+              void add(std::shared_ptr<StreamBase> stream) {{
+                std::lock_guard<std::mutex> lock(mutex_);
+                streams_.erase(
+                    std::remove_if(streams_.begin(), streams_.end(),
+                                   [](const auto &stream) {{ return stream.expired(); }}),
+                    streams_.end());
+                streams_.push_back(stream);
+              }}
+
+              void cancelAll() {{
+                std::vector<std::weak_ptr<StreamBase>> streams;
+                {{
+                  std::lock_guard<std::mutex> lock(mutex_);
+                  std::swap(streams, streams_);
+                }}
+
+                for (auto &stream : streams) {{
+                  if (auto ptr = stream.lock()) {{
+                    ptr->cancel();
                   }}
-                  }}"#,
-                  enum_name = enum_name,
-                  mod_name = mod_name,
-                  flat_name = flat_name
-              }
-          } else {
-              String::new()
-          },
-          signal_delegate_typedef = if signal_enum.is_some() {
-              formatdoc! {
-                  r#"
-                  using Delegate = std::function<void(const std::string& signalName, void* signal)>;"#
-              }
-          } else {
-              String::new()
-          },
-          emit_impl = if let Some(ref enum_name) = signal_enum {
-              formatdoc! {
-                  r#"
-                  void emit(uintptr_t id, rust::Str name, craby::{flat_name}::bridging::{enum_name}* signal) const {{
-                      std::lock_guard<std::mutex> lock(mutex_);
-                      auto it = delegates_.find(id);
-                      if (it != delegates_.end()) {{
-                        it->second(std::string(name), reinterpret_cast<void*>(signal));
-                      }}
-                    }}"#,
-                  enum_name = enum_name,
-                  flat_name = flat_name
-              }
-          } else {
-              String::new()
-          },
-          register_delegate_impl = if signal_enum.is_some() {
-              formatdoc! {
-                  r#"
-                  void registerDelegate(uintptr_t id, Delegate delegate) const {{
-                      std::lock_guard<std::mutex> lock(mutex_);
-                      delegates_.insert_or_assign(id, delegate);
-                    }}"#
-              }
-          } else {
-              String::new()
-          },
-          delegates_map = if signal_enum.is_some() {
-              formatdoc! {
-                  r#"
-                  mutable std::unordered_map<uintptr_t, Delegate> delegates_;"#
-              }
-          } else {
-              String::new()
-          },
-      })
-  }
+                }}
+              }}
+
+            private:
+              std::mutex mutex_;
+              std::vector<std::weak_ptr<StreamBase>> streams_;
+            }};
+
+            {aliases}
+
+            }} // namespace streams
+            }} // namespace {flat_name}
+            }} // namespace craby"#,
+        })
+    }
+
+    /// Generates the panic reporter header file for the `panic = "abort"` mode.
+    ///
+    /// # Generated Code
+    ///
+    /// ```cpp
+    /// #pragma once
+    ///
+    /// #include "rust/cxx.h"
+    /// // ...
+    ///
+    /// namespace craby {
+    /// namespace mymodule {
+    /// namespace panics {
+    ///
+    /// using Reporter = std::function<void(const std::string &message)>;
+    ///
+    /// class PanicReporter {
+    ///   // ...
+    /// };
+    ///
+    /// inline void reportPanic(rust::Str message) {
+    ///   PanicReporter::getInstance().report(std::string(message));
+    /// }
+    ///
+    /// } // namespace panics
+    /// } // namespace mymodule
+    /// } // namespace craby
+    /// ```
+    fn cxx_panics(&self, project_name: &str) -> String {
+        let flat_name = flat_case(project_name);
+
+        formatdoc! {
+            r#"
+            #pragma once
+
+            #include "rust/cxx.h"
+            #include <cstdint>
+            #include <functional>
+            #include <mutex>
+            #include <string>
+            #include <unordered_map>
+
+            namespace craby {{
+            namespace {flat_name} {{
+            namespace panics {{
+
+            using Reporter = std::function<void(const std::string &message)>;
+
+            class PanicReporter {{
+            public:
+              static PanicReporter &getInstance() {{
+                static PanicReporter instance;
+                return instance;
+              }}
+
+              // The modules share the same JS runtime, so the panic is reported once
+              void report(const std::string &message) const {{
+                Reporter reporter;
+                {{
+                  std::lock_guard<std::mutex> lock(mutex_);
+                  if (reporters_.empty()) {{
+                    return;
+                  }}
+                  reporter = reporters_.begin()->second;
+                }}
+
+                reporter(message);
+              }}
+
+              void registerReporter(uintptr_t id, Reporter reporter) const {{
+                std::lock_guard<std::mutex> lock(mutex_);
+                reporters_.insert_or_assign(id, reporter);
+              }}
+
+              void unregisterReporter(uintptr_t id) const {{
+                std::lock_guard<std::mutex> lock(mutex_);
+                reporters_.erase(id);
+              }}
+
+            private:
+              PanicReporter() = default;
+              mutable std::unordered_map<uintptr_t, Reporter> reporters_;
+              mutable std::mutex mutex_;
+            }};
+
+            // Called by the panic hook (Rust) before the process is aborted
+            inline void reportPanic(rust::Str message) {{
+              PanicReporter::getInstance().report(std::string(message));
+            }}
+
+            }} // namespace panics
+            }} // namespace {flat_name}
+            }} // namespace craby"#,
+        }
+    }
+}
+
+/// Collects the forward declarations of the bridging types that the callback parameter refers to.
+fn collect_forward_decls(
+    type_annotation: &TypeAnnotation,
+    forward_decls: &mut BTreeMap<String, String>,
+) -> Result<(), anyhow::Error> {
+    match type_annotation {
+        TypeAnnotation::Enum(..) => {
+            let name = type_annotation.as_rs_struct_name()?;
+            forward_decls.insert(name.clone(), format!("enum class {name} : uint8_t;"));
+        }
+        TypeAnnotation::Object(..)
+        | TypeAnnotation::Nullable(..)
+        | TypeAnnotation::Map(..)
+        | TypeAnnotation::Union(..) => {
+            let name = type_annotation.as_rs_struct_name()?;
+            forward_decls.insert(name.clone(), format!("struct {name};"));
+        }
+        // Nested arrays and binary types are wrapped with a generated struct
+        TypeAnnotation::Array(element_type) if element_type.is_rs_wrapped_element() => {
+            let name = element_type.as_rs_generated_struct_name()?;
+            forward_decls.insert(name.clone(), format!("struct {name};"));
+        }
+        TypeAnnotation::Array(element_type) => collect_forward_decls(element_type, forward_decls)?,
+        _ => {}
+    }
+
+    Ok(())
 }
 
 impl Template for CxxTemplate {
@@ -1123,7 +2431,12 @@ impl Template for CxxTemplate {
                 .schemas
                 .iter()
                 .map(|schema| -> Result<Vec<TemplateResult>, anyhow::Error> {
-                    let (cpp, hpp) = self.cxx_mod(schema, &ctx.project_name)?;
+                    let (cpp, hpp) = self.cxx_mod(
+                        schema,
+                        &ctx.project_name,
+                        ctx.panic_strategy,
+                        &ctx.thread_pool,
+                    )?;
                     let cxx_mod = CxxModuleName::from(&schema.module_name);
                     let cxx_base_path = cxx_dir(&ctx.root);
                     let files = vec![
@@ -1149,7 +2462,7 @@ impl Template for CxxTemplate {
             }],
             CxxFileType::UtilsHpp => vec![TemplateResult {
                 path: cxx_dir(&ctx.root).join("CrabyUtils.hpp"),
-                content: self.cxx_utils(&ctx.project_name)?,
+                content: self.cxx_utils(&ctx.project_name, ctx.panic_strategy)?,
                 overwrite: true,
             }],
             CxxFileType::SignalsH => {
@@ -1165,6 +2478,46 @@ impl Template for CxxTemplate {
                     Vec::default()
                 }
             }
+            CxxFileType::StreamsH => {
+                let has_streams = ctx
+                    .schemas
+                    .iter()
+                    .any(|schema| !schema.collect_stream_types().is_empty());
+
+                if has_streams {
+                    vec![TemplateResult {
+                        path: cxx_bridge_include_dir(&ctx.root).join("CrabyStreams.h"),
+                        content: self.cxx_streams(&ctx.project_name, &ctx.schemas)?,
+                        overwrite: true,
+                    }]
+                } else {
+                    Vec::default()
+                }
+            }
+            CxxFileType::PanicsH => match ctx.panic_strategy {
+                PanicStrategy::Unwind => Vec::default(),
+                PanicStrategy::Abort => vec![TemplateResult {
+                    path: cxx_bridge_include_dir(&ctx.root).join("CrabyPanics.h"),
+                    content: self.cxx_panics(&ctx.project_name),
+                    overwrite: true,
+                }],
+            },
+            CxxFileType::CallbacksH => {
+                let has_callbacks = ctx
+                    .schemas
+                    .iter()
+                    .any(|schema| !schema.collect_callback_types().is_empty());
+
+                if has_callbacks {
+                    vec![TemplateResult {
+                        path: cxx_bridge_include_dir(&ctx.root).join("CrabyCallbacks.h"),
+                        content: self.cxx_callbacks(&ctx.project_name, &ctx.schemas)?,
+                        overwrite: true,
+                    }]
+                } else {
+                    Vec::default()
+                }
+            }
         };
 
         Ok(res)
@@ -1212,6 +2565,9 @@ impl Generator<CxxTemplate> for CxxGenerator {
             template.render(ctx, &CxxFileType::BridgingHpp)?,
             template.render(ctx, &CxxFileType::UtilsHpp)?,
             template.render(ctx, &CxxFileType::SignalsH)?,
+            template.render(ctx, &CxxFileType::CallbacksH)?,
+            template.render(ctx, &CxxFileType::StreamsH)?,
+            template.render(ctx, &CxxFileType::PanicsH)?,
         ]
         .into_iter()
         .flatten()
@@ -1235,7 +2591,10 @@ impl GeneratorInvoker for CxxGenerator {
 mod tests {
     use insta::assert_snapshot;
 
-    use crate::tests::get_codegen_context;
+    use crate::tests::{
+        get_binary_arrays_codegen_context, get_codegen_context, get_serial_codegen_context,
+        get_shared_types_codegen_context, get_signals_codegen_context,
+    };
 
     use super::*;
 
@@ -1252,4 +2611,156 @@ mod tests {
 
         assert_snapshot!(result);
     }
+    #[test]
+    fn test_cxx_generator_shared_types() {
+        let ctx = get_shared_types_codegen_context();
+        let bridging_h = CxxTemplate.cxx_bridging(&ctx).unwrap();
+
+        for name in ["Shared", "NullableShared", "Kind"] {
+            let template = format!("struct Bridging<craby::testmodule::bridging::{name}> {{");
+            assert_eq!(bridging_h.matches(&template).count(), 1);
+        }
+    }
+
+    #[test]
+    fn test_cxx_generator_binary_arrays() {
+        let ctx = get_binary_arrays_codegen_context();
+        let bridging_h = CxxTemplate.cxx_bridging(&ctx).unwrap();
+
+        for name in ["ArrayBufferItem", "Uint8ArrayItem", "Float32ArrayItem"] {
+            let template = format!("struct Bridging<craby::testmodule::bridging::{name}> {{");
+            assert_eq!(bridging_h.matches(&template).count(), 1);
+        }
+        // The wrapped `Uint8Array` is converted to `Uint8Array` (not `ArrayBuffer`)
+        assert!(bridging_h
+            .contains("return react::TypedArrayBridging<uint8_t>::toJs(rt, value.val, \"Uint8Array\");"));
+    }
+
+    #[test]
+    fn test_cxx_generator_typed_arrays() {
+        let ctx = get_codegen_context();
+        let bridging_h = CxxTemplate.cxx_bridging(&ctx).unwrap();
+
+        // `rust::Vec<double>` and `rust::Vec<int64_t>` are shared with `number[]` and `bigint[]`
+        for elem in ["double", "int64_t", "uint64_t"] {
+            let template = format!("struct Bridging<rust::Vec<{elem}>> {{");
+            assert!(!bridging_h.contains(&template));
+        }
+        assert!(bridging_h.contains("throw jsi::JSError(rt, \"Invalid typed array value (\" + std::string(name) + \")\");"));
+        assert!(bridging_h.contains("throw jsi::JSError(rt, \"Invalid typed array length (\" + std::string(name) + \")\");"));
+    }
+
+    #[test]
+    fn test_cxx_generator_multiple_signals() {
+        let ctx = get_signals_codegen_context();
+        let signals_h = CxxTemplate
+            .cxx_signals(&ctx.project_name, &ctx.schemas)
+            .unwrap();
+
+        for (module_name, snake_name, signal_enum) in [
+            ("FooModule", "foo_module", "FooModuleSignal"),
+            ("BarModule", "bar_module", "BarModuleSignal"),
+        ] {
+            let (cpp, _) = CxxTemplate
+                .cxx_mod(
+                    ctx.schemas
+                        .iter()
+                        .find(|schema| schema.module_name == module_name)
+                        .unwrap(),
+                    &ctx.project_name,
+                    ctx.panic_strategy,
+                    &ctx.thread_pool,
+                )
+                .unwrap();
+            let drop_fn = format!("{snake_name}_drop_signal(");
+
+            assert_eq!(signals_h.matches(&format!("struct {signal_enum};")).count(), 1);
+            assert_eq!(signals_h.matches(&format!("void emit{signal_enum}(")).count(), 1);
+            assert_eq!(cpp.matches(&drop_fn).count(), 1);
+            assert!(cpp.contains(&format!("{snake_name}_on_listener_added(")));
+            assert!(cpp.contains(&format!("{snake_name}_on_listener_removed(")));
+        }
+    }
+
+    #[test]
+    fn test_cxx_generator_signal_policies() {
+        let ctx = get_signals_codegen_context();
+        let cxx_mod = |module_name: &str| {
+            let schema = ctx
+                .schemas
+                .iter()
+                .find(|schema| schema.module_name == module_name)
+                .unwrap();
+            let (cpp, _) = CxxTemplate
+                .cxx_mod(
+                    schema,
+                    &ctx.project_name,
+                    ctx.panic_strategy,
+                    &ctx.thread_pool,
+                )
+                .unwrap();
+            cpp
+        };
+        let foo_cpp = cxx_mod("FooModule");
+        let bar_cpp = cxx_mod("BarModule");
+
+        assert!(foo_cpp.contains(
+            "SignalPolicy{.throttle = std::chrono::milliseconds(16), .latestOnly = true}"
+        ));
+        assert!(foo_cpp.contains("SignalPolicy{}"));
+        assert!(bar_cpp.contains("SignalPolicy{.bufferSize = 8}"));
+    }
+
+    #[test]
+    fn test_cxx_generator_thread_pool() {
+        let mut ctx = get_serial_codegen_context();
+        let cxx_mod = |ctx: &CodegenContext| {
+            let (cpp, hpp) = CxxTemplate
+                .cxx_mod(
+                    &ctx.schemas[0],
+                    &ctx.project_name,
+                    ctx.panic_strategy,
+                    &ctx.thread_pool,
+                )
+                .unwrap();
+            (cpp, hpp)
+        };
+
+        let (cpp, hpp) = cxx_mod(&ctx);
+        assert!(cpp.contains("std::make_shared<craby::testmodule::utils::ThreadPool>(10, \"SerialModule\");"));
+        assert!(cpp.contains("serialQueues_[\"storage\"] = std::make_shared<craby::testmodule::utils::ThreadPool>(1, \"SerialModule-storage\");"));
+        assert!(cpp.contains("serialQueues_[\"watch\"] = std::make_shared<craby::testmodule::utils::ThreadPool>(1, \"SerialModule-watch\");"));
+        assert_eq!(cpp.matches("thisModule.serialQueues_.at(\"storage\")->enqueue(").count(), 2);
+        assert_eq!(cpp.matches("thisModule.serialQueues_.at(\"watch\")->enqueue(").count(), 1);
+        assert_eq!(cpp.matches("thisModule.threadPool_->enqueue(").count(), 1);
+        assert!(hpp.contains("serialQueues_;"));
+
+        ctx.thread_pool = ThreadPoolConfig {
+            size: ThreadPoolSize::Auto,
+            name: Some("worker".to_string()),
+        };
+        let (cpp, _) = cxx_mod(&ctx);
+        assert!(cpp.contains("std::make_shared<craby::testmodule::utils::ThreadPool>(craby::testmodule::utils::ThreadPool::hardwareConcurrency(), \"worker\");"));
+        assert!(cpp.contains("std::make_shared<craby::testmodule::utils::ThreadPool>(1, \"worker-storage\");"));
+    }
+
+    #[test]
+    fn test_cxx_generator_panic_abort() {
+        let mut ctx = get_codegen_context();
+        ctx.panic_strategy = PanicStrategy::Abort;
+        let generator = CxxGenerator::new();
+        let results = generator.generate(&ctx).unwrap();
+        let content_of = |file_name: &str| {
+            results
+                .iter()
+                .find(|res| res.path.ends_with(file_name))
+                .map(|res| res.content.as_str())
+                .unwrap()
+        };
+
+        assert!(content_of("CrabyPanics.h").contains("inline void reportPanic(rust::Str message)"));
+        assert!(content_of("CrabyUtils.hpp").contains("inline void reportPanic("));
+        assert!(content_of("CxxCrabyTestModule.cpp").contains("registerReporter("));
+        assert!(content_of("CxxCrabyTestModule.cpp").contains("unregisterReporter("));
+    }
 }
@@ -1,6 +1,7 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use craby_common::{
+    config::PanicStrategy,
     constants::{HASH_COMMENT_PREFIX, crate_dir, impl_mod_name},
     utils::string::{pascal_case, snake_case},
 };
@@ -9,6 +10,7 @@ use indoc::formatdoc;
 use crate::{
     common::IntoCode,
     generators::types::TemplateResult,
+    parser::types::EnumMemberValue,
     platform::rust::RsCxxBridge,
     types::{CodegenContext, CxxNamespace, Schema},
     utils::indent_str,
@@ -38,10 +40,14 @@ impl RsTemplate {
             .collect::<Vec<String>>()
     }
 
-    fn rs_cxx_bridges(&self, schemas: &[Schema]) -> Result<Vec<RsCxxBridge>, anyhow::Error> {
+    fn rs_cxx_bridges(
+        &self,
+        schemas: &[Schema],
+        panic_strategy: PanicStrategy,
+    ) -> Result<Vec<RsCxxBridge>, anyhow::Error> {
         let res = schemas
             .iter()
-            .map(|schema| schema.as_rs_cxx_bridge())
+            .map(|schema| schema.as_rs_cxx_bridge(panic_strategy))
             .collect::<Result<Vec<_>, _>>()?;
 
         Ok(res)
@@ -81,14 +87,29 @@ impl RsTemplate {
         rs_cxx_bridges: &[RsCxxBridge],
         has_signals: bool,
         schemas: &[Schema],
+        cxx_handles: &[&str],
     ) -> String {
+        // Types shared between the modules are defined only once
+        let mut type_defs = BTreeSet::new();
         let (impl_types, cxx_externs, struct_defs, enum_defs) = rs_cxx_bridges.iter().fold(
             (vec![], vec![], vec![], vec![]),
             |(mut impl_types, mut externs, mut structs, mut enums), bridge| {
                 impl_types.push(bridge.impl_type.clone());
                 externs.extend(bridge.func_extern_sigs.clone());
-                structs.extend(bridge.struct_defs.clone());
-                enums.extend(bridge.enum_defs.clone());
+                structs.extend(
+                    bridge
+                        .struct_defs
+                        .iter()
+                        .filter(|def| type_defs.insert(*def))
+                        .cloned(),
+                );
+                enums.extend(
+                    bridge
+                        .enum_defs
+                        .iter()
+                        .filter(|def| type_defs.insert(*def))
+                        .cloned(),
+                );
                 (impl_types, externs, structs, enums)
             },
         );
@@ -109,15 +130,17 @@ impl RsTemplate {
                 }
                 
                 let signal_enum_name = format!("{}Signal", schema.module_name);
+                let mod_name = snake_case(&schema.module_name);
                 let mut functions = vec![format!("type {};", signal_enum_name)];
                 
                 // Generate payload extraction function for each signal
+                // (prefixed with the module name to avoid conflicts between the modules)
                 for signal in &schema.signals {
                     if let Some(payload_type) = &signal.payload_type {
                         let payload_type_name = payload_type.as_rs_type()
                             .map(|t| t.into_code())
                             .unwrap_or_else(|_| "String".to_string());
-                        let function_name = format!("get_{}_payload", snake_case(&signal.name));
+                        let function_name = format!("{mod_name}_get_{}_payload", snake_case(&signal.name));
                         functions.push(format!(
                             "fn {}(s: &{}) -> {};",
                             function_name, signal_enum_name, payload_type_name
@@ -127,12 +150,25 @@ impl RsTemplate {
                 
                 // Add drop_signal function for memory management
                 functions.push(format!(
-                    "unsafe fn drop_signal(signal: *mut {});",
+                    "unsafe fn {mod_name}_drop_signal(signal: *mut {});",
                     signal_enum_name
                 ));
+
+                // Listener hooks called when the JS listener is added or removed
+                let module_name = pascal_case(&schema.module_name);
+                functions.push(format!(
+                    "fn {mod_name}_on_listener_added(it_: &mut {module_name}, name: &str) -> Result<()>;"
+                ));
+                functions.push(format!(
+                    "fn {mod_name}_on_listener_removed(it_: &mut {module_name}, name: &str) -> Result<()>;"
+                ));
                 
                 functions
-            }).collect::<Vec<_>>()
+            }).chain([
+                // The generation of the module is registered on creation and checked when emitting the signals
+                "fn register_signals(id: usize) -> u64;".to_string(),
+                "fn unregister_signals(id: usize);".to_string(),
+            ]).collect::<Vec<_>>()
         } else {
             vec![]
         };
@@ -150,13 +186,25 @@ impl RsTemplate {
         };
 
         let cxx_signal_manager = if has_signals {
-            // Get signal enum type for each schema
-            let signal_enum_types: Vec<String> = schemas.iter()
+            // Each module emits its own signal enum type through the dedicated entry point
+            //
+            // ```rust,ignore
+            // #[rust_name = "emit_my_module_signal"]
+            // unsafe fn emitMyModuleSignal(self: &SignalManager, id: usize, generation: u64, name: &str, signal: *mut MyModuleSignal);
+            // ```
+            let emit_fns = schemas.iter()
                 .filter(|s| !s.signals.is_empty())
-                .map(|s| format!("{}Signal", s.module_name))
-                .collect();
-            
-            let signal_type = signal_enum_types.first().unwrap().clone();
+                .map(|s| {
+                    let signal_type = format!("{}Signal", s.module_name);
+                    formatdoc! {
+                        r#"
+                        #[rust_name = "{rust_name}"]
+                        unsafe fn emit{signal_type}(self: &SignalManager, id: usize, generation: u64, name: &str, signal: *mut {signal_type});"#,
+                        rust_name = format!("emit_{}", snake_case(&signal_type)),
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("\n\n");
             
             formatdoc! {
                 r#"
@@ -166,12 +214,15 @@ impl RsTemplate {
 
                     type SignalManager;
 
-                    unsafe fn emit(self: &SignalManager, id: usize, name: &str, signal: *mut {signal_type});
+                {emit_fns}
+
+                    #[rust_name = "has_listeners"]
+                    fn hasListeners(self: &SignalManager, id: usize, name: &str) -> bool;
                     
                     #[rust_name = "get_signal_manager"]
                     fn getSignalManager() -> &'static SignalManager;
                 }}"#,
-                signal_type = signal_type,
+                emit_fns = indent_str(&emit_fns, 4),
             }
         } else {
             String::new()
@@ -186,8 +237,10 @@ impl RsTemplate {
                 cxx_signal_manager,
             ]
             .iter()
-            .filter(|s| !s.is_empty())
             .map(|s| s.as_str())
+            // Callback, stream, abort controller handle types and panic reporter
+            .chain(cxx_handles.iter().copied())
+            .filter(|s| !s.is_empty())
             .collect::<Vec<_>>()
             .join("\n\n"),
             4,
@@ -202,6 +255,325 @@ impl RsTemplate {
         }
     }
 
+    /// Generates the callback handle types (C++ opaque types) and their `Send`/`Sync` implementations.
+    ///
+    /// # Generated Code
+    ///
+    /// ```rust,ignore
+    /// #[namespace = "craby::mymodule::callbacks"]
+    /// unsafe extern "C++" {
+    ///     include!("CrabyCallbacks.h");
+    ///
+    ///     type NumberCallback;
+    ///
+    ///     fn call(self: &NumberCallback, arg_0: f64);
+    /// }
+    ///
+    /// // ...
+    ///
+    /// unsafe impl Send for NumberCallback {}
+    /// unsafe impl Sync for NumberCallback {}
+    /// ```
+    fn rs_cxx_callbacks(
+        &self,
+        cxx_ns: &CxxNamespace,
+        schemas: &[Schema],
+    ) -> Result<(String, String), anyhow::Error> {
+        let mut callbacks = BTreeMap::new();
+
+        for schema in schemas {
+            for type_annotation in schema.collect_callback_types() {
+                let name = type_annotation.as_rs_struct_name()?;
+                let params = type_annotation
+                    .as_function()
+                    .unwrap()
+                    .params
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, param)| -> Result<String, anyhow::Error> {
+                        Ok(format!("arg_{idx}: {}", param.as_rs_type()?.into_code()))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let params_sig = [vec![format!("self: &{name}")], params]
+                    .concat()
+                    .join(", ");
+                callbacks.insert(name, params_sig);
+            }
+        }
+
+        if callbacks.is_empty() {
+            return Ok((String::new(), String::new()));
+        }
+
+        let callback_decls = callbacks
+            .iter()
+            .map(|(name, params_sig)| {
+                formatdoc! {
+                    r#"
+                    type {name};
+
+                    fn call({params_sig});"#,
+                }
+            })
+            .collect::<Vec<_>>();
+        let callback_decls = indent_str(&callback_decls.join("\n\n"), 4);
+
+        let cxx_extern = formatdoc! {
+            r#"
+            #[namespace = "{cxx_ns}::callbacks"]
+            unsafe extern "C++" {{
+                include!("CrabyCallbacks.h");
+
+            {callback_decls}
+            }}"#,
+        };
+
+        // Calls are dispatched to the JS thread by the C++ side, so the handles can be used from any thread
+        let send_impls = callbacks
+            .keys()
+            .map(|name| {
+                formatdoc! {
+                    r#"
+                    unsafe impl Send for {name} {{}}
+                    unsafe impl Sync for {name} {{}}"#,
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        Ok((cxx_extern, send_impls))
+    }
+
+    /// Generates the stream handle types (C++ opaque types) and their `Send`/`Sync` implementations.
+    ///
+    /// # Generated Code
+    ///
+    /// ```rust,ignore
+    /// #[namespace = "craby::mymodule::streams"]
+    /// unsafe extern "C++" {
+    ///     include!("CrabyStreams.h");
+    ///
+    ///     type NumberStream;
+    ///
+    ///     fn send(self: &NumberStream, item: f64) -> bool;
+    ///     fn close(self: &NumberStream) -> bool;
+    ///     fn error(self: &NumberStream, message: &str) -> bool;
+    /// }
+    ///
+    /// // ...
+    ///
+    /// unsafe impl Send for NumberStream {}
+    /// unsafe impl Sync for NumberStream {}
+    /// ```
+    fn rs_cxx_streams(
+        &self,
+        cxx_ns: &CxxNamespace,
+        schemas: &[Schema],
+    ) -> Result<(String, String), anyhow::Error> {
+        let mut streams = BTreeMap::new();
+
+        for schema in schemas {
+            for type_annotation in schema.collect_stream_types() {
+                let name = type_annotation.as_rs_struct_name()?;
+                let item_type = type_annotation.as_stream().unwrap().as_rs_type()?.into_code();
+                streams.insert(name, item_type);
+            }
+        }
+
+        if streams.is_empty() {
+            return Ok((String::new(), String::new()));
+        }
+
+        let stream_decls = streams
+            .iter()
+            .map(|(name, item_type)| {
+                formatdoc! {
+                    r#"
+                    type {name};
+
+                    fn send(self: &{name}, item: {item_type}) -> bool;
+                    fn close(self: &{name}) -> bool;
+                    fn error(self: &{name}, message: &str) -> bool;"#,
+                }
+            })
+            .collect::<Vec<_>>();
+        let stream_decls = indent_str(&stream_decls.join("\n\n"), 4);
+
+        let cxx_extern = formatdoc! {
+            r#"
+            #[namespace = "{cxx_ns}::streams"]
+            unsafe extern "C++" {{
+                include!("CrabyStreams.h");
+
+            {stream_decls}
+            }}"#,
+        };
+
+        // The items are buffered with the lock by the C++ side, so the handles can be used from any thread
+        let send_impls = streams
+            .keys()
+            .map(|name| {
+                formatdoc! {
+                    r#"
+                    unsafe impl Send for {name} {{}}
+                    unsafe impl Sync for {name} {{}}"#,
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        Ok((cxx_extern, send_impls))
+    }
+
+    /// Generates the panic reporter function (C++) that is called by the panic hook in the `panic = "abort"` mode.
+    ///
+    /// # Generated Code
+    ///
+    /// ```rust,ignore
+    /// #[namespace = "craby::mymodule::panics"]
+    /// unsafe extern "C++" {
+    ///     include!("CrabyPanics.h");
+    ///
+    ///     #[rust_name = "report_panic"]
+    ///     fn reportPanic(message: &str);
+    /// }
+    /// ```
+    fn rs_cxx_panic_reporter(&self, cxx_ns: &CxxNamespace, panic_strategy: PanicStrategy) -> String {
+        match panic_strategy {
+            PanicStrategy::Unwind => String::new(),
+            PanicStrategy::Abort => formatdoc! {
+                r#"
+                #[namespace = "{cxx_ns}::panics"]
+                unsafe extern "C++" {{
+                    include!("CrabyPanics.h");
+
+                    #[rust_name = "report_panic"]
+                    fn reportPanic(message: &str);
+                }}"#,
+            },
+        }
+    }
+
+    /// Generates the abort controller type (Rust opaque type) that cancels the `AbortSignal` parameters.
+    ///
+    /// # Generated Code
+    ///
+    /// ```rust,ignore
+    /// extern "Rust" {
+    ///     type AbortController;
+    ///
+    ///     #[cxx_name = "createAbortController"]
+    ///     fn create_abort_controller() -> Box<AbortController>;
+    ///     fn abort(self: &AbortController);
+    /// }
+    ///
+    /// // ...
+    ///
+    /// pub struct AbortController(AbortSignal);
+    /// ```
+    fn rs_cxx_abort_controller(
+        &self,
+        schemas: &[Schema],
+        panic_strategy: PanicStrategy,
+    ) -> (String, String) {
+        if !schemas.iter().any(|schema| schema.has_abort_signal()) {
+            return (String::new(), String::new());
+        }
+
+        let cxx_extern = formatdoc! {
+            r#"
+            extern "Rust" {{
+                type AbortController;
+
+                #[cxx_name = "createAbortController"]
+                fn create_abort_controller() -> Box<AbortController>;
+                fn abort(self: &AbortController);
+            }}"#,
+        };
+
+        // The panic of the cancellation is not unwound across the FFI boundary (`panic = "unwind"`)
+        let cancel_stmt = match panic_strategy {
+            PanicStrategy::Unwind => "let _ = craby::catch_panic!(self.0.cancel());",
+            PanicStrategy::Abort => "self.0.cancel();",
+        };
+
+        // The controller is owned by the C++ side and aborted when the JS `AbortSignal` is aborted
+        let controller_impl = formatdoc! {
+            r#"
+            pub struct AbortController(AbortSignal);
+
+            fn create_abort_controller() -> Box<AbortController> {{
+                Box::new(AbortController(AbortSignal::new()))
+            }}
+
+            impl AbortController {{
+                fn abort(&self) {{
+                    {cancel_stmt}
+                }}
+            }}"#,
+        };
+
+        (cxx_extern, controller_impl)
+    }
+
+    /// Generates the `ErrorCode` implementations of the `@throws` error code types.
+    ///
+    /// # Generated Code
+    ///
+    /// ```rust,ignore
+    /// impl craby::error::ErrorCode for DownloadError {
+    ///     fn code(&self) -> &str {
+    ///         match *self {
+    ///             DownloadError::NotFound => "NOT_FOUND",
+    ///             DownloadError::Timeout => "TIMEOUT",
+    ///             _ => unreachable!(),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    fn rs_error_codes(&self, schemas: &[Schema]) -> String {
+        let mut error_codes = BTreeMap::new();
+
+        for schema in schemas {
+            for type_annotation in schema.collect_error_code_types() {
+                let enum_type = type_annotation.as_enum().unwrap();
+                error_codes.insert(enum_type.name.clone(), enum_type);
+            }
+        }
+
+        error_codes
+            .iter()
+            .map(|(name, enum_type)| {
+                let arms = enum_type
+                    .members
+                    .iter()
+                    .filter_map(|member| match &member.value {
+                        EnumMemberValue::String(value) => {
+                            Some(format!("{name}::{} => {value:?},", member.name))
+                        }
+                        EnumMemberValue::Number(..) => None,
+                    })
+                    .collect::<Vec<_>>();
+                let arms = indent_str(&arms.join("\n"), 12);
+
+                // The shared enums of cxx are not exhaustive
+                formatdoc! {
+                    r#"
+                    impl craby::error::ErrorCode for {name} {{
+                        fn code(&self) -> &str {{
+                            match *self {{
+                    {arms}
+                                _ => unreachable!(),
+                            }}
+                        }}
+                    }}"#,
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
     /// Generates Rust FFI function implementations.
     ///
     /// # Generated Code
@@ -240,12 +612,30 @@ impl RsTemplate {
             .iter()
             .map(|spec| -> Result<String, anyhow::Error> {
                 let sig = spec.try_into_impl_sig()?;
-                Ok(format!("{sig};"))
+                let doc = spec.doc.to_comment("///");
+
+                let deprecated_attr = spec
+                    .as_rs_deprecated_attr()
+                    .map(|attr| format!("{attr}\n"))
+                    .unwrap_or_default();
+
+                // Deprecated methods returning a `Result` have a default implementation, so implementors may drop them
+                match spec.as_rs_deprecated_default_body() {
+                    Some(body) => Ok(formatdoc! {
+                        r#"
+                        {doc}{deprecated_attr}#[allow(unused_variables)]
+                        {sig} {{
+                            {body}
+                        }}"#,
+                    }),
+                    None => Ok(format!("{doc}{deprecated_attr}{sig};")),
+                }
             })
             .collect::<Result<Vec<_>, _>>()?;
 
         let signal_enum = if !schema.signals.is_empty() {
             let signal_enum_name = format!("{}Signal", schema.module_name);
+            let emit_fn = format!("emit_{}", snake_case(&signal_enum_name));
             let (signal_members, pattern_matches, pattern_matches_with_data) = schema
                 .signals
                 .iter()
@@ -253,20 +643,21 @@ impl RsTemplate {
                     let member_name = pascal_case(&signal.name);
                     
                     // Create enum variant based on payload type
+                    let doc = signal.doc.to_comment("///");
                     let enum_member = if let Some(payload_type) = &signal.payload_type {
                         // Convert payload_type to Rust type
                         match payload_type.as_rs_type() {
-                            Ok(rs_type) => format!("{member_name}({}),", rs_type.into_code()),
-                            Err(_) => format!("{member_name},"), // Create without payload if conversion fails
+                            Ok(rs_type) => format!("{doc}{member_name}({}),", rs_type.into_code()),
+                            Err(_) => format!("{doc}{member_name},"), // Create without payload if conversion fails
                         }
                     } else {
-                        format!("{member_name},")
+                        format!("{doc}{member_name},")
                     };
                     
                     let enum_pattern_match = formatdoc! {
                         r#"{signal_enum_name}::{member_name} => {{
                             unsafe {{
-                                manager.emit(self.id(), "{raw}", std::ptr::null_mut());
+                                manager.{emit_fn}(id, generation, "{raw}", std::ptr::null_mut());
                             }}
                         }}"#,
                         raw = signal.name,
@@ -279,7 +670,7 @@ impl RsTemplate {
                                 let signal = Box::new({signal_enum_name}::{member_name}(data));
                                 let signal_ptr = Box::into_raw(signal);
                                 unsafe {{
-                                    manager.emit(self.id(), "{raw}", signal_ptr);
+                                    manager.{emit_fn}(id, generation, "{raw}", signal_ptr);
                                 }}
                             }}"#,
                             signal_enum_name = signal_enum_name,
@@ -302,10 +693,68 @@ impl RsTemplate {
                 );
 
             let signal_members_exprs = indent_str(&signal_members.join("\n"), 4);
+
+            // Payload-less names of the signals for the listener presence API
+            //
+            // ```rust,ignore
+            // pub enum MyModuleSignalName {
+            //     OnProgress,
+            // }
+            // ```
+            let signal_name_enum_name = format!("{signal_enum_name}Name");
+            let (name_members, as_str_arms, from_str_arms) = schema
+                .signals
+                .iter()
+                .map(|signal| {
+                    let member_name = pascal_case(&signal.name);
+                    (
+                        format!("{member_name},"),
+                        format!(r#"{signal_name_enum_name}::{member_name} => "{}","#, signal.name),
+                        format!(r#""{}" => Ok({signal_name_enum_name}::{member_name}),"#, signal.name),
+                    )
+                })
+                .fold(
+                    (Vec::new(), Vec::new(), Vec::new()),
+                    |(mut members, mut as_str, mut from_str), (member, as_str_arm, from_str_arm)| {
+                        members.push(member);
+                        as_str.push(as_str_arm);
+                        from_str.push(from_str_arm);
+                        (members, as_str, from_str)
+                    },
+                );
+            let name_members = indent_str(&name_members.join("\n"), 4);
+            let as_str_arms = indent_str(&as_str_arms.join("\n"), 12);
+            let from_str_arms = indent_str(&from_str_arms.join("\n"), 12);
+
             let signal_enum = formatdoc! {
                 r#"
                 pub enum {signal_enum_name} {{
                 {signal_members_exprs}
+                }}
+
+                #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+                pub enum {signal_name_enum_name} {{
+                {name_members}
+                }}
+
+                impl {signal_name_enum_name} {{
+                    /// Returns the name of the signal in the spec (eg. `onProgress`).
+                    pub fn as_str(&self) -> &'static str {{
+                        match self {{
+                {as_str_arms}
+                        }}
+                    }}
+                }}
+
+                impl std::str::FromStr for {signal_name_enum_name} {{
+                    type Err = anyhow::Error;
+
+                    fn from_str(name: &str) -> std::result::Result<Self, Self::Err> {{
+                        match name {{
+                {from_str_arms}
+                            _ => Err(anyhow::anyhow!("Unknown signal: {{name}}")),
+                        }}
+                    }}
                 }}"#,
             };
 
@@ -320,19 +769,51 @@ impl RsTemplate {
                 indent_str(&pattern_matches.join("\n"), 8)
             };
             
+            // The signals are emitted through the `Signal` trait, so the detached emitters can emit them too
             let emit_impl = formatdoc! {
                 r#"
                 fn emit(&self, signal_name: {signal_enum_name}) {{
-                    let manager = crate::ffi::bridging::get_signal_manager();
-                    match signal_name {{
+                    let id = self.id();
+                    craby::signals::Signal::emit_to(signal_name, id, craby::signals::generation(id));
+                }}
+                /// Returns the emitter that can be moved to the other threads.
+                fn emitter(&self) -> craby::signals::Emitter<{signal_enum_name}> {{
+                    craby::signals::Emitter::new(self.id())
+                }}"#,
+            };
+            let signal_impl = formatdoc! {
+                r#"
+                impl craby::signals::Signal for {signal_enum_name} {{
+                    fn emit_to(self, id: usize, generation: u64) {{
+                        let manager = crate::ffi::bridging::get_signal_manager();
+                        match self {{
                 {pattern_match_stmts}
+                        }}
                     }}
                 }}"#,
+                pattern_match_stmts = indent_str(&pattern_match_stmts, 4),
             };
 
+            // The listener hooks are called on the JS thread when the JS listener is added or removed
+            let listener_impls = formatdoc! {
+                r#"
+                /// Returns `true` if the signal has any JS listener.
+                fn has_listeners(&self, signal_name: {signal_name_enum_name}) -> bool {{
+                    let manager = crate::ffi::bridging::get_signal_manager();
+                    manager.has_listeners(self.id(), signal_name.as_str())
+                }}
+                /// Called when the JS listener of the signal is added.
+                #[allow(unused_variables)]
+                fn on_listener_added(&mut self, signal_name: {signal_name_enum_name}) {{}}
+                /// Called when the JS listener of the signal is removed.
+                #[allow(unused_variables)]
+                fn on_listener_removed(&mut self, signal_name: {signal_name_enum_name}) {{}}"#,
+            };
+
+            methods.insert(0, listener_impls);
             methods.insert(0, emit_impl);
 
-            Some(signal_enum)
+            Some(format!("{signal_enum}\n\n{signal_impl}"))
         } else {
             None
         };
@@ -390,6 +871,7 @@ impl RsTemplate {
         let methods = schema
             .methods
             .iter()
+            .filter(|spec| spec.as_rs_deprecated_default_body().is_none())
             .map(|spec| -> Result<String, anyhow::Error> {
                 let func_sig = spec.try_into_impl_sig()?;
                 let code = formatdoc! {
@@ -483,9 +965,25 @@ impl RsTemplate {
             .collect::<Vec<String>>();
 
         let has_signals = ctx.schemas.iter().any(|schema| !schema.signals.is_empty());
-        let rs_cxx_bridges = self.rs_cxx_bridges(&ctx.schemas)?;
+        let rs_cxx_bridges = self.rs_cxx_bridges(&ctx.schemas, ctx.panic_strategy)?;
         let cxx_impls = self.rs_cxx_impl(&rs_cxx_bridges);
-        let cxx_externs = self.rs_cxx_extern(&cxx_ns, &rs_cxx_bridges, has_signals, &ctx.schemas);
+        let (cxx_callbacks, callback_impls) = self.rs_cxx_callbacks(&cxx_ns, &ctx.schemas)?;
+        let (cxx_streams, stream_impls) = self.rs_cxx_streams(&cxx_ns, &ctx.schemas)?;
+        let (cxx_abort_controller, abort_controller_impl) =
+            self.rs_cxx_abort_controller(&ctx.schemas, ctx.panic_strategy);
+        let cxx_panic_reporter = self.rs_cxx_panic_reporter(&cxx_ns, ctx.panic_strategy);
+        let cxx_externs = self.rs_cxx_extern(
+            &cxx_ns,
+            &rs_cxx_bridges,
+            has_signals,
+            &ctx.schemas,
+            &[
+                &cxx_callbacks,
+                &cxx_streams,
+                &cxx_abort_controller,
+                &cxx_panic_reporter,
+            ],
+        );
         
         // Generate signal payload extraction function implementation
         let signal_payload_impls = if has_signals {
@@ -495,12 +993,13 @@ impl RsTemplate {
                 }
                 
                 let signal_enum_name = format!("{}Signal", schema.module_name);
+                let mod_name = snake_case(&schema.module_name);
                 let mut impls: Vec<String> = schema.signals.iter().filter_map(|signal| {
                     signal.payload_type.as_ref().map(|payload_type| {
                         let payload_type_name = payload_type.as_rs_type()
                             .map(|t| t.into_code())
                             .unwrap_or_else(|_| "String".to_string());
-                        let function_name = format!("get_{}_payload", snake_case(&signal.name));
+                        let function_name = format!("{mod_name}_get_{}_payload", snake_case(&signal.name));
                         let signal_variant = pascal_case(&signal.name);
                         
                         formatdoc! {
@@ -518,16 +1017,52 @@ impl RsTemplate {
                 // Add drop_signal implementation
                 impls.push(formatdoc! {
                     r#"
-                    unsafe fn drop_signal(signal: *mut {signal_enum_name}) {{
+                    unsafe fn {mod_name}_drop_signal(signal: *mut {signal_enum_name}) {{
                         if !signal.is_null() {{
                             drop(Box::from_raw(signal));
                         }}
                     }}"#,
                     signal_enum_name = signal_enum_name,
                 });
+
+                // Add listener hook implementations
+                let module_name = pascal_case(&schema.module_name);
+                for hook in ["on_listener_added", "on_listener_removed"] {
+                    let call_hook = match ctx.panic_strategy {
+                        PanicStrategy::Unwind => formatdoc! {
+                            r#"
+                            craby::catch_panic!({{
+                                it_.{hook}(signal_name)
+                            }})"#,
+                        },
+                        PanicStrategy::Abort => formatdoc! {
+                            r#"
+                            it_.{hook}(signal_name);
+                            Ok(())"#,
+                        },
+                    };
+
+                    impls.push(formatdoc! {
+                        r#"
+                        fn {mod_name}_{hook}(it_: &mut {module_name}, name: &str) -> Result<(), anyhow::Error> {{
+                            let signal_name = name.parse::<{signal_enum_name}Name>()?;
+                        {call_hook}
+                        }}"#,
+                        call_hook = indent_str(&call_hook, 4),
+                    });
+                }
                 
                 impls
-            }).collect::<Vec<_>>()
+            }).chain([formatdoc! {
+                r#"
+                fn register_signals(id: usize) -> u64 {{
+                    craby::signals::register(id)
+                }}
+
+                fn unregister_signals(id: usize) {{
+                    craby::signals::unregister(id);
+                }}"#,
+            }]).collect::<Vec<_>>()
         } else {
             vec![]
         };
@@ -535,6 +1070,24 @@ impl RsTemplate {
         let impl_mods = impl_mods.join("\n");
         let cxx_impls = cxx_impls.join("\n\n");
         let signal_impls = signal_payload_impls.join("\n\n");
+        let handle_impls = [callback_impls, stream_impls]
+            .into_iter()
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        let (bridging_uses, cxx_externs) = if handle_impls.is_empty() {
+            ("use bridging::*;".to_string(), cxx_externs)
+        } else {
+            (
+                "use bridging::*;\nuse cxx::SharedPtr;".to_string(),
+                format!("{cxx_externs}\n\n{handle_impls}"),
+            )
+        };
+        let cxx_externs = [cxx_externs, abort_controller_impl, self.rs_error_codes(&ctx.schemas)]
+            .into_iter()
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n\n");
         let content = formatdoc! {
             r#"
             #[rustfmt::skip]
@@ -543,7 +1096,7 @@ impl RsTemplate {
             {impl_mods}
             use crate::generated::*;
 
-            use bridging::*;
+            {bridging_uses}
 
             {cxx_externs}
 
@@ -690,7 +1243,10 @@ impl GeneratorInvoker for RsGenerator {
 mod tests {
     use insta::assert_snapshot;
 
-    use crate::tests::get_codegen_context;
+    use crate::tests::{
+        get_binary_arrays_codegen_context, get_codegen_context, get_shared_types_codegen_context,
+        get_signals_codegen_context,
+    };
 
     use super::*;
 
@@ -707,4 +1263,75 @@ mod tests {
 
         assert_snapshot!(result);
     }
+    #[test]
+    fn test_rs_generator_shared_types() {
+        let ctx = get_shared_types_codegen_context();
+        let ffi_rs = RsTemplate.ffi_rs(&ctx).unwrap();
+
+        assert_eq!(ffi_rs.matches("struct Shared {").count(), 1);
+        assert_eq!(ffi_rs.matches("struct NullableShared {").count(), 1);
+        assert_eq!(ffi_rs.matches("enum Kind {").count(), 1);
+    }
+
+    #[test]
+    fn test_rs_generator_binary_arrays() {
+        let ctx = get_binary_arrays_codegen_context();
+        let ffi_rs = RsTemplate.ffi_rs(&ctx).unwrap();
+
+        // `Vec<Vec<T>>` is not supported by cxx
+        assert!(!ffi_rs.contains("Vec<Vec<"));
+        assert!(ffi_rs.contains(
+            "fn binary_module_buffers(it_: &mut BinaryModule, arg: Vec<ArrayBufferItem>) -> Result<Vec<Uint8ArrayItem>>;"
+        ));
+        assert!(ffi_rs.contains(
+            "fn binary_module_nested(it_: &mut BinaryModule, arg: Vec<Float32ArrayArray>) -> Result<ArrayBufferMap>;"
+        ));
+        for name in ["ArrayBufferItem", "Uint8ArrayItem", "Float32ArrayItem"] {
+            assert_eq!(ffi_rs.matches(&format!("struct {name} {{")).count(), 1);
+        }
+    }
+
+    #[test]
+    fn test_rs_generator_multiple_signals() {
+        let ctx = get_signals_codegen_context();
+        let ffi_rs = RsTemplate.ffi_rs(&ctx).unwrap();
+
+        for (module_name, signal_enum) in [
+            ("foo_module", "FooModuleSignal"),
+            ("bar_module", "BarModuleSignal"),
+        ] {
+            let emit_fn = format!("unsafe fn emit{signal_enum}(self: &SignalManager, id: usize, generation: u64, name: &str, signal: *mut {signal_enum});");
+            let drop_fn = format!("unsafe fn {module_name}_drop_signal(signal: *mut {signal_enum})");
+
+            assert_eq!(ffi_rs.matches(&emit_fn).count(), 1);
+            // Extern declaration and implementation
+            assert_eq!(ffi_rs.matches(&drop_fn).count(), 2);
+            assert_eq!(ffi_rs.matches(&format!("fn {module_name}_on_listener_added(")).count(), 2);
+            assert_eq!(ffi_rs.matches(&format!("fn {module_name}_on_listener_removed(")).count(), 2);
+        }
+        assert_eq!(ffi_rs.matches("fn foo_module_get_on_progress_payload(").count(), 2);
+        assert_eq!(ffi_rs.matches("fn bar_module_get_on_message_payload(").count(), 2);
+        // Shared between the modules
+        assert_eq!(ffi_rs.matches("fn register_signals(").count(), 2);
+        assert_eq!(ffi_rs.matches("fn unregister_signals(").count(), 2);
+    }
+
+    #[test]
+    fn test_rs_generator_panic_abort() {
+        let mut ctx = get_codegen_context();
+        ctx.panic_strategy = PanicStrategy::Abort;
+        let ffi_rs = RsTemplate.ffi_rs(&ctx).unwrap();
+        let results = RsGenerator::new().generate(&ctx).unwrap();
+
+        // Errors are propagated without unwinding
+        for res in &results {
+            assert!(
+                !res.content.contains("catch_panic!"),
+                "{}",
+                res.path.display()
+            );
+        }
+        assert!(ffi_rs.contains("fn reportPanic(message: &str);"));
+        assert!(ffi_rs.contains("craby::panic::set_hook(report_panic);"));
+    }
 }
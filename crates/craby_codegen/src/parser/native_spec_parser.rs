@@ -1,11 +1,20 @@
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use craby_common::utils::string::pascal_case;
 use log::debug;
 use oxc::{
     allocator::Allocator,
     ast::ast::*,
-    ast_visit::Visit,
+    ast_visit::{walk, Visit},
     diagnostics::OxcDiagnostic,
     parser::Parser,
     semantic::{Scoping, SemanticBuilder, SymbolId},
+    span::GetSpan,
 };
 use rustc_hash::{FxHashMap, FxHashSet};
 
@@ -19,18 +28,60 @@ const INVALID_SPEC: &str = "Invalid specification";
 const INVALID_TYPE_REFERENCE: &str = "Invalid type reference";
 const INVALID_COMPUTED_SIG: &str = "Computed signature is not supported";
 const INVALID_OPTIONAL_SIG: &str = "Optional signature is not supported";
-const INVALID_OPTIONAL_PROP: &str = "Optional property is not supported";
-const INVALID_OPTIONAL_PARAM: &str = "Optional parameter is not supported";
+const INVALID_OPTIONAL_PARAM: &str = "Required parameter cannot follow an optional parameter";
 const INVALID_NO_SPEC_GENERIC: &str = "NativeModule specification generic argument is required";
-const INVALID_FUNC_PARAM: &str = "Function parameter is not supported";
+const INVALID_FUNC_PARAM: &str = "Function type is only supported as a required method parameter";
+const INVALID_FUNC_RETURN: &str = "Function type must return `void`";
+const INVALID_FUNC_ARG: &str = "Function parameter type cannot be `void` or `Promise`";
 const INVALID_TYPE_LITERAL: &str =
     "Type literal is not supported. Use defined type reference instead";
-const INVALID_UNION_TYPE: &str = "Union types only allow nullable type (eg. `T | null`)";
+const INVALID_MAP_KEY: &str = "Map key type must be `string`";
+const INVALID_UNION_TYPE: &str =
+    "Union types only allow nullable type (eg. `T | null`) or union of object types";
+const INVALID_DISCRIMINATED_UNION: &str =
+    "Union of object types must have a string literal discriminant property (eg. `kind: 'foo'`)";
 const INVALID_MIXED_ENUM_MEMBER: &str =
     "Enum member type must be single type (eg. only `number` or `string`)";
+const INVALID_ENUM_MEMBER: &str =
+    "Enum member must be a string or non-negative integer literal (eg. `'foo' | 'bar'`, `0 | 1`)";
+const INVALID_DUPLICATE_ENUM_MEMBER: &str = "Duplicate enum member";
+const INVALID_EXTENDS: &str = "Interface can only extend type references (eg. `extends Base`)";
+const INVALID_EXTENDS_TYPE: &str = "Interface can only extend object types";
+const INVALID_CIRCULAR_EXTENDS: &str = "Circular inheritance is not supported";
+const INVALID_GENERIC_ALIAS: &str =
+    "Generic type alias must be an object type literal (eg. `type Page<T> = { items: T[] }`)";
+const INVALID_GENERIC_IMPORT: &str = "Generic type alias cannot be imported from the other module";
+const INVALID_TYPE_PARAM: &str = "Type parameter constraints and defaults are not supported";
+const INVALID_TYPE_ARG: &str =
+    "Type argument must be a primitive, object, enum, array or record type (eg. `Page<User>`)";
+const INVALID_RECURSIVE_TYPE: &str = "Recursive type is not supported";
+const INVALID_RECURSIVE_GENERIC: &str = "Recursive generic type is not supported";
+const INVALID_CIRCULAR_IMPORT: &str = "Circular import between modules is not supported";
 const INVALID_REGISTRY_METHOD: &str = "Invalid NativeModuleRegistry method";
 const INVALID_RESERVED_ARG_NAME_ID: &str = "Reserved argument name `it_` is not allowed";
 const INVALID_RESERVED_METHOD_NAME_ID: &str = "Reserved method name `emit` is not allowed";
+const INVALID_RESERVED_METHOD_NAME_SIGNAL: &str =
+    "Reserved method names `emitter`, `hasListeners`, `onListenerAdded` and `onListenerRemoved` are not allowed";
+const INVALID_STREAM: &str = "AsyncIterable type is only supported as a method return type";
+const INVALID_STREAM_ITEM: &str = "AsyncIterable item type cannot be `void`, `Promise` or function";
+const INVALID_RESERVED_ARG_NAME_STREAM: &str =
+    "Reserved argument name `stream` is not allowed in the AsyncIterable method";
+const INVALID_ABORT_SIGNAL: &str = "AbortSignal type is only supported as a method parameter";
+const INVALID_ABORT_SIGNAL_METHOD: &str =
+    "AbortSignal parameter is only supported in the Promise method";
+const INVALID_DUPLICATE_ABORT_SIGNAL: &str = "Only one AbortSignal parameter is allowed";
+const INVALID_THROWS: &str =
+    "`@throws` type must be a string enum (eg. `enum ErrorCode { NotFound = 'NOT_FOUND' }`)";
+const INVALID_THROWS_METHOD: &str = "`@throws` tag is not supported in the AsyncIterable method";
+const INVALID_THROTTLE: &str =
+    "`@throttle` interval must be a positive duration (eg. `@throttle 16ms`, `@throttle 1s`)";
+const INVALID_BUFFER: &str = "`@buffer` size must be a positive integer (eg. `@buffer 8`)";
+const INVALID_SERIAL: &str =
+    "`@serial` queue name must contain only alphanumeric characters and `_` (eg. `@serial storage`)";
+const INVALID_SERIAL_METHOD: &str =
+    "`@serial` tag is only supported in the Promise and AsyncIterable methods";
+const INVALID_CONSTANTS: &str =
+    "`getConstants` must have no parameters and return an object type (eg. `getConstants(): Constants`)";
 
 pub struct NativeModuleAnalyzer<'a> {
     pub diagnostics: Vec<OxcDiagnostic>,
@@ -49,6 +100,27 @@ pub struct NativeModuleAnalyzer<'a> {
     decls: FxHashMap<SymbolId, TypeAnnotation>,
     /// NativeModule specs collected from the source code
     specs: FxHashMap<SymbolId, Spec>,
+    /// Types imported from the relative modules
+    imports: Vec<TypeImport>,
+    /// Generic type aliases to be instantiated at each use site (eg. `type Page<T> = { .. }`)
+    generics: FxHashMap<SymbolId, GenericAlias>,
+    /// Instantiated generic types keyed by the name (eg. `PageUser`) to detect the conflicting instantiations
+    instances: RefCell<FxHashMap<String, TypeAnnotation>>,
+    /// Base types of the interfaces to be flattened (eg. `interface Foo extends Bar, Baz`)
+    bases: FxHashMap<SymbolId, (Span, Vec<RefTypeAnnotation>)>,
+    /// JSDoc comments keyed by the start position of the documented node
+    docs: FxHashMap<u32, String>,
+}
+
+/// Type imported from the relative module (eg. `import type { Foo } from './types'`)
+struct TypeImport {
+    /// Symbol ID of the local binding
+    sym_id: SymbolId,
+    /// Exported name of the type in the imported module
+    imported_name: String,
+    /// Module specifier (eg. `./types`)
+    source: String,
+    span: Span,
 }
 
 impl<'a> NativeModuleAnalyzer<'a> {
@@ -63,9 +135,58 @@ impl<'a> NativeModuleAnalyzer<'a> {
             specs: FxHashMap::default(),
             mods: FxHashMap::default(),
             decls: FxHashMap::default(),
+            imports: vec![],
+            generics: FxHashMap::default(),
+            instances: RefCell::default(),
+            bases: FxHashMap::default(),
+            docs: FxHashMap::default(),
+        }
+    }
+
+    /// Collects the leading JSDoc comments (eg. `/** Foo */`) without the comment markers.
+    ///
+    /// ```ts
+    /// /**
+    ///  * Returns the user
+    ///  *
+    ///  * @param id The user ID
+    ///  */
+    /// ```
+    ///
+    /// is collected as `"Returns the user\n\n@param id The user ID"`.
+    fn collect_docs(&mut self, src: &str, comments: &[Comment]) {
+        for comment in comments.iter().filter(|comment| comment.is_jsdoc()) {
+            let content = comment.content_span().source_text(src);
+            let lines = content
+                .strip_prefix('*')
+                .unwrap_or(content)
+                .lines()
+                .map(|line| {
+                    let line = line.trim();
+                    let line = line.strip_prefix('*').unwrap_or(line);
+                    line.strip_prefix(' ').unwrap_or(line).trim_end()
+                })
+                .collect::<Vec<_>>();
+
+            let start = lines.iter().position(|line| !line.is_empty());
+            let end = lines.iter().rposition(|line| !line.is_empty());
+
+            // The closest comment is used if there are multiple leading comments
+            match (start, end) {
+                (Some(start), Some(end)) => {
+                    self.docs
+                        .insert(comment.attached_to, lines[start..=end].join("\n"));
+                }
+                _ => drop(self.docs.remove(&comment.attached_to)),
+            }
         }
     }
 
+    /// Returns the JSDoc comment of the node that starts at the given span.
+    fn doc_of(&self, span: Span) -> JsDoc {
+        JsDoc(self.docs.get(&span.start).cloned())
+    }
+
     fn collect_mod(&mut self, it: &CallExpression<'a>) {
         if !self.is_reg_call(it) {
             return;
@@ -119,34 +240,62 @@ impl<'a> NativeModuleAnalyzer<'a> {
             return self.collect_error(&e.to_string(), it.span);
         };
 
-        if !it.extends.is_empty() {
-            return self.collect_error(INVALID_SPEC, it.span);
-        }
-
         let id = it.id.symbol_id();
         let name = it.id.name.to_string();
 
+        // Collect base types (flattened after all declarations are collected)
+        let mut bases = vec![];
+        for heritage in &it.extends {
+            let ident = match &heritage.expression {
+                Expression::Identifier(ident) => ident,
+                _ => return self.collect_error(INVALID_EXTENDS, heritage.span),
+            };
+
+            // Generic base type (eg. `extends Page<User>`)
+            let type_args = match &heritage.type_arguments {
+                Some(type_args) => match type_args
+                    .params
+                    .iter()
+                    .map(|type_arg| self.try_into_type_annotation(type_arg))
+                    .collect::<Result<Vec<_>, _>>()
+                {
+                    Ok(type_args) => type_args,
+                    Err(e) => return self.collect_error(&e.to_string(), heritage.span),
+                },
+                None => vec![],
+            };
+
+            bases.push(RefTypeAnnotation {
+                ref_id: ident.reference_id(),
+                name: ident.name.to_string(),
+                span: ident.span,
+                type_args,
+            });
+        }
+
+        if !bases.is_empty() {
+            self.bases.insert(id, (it.id.span, bases));
+        }
+
         // Collect type alias
         let mut props = vec![];
         for sig in &it.body.body {
             match sig {
-                TSSignature::TSPropertySignature(prop_sig) => {
-                    if prop_sig.optional {
-                        return self.collect_error(INVALID_OPTIONAL_PROP, prop_sig.span);
-                    }
-
-                    match self.try_into_prop(prop_sig) {
-                        Ok(prop) => props.push(prop),
-                        Err(e) => return self.diagnostics.push(e),
-                    }
-                }
+                TSSignature::TSPropertySignature(prop_sig) => match self.try_into_prop(prop_sig) {
+                    Ok(prop) => props.push(prop),
+                    Err(e) => return self.diagnostics.push(e),
+                },
                 _ => return self.collect_error(INVALID_SPEC, it.span),
             }
         }
 
         self.decls.insert(
             id,
-            TypeAnnotation::Object(ObjectTypeAnnotation { name, props }),
+            TypeAnnotation::Object(ObjectTypeAnnotation {
+                name,
+                props,
+                doc: self.doc_of(it.span),
+            }),
         );
     }
 
@@ -155,28 +304,30 @@ impl<'a> NativeModuleAnalyzer<'a> {
             return self.collect_error(&e.to_string(), it.span);
         };
 
-        if let Some(params) = &it.type_parameters {
-            if !params.params.is_empty() {
-                return self.collect_error("Type parameters are not supported", it.span);
-            }
-        }
-
         let id = it.id.symbol_id();
         let name = it.id.name.to_string();
 
+        if let Some(type_params) = &it.type_parameters {
+            if !type_params.params.is_empty() {
+                return self.collect_generic_alias(it, type_params);
+            }
+        }
+
         match &it.type_annotation {
+            map_type @ (TSType::TSTypeLiteral(..) | TSType::TSTypeReference(..))
+                if Self::is_map_type(map_type) =>
+            {
+                match self.try_into_type_annotation(map_type) {
+                    Ok(type_annotation) => drop(self.decls.insert(id, type_annotation)),
+                    Err(e) => self.diagnostics.push(error(&e.to_string(), it.span)),
+                }
+            }
             TSType::TSTypeLiteral(type_lit) => {
                 let props = type_lit
                     .members
                     .iter()
                     .map(|member| match member {
-                        TSSignature::TSPropertySignature(prop_sig) => {
-                            if prop_sig.optional {
-                                Err(error(INVALID_OPTIONAL_PROP, prop_sig.span))
-                            } else {
-                                self.try_into_prop(prop_sig)
-                            }
-                        }
+                        TSSignature::TSPropertySignature(prop_sig) => self.try_into_prop(prop_sig),
                         _ => Err(error(INVALID_SPEC, type_lit.span)),
                     })
                     .collect::<Result<Vec<Prop>, OxcDiagnostic>>();
@@ -185,12 +336,33 @@ impl<'a> NativeModuleAnalyzer<'a> {
                     Ok(props) => {
                         self.decls.insert(
                             id,
-                            TypeAnnotation::Object(ObjectTypeAnnotation { name, props }),
+                            TypeAnnotation::Object(ObjectTypeAnnotation {
+                                name,
+                                props,
+                                doc: self.doc_of(it.span),
+                            }),
                         );
                     }
                     Err(e) => self.diagnostics.push(e),
                 }
             }
+            TSType::TSUnionType(union_type) if Self::is_literal_union(union_type) => {
+                match self.try_into_literal_enum(name, union_type) {
+                    Ok(mut type_annotation) => {
+                        if let TypeAnnotation::Enum(enum_type) = &mut type_annotation {
+                            enum_type.doc = self.doc_of(it.span);
+                        }
+                        self.decls.insert(id, type_annotation);
+                    }
+                    Err(e) => self.diagnostics.push(error(&e.to_string(), it.span)),
+                }
+            }
+            TSType::TSUnionType(union_type) if Self::is_object_union(union_type) => {
+                match self.try_into_union(name, union_type) {
+                    Ok(type_annotation) => drop(self.decls.insert(id, type_annotation)),
+                    Err(e) => self.diagnostics.push(error(&e.to_string(), it.span)),
+                }
+            }
             TSType::TSUnionType(union_type) => match self.try_into_nullable(union_type) {
                 Ok(type_annotation) => drop(self.decls.insert(id, type_annotation)),
                 Err(e) => self.diagnostics.push(error(&e.to_string(), it.span)),
@@ -199,6 +371,47 @@ impl<'a> NativeModuleAnalyzer<'a> {
         }
     }
 
+    fn collect_generic_alias(
+        &mut self,
+        it: &TSTypeAliasDeclaration<'a>,
+        type_params: &TSTypeParameterDeclaration<'a>,
+    ) {
+        let type_lit = match &it.type_annotation {
+            TSType::TSTypeLiteral(type_lit) if !Self::is_map_type(&it.type_annotation) => type_lit,
+            _ => return self.collect_error(INVALID_GENERIC_ALIAS, it.span),
+        };
+
+        let mut params = vec![];
+        for param in &type_params.params {
+            if param.constraint.is_some() || param.default.is_some() {
+                return self.collect_error(INVALID_TYPE_PARAM, param.span);
+            }
+            params.push(param.name.symbol_id());
+        }
+
+        let mut props = vec![];
+        for member in &type_lit.members {
+            match member {
+                TSSignature::TSPropertySignature(prop_sig) => match self.try_into_prop(prop_sig) {
+                    Ok(prop) => props.push(prop),
+                    Err(e) => return self.diagnostics.push(e),
+                },
+                _ => return self.collect_error(INVALID_SPEC, type_lit.span),
+            }
+        }
+
+        self.generics.insert(
+            it.id.symbol_id(),
+            GenericAlias {
+                name: it.id.name.to_string(),
+                params,
+                props,
+                span: it.span,
+                doc: self.doc_of(it.span),
+            },
+        );
+    }
+
     fn collect_enum_type(&mut self, it: &TSEnumDeclaration<'a>) {
         let mut members = vec![];
         let mut prev_num_raw_val = 0;
@@ -226,6 +439,7 @@ impl<'a> NativeModuleAnalyzer<'a> {
                             members.push(EnumMember {
                                 name: member.id.static_name().to_string(),
                                 value: EnumMemberValue::Number(raw),
+                                doc: self.doc_of(member.span),
                             });
                         }
                     }
@@ -241,6 +455,7 @@ impl<'a> NativeModuleAnalyzer<'a> {
                         members.push(EnumMember {
                             name: member.id.static_name().to_string(),
                             value: EnumMemberValue::String(str_lit.value.into_string()),
+                            doc: self.doc_of(member.span),
                         });
                     }
                     _ => self.collect_error(INVALID_SPEC, it.span),
@@ -257,6 +472,7 @@ impl<'a> NativeModuleAnalyzer<'a> {
                     members.push(EnumMember {
                         name: member.id.static_name().to_string(),
                         value: EnumMemberValue::Number(prev_num_raw_val + idx),
+                        doc: self.doc_of(member.span),
                     });
                 }
             };
@@ -267,6 +483,7 @@ impl<'a> NativeModuleAnalyzer<'a> {
             TypeAnnotation::Enum(EnumTypeAnnotation {
                 name: it.id.name.to_string(),
                 members,
+                doc: self.doc_of(it.span),
             }),
         );
     }
@@ -365,9 +582,21 @@ impl<'a> NativeModuleAnalyzer<'a> {
                         Err(e) => return Err(error(&e.to_string(), prop_sig.span)),
                     };
 
+                // Optional properties are represented as nullable types (eg. `foo?: T` -> `T | null`)
+                let type_annotation = if prop_sig.optional {
+                    match self.try_into_optional(type_annotation) {
+                        Ok(type_annotation) => type_annotation,
+                        Err(e) => return Err(error(&e.to_string(), prop_sig.span)),
+                    }
+                } else {
+                    type_annotation
+                };
+
                 Ok(Prop {
                     name: prop_name,
                     type_annotation,
+                    optional: prop_sig.optional,
+                    doc: self.doc_of(prop_sig.span),
                 })
             }
             _ => Err(error(INVALID_SPEC, prop_sig.span)),
@@ -392,6 +621,10 @@ impl<'a> NativeModuleAnalyzer<'a> {
             return Err(error(INVALID_RESERVED_METHOD_NAME_ID, sig.span));
         }
 
+        if RESERVED_METHOD_NAMES_SIGNAL.contains(&method_name.as_str()) {
+            return Err(error(INVALID_RESERVED_METHOD_NAME_SIGNAL, sig.span));
+        }
+
         let params = sig
             .params
             .items
@@ -401,10 +634,6 @@ impl<'a> NativeModuleAnalyzer<'a> {
                     return Err(error(INVALID_SPEC, param.span));
                 }
 
-                if param.pattern.optional {
-                    return Err(error(INVALID_OPTIONAL_PARAM, param.span));
-                }
-
                 let param_name = param
                     .pattern
                     .kind
@@ -421,28 +650,177 @@ impl<'a> NativeModuleAnalyzer<'a> {
                     .as_ref()
                     .ok_or_else(|| error(INVALID_SPEC, param.span))?;
 
-                match self.try_into_type_annotation(&param_type_annotation.type_annotation) {
-                    Ok(type_annotation) => Ok(Param {
-                        name: param_name.to_string(),
-                        type_annotation,
-                    }),
-                    Err(e) => Err(error(&e.to_string(), param.span)),
+                let type_annotation = match &param_type_annotation.type_annotation {
+                    // Callback function (eg. `onProgress: (progress: number) => void`)
+                    TSType::TSFunctionType(fn_type) if !param.pattern.optional => {
+                        self.try_into_function(fn_type)
+                    }
+                    // Cancellation token (eg. `signal?: AbortSignal`)
+                    TSType::TSTypeReference(type_ref)
+                        if matches!(
+                            &type_ref.type_name,
+                            TSTypeName::IdentifierReference(ident_ref) if ident_ref.name == RESERVED_TYPE_ABORT_SIGNAL
+                        ) =>
+                    {
+                        Ok(TypeAnnotation::AbortSignal)
+                    }
+                    ts_type => self.try_into_type_annotation(ts_type),
                 }
+                .and_then(|type_annotation| {
+                    // Optional parameters are represented as nullable types (eg. `arg?: T` -> `T | null`)
+                    //
+                    // The omitted signal is never cancelled, so it is not represented as a nullable type
+                    if param.pattern.optional && !type_annotation.is_abort_signal() {
+                        self.try_into_optional(type_annotation)
+                    } else {
+                        Ok(type_annotation)
+                    }
+                })
+                .map_err(|e| error(&e.to_string(), param.span))?;
+
+                Ok(Param {
+                    name: param_name.to_string(),
+                    type_annotation,
+                    optional: param.pattern.optional,
+                })
             })
             .collect::<Result<Vec<Param>, OxcDiagnostic>>()?;
 
+        // Only trailing parameters can be optional
+        // eg. `method(a: number, b?: number)`
+        if let Some(idx) = params.iter().position(|param| param.optional) {
+            if let Some(pos) = params[idx..].iter().position(|param| !param.optional) {
+                return Err(error(
+                    INVALID_OPTIONAL_PARAM,
+                    sig.params.items[idx + pos].span,
+                ));
+            }
+        }
+
         let ret_type = sig
             .return_type
             .as_ref()
             .ok_or_else(|| error(INVALID_SPEC, sig.span))?;
 
-        match self.try_into_type_annotation(&ret_type.type_annotation) {
-            Ok(type_annotation) => Ok(Method {
+        let doc = self.doc_of(sig.span);
+        let serial = match doc.serial() {
+            Some("") => Some(method_name.clone()),
+            Some(queue) if queue.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') => {
+                Some(queue.to_string())
+            }
+            Some(_) => return Err(error(INVALID_SERIAL, sig.span)),
+            None => None,
+        };
+
+        let method = match self.try_into_ret_type(&ret_type.type_annotation) {
+            Ok(type_annotation) => Method {
                 name: method_name,
                 params,
                 ret_type: type_annotation,
-            }),
-            Err(e) => Err(error(&e.to_string(), sig.span)),
+                // Resolved by the type name after the declarations are collected
+                throws: None,
+                serial,
+                deprecated: doc.deprecated(),
+                doc,
+            },
+            Err(e) => return Err(error(&e.to_string(), sig.span)),
+        };
+
+        // The synchronous methods are invoked on the JS thread without the worker threads
+        if method.serial.is_some()
+            && !matches!(
+                method.ret_type,
+                TypeAnnotation::Promise(..) | TypeAnnotation::Stream(..)
+            )
+        {
+            return Err(error(INVALID_SERIAL_METHOD, sig.span));
+        }
+
+        // The constants are cached in the module, so they cannot depend on the arguments
+        //
+        // The return type is checked whether it is an object type after the references are resolved
+        if method.is_constants()
+            && (!method.params.is_empty() || !matches!(method.ret_type, TypeAnnotation::Ref(..)))
+        {
+            return Err(error(INVALID_CONSTANTS, sig.span));
+        }
+
+        // The stream handle is passed to the implementation as the trailing `stream` argument
+        if method.ret_type.as_stream().is_some() {
+            if let Some(idx) = method
+                .params
+                .iter()
+                .position(|param| param.name == RESERVED_ARG_NAME_STREAM)
+            {
+                return Err(error(
+                    INVALID_RESERVED_ARG_NAME_STREAM,
+                    sig.params.items[idx].span,
+                ));
+            }
+        }
+
+        // The stream errors are delivered through the iterator without the error code
+        if method.doc.throws().is_some() && matches!(method.ret_type, TypeAnnotation::Stream(..)) {
+            return Err(error(INVALID_THROWS_METHOD, sig.span));
+        }
+
+        // The signal rejects the pending Promise, so it is only meaningful in the Promise methods
+        let mut signal_params = method
+            .params
+            .iter()
+            .enumerate()
+            .filter(|(_, param)| param.type_annotation.is_abort_signal())
+            .map(|(idx, _)| idx);
+
+        if let Some(idx) = signal_params.next() {
+            if !matches!(method.ret_type, TypeAnnotation::Promise(..)) {
+                return Err(error(
+                    INVALID_ABORT_SIGNAL_METHOD,
+                    sig.params.items[idx].span,
+                ));
+            }
+
+            if let Some(idx) = signal_params.next() {
+                return Err(error(
+                    INVALID_DUPLICATE_ABORT_SIGNAL,
+                    sig.params.items[idx].span,
+                ));
+            }
+        }
+
+        Ok(method)
+    }
+
+    /// Converts the method return type to the type annotation.
+    ///
+    /// `AsyncIterable<T>` is only allowed here, so it is converted to the stream type before the other types.
+    fn try_into_ret_type(&mut self, ts_type: &TSType<'a>) -> Result<TypeAnnotation, anyhow::Error> {
+        match ts_type {
+            TSType::TSTypeReference(type_ref)
+                if matches!(
+                    &type_ref.type_name,
+                    TSTypeName::IdentifierReference(ident_ref) if ident_ref.name == RESERVED_TYPE_ASYNC_ITERABLE
+                ) =>
+            {
+                let item_type = match &type_ref.type_arguments {
+                    Some(type_args) if type_args.params.len() == 1 => {
+                        self.try_into_type_annotation(type_args.params.first().unwrap())?
+                    }
+                    _ => anyhow::bail!("Invalid async iterable type"),
+                };
+
+                if matches!(
+                    item_type,
+                    TypeAnnotation::Void
+                        | TypeAnnotation::Promise(..)
+                        | TypeAnnotation::Function(..)
+                ) {
+                    anyhow::bail!(INVALID_STREAM_ITEM);
+                }
+
+                Ok(TypeAnnotation::Stream(Box::new(item_type)))
+            }
+            _ => self.try_into_type_annotation(ts_type),
         }
     }
 
@@ -474,9 +852,31 @@ impl<'a> NativeModuleAnalyzer<'a> {
                         } else {
                             None
                         };
+                        let doc = self.doc_of(sig.span);
+                        let throttle = match doc.throttle() {
+                            Some(interval) => Some(
+                                parse_interval(interval)
+                                    .ok_or_else(|| error(INVALID_THROTTLE, sig.span))?,
+                            ),
+                            None => None,
+                        };
+                        let buffer = match doc.buffer() {
+                            Some(size) => Some(
+                                size.parse::<usize>()
+                                    .ok()
+                                    .filter(|size| *size > 0)
+                                    .ok_or_else(|| error(INVALID_BUFFER, sig.span))?,
+                            ),
+                            None => None,
+                        };
+
                         Ok(Signal {
                             name: event_name,
                             payload_type,
+                            throttle,
+                            latest_only: doc.latest_only(),
+                            buffer,
+                            doc,
                         })
                     } else {
                         Err(error(INVALID_SPEC, sig.span))
@@ -504,6 +904,7 @@ impl<'a> NativeModuleAnalyzer<'a> {
             TSType::TSBooleanKeyword(..) => Ok(TypeAnnotation::Boolean),
             TSType::TSNumberKeyword(..) => Ok(TypeAnnotation::Number),
             TSType::TSStringKeyword(..) => Ok(TypeAnnotation::String),
+            TSType::TSBigIntKeyword(..) => Ok(TypeAnnotation::BigInt),
             TSType::TSArrayType(arr_type) => {
                 let type_annotation = self.try_into_type_annotation(&arr_type.element_type)?;
                 Ok(TypeAnnotation::Array(Box::new(type_annotation)))
@@ -511,6 +912,28 @@ impl<'a> NativeModuleAnalyzer<'a> {
             TSType::TSTypeReference(type_ref) => match &type_ref.type_name {
                 TSTypeName::IdentifierReference(ident_ref) => match ident_ref.name.as_str() {
                     RESERVED_TYPE_ARRAY_BUFFER => Ok(TypeAnnotation::ArrayBuffer),
+                    RESERVED_TYPE_BIG_UINT64 => Ok(TypeAnnotation::BigUint64),
+                    name if TypedArrayType::from_name(name).is_some() => Ok(
+                        TypeAnnotation::TypedArray(TypedArrayType::from_name(name).unwrap()),
+                    ),
+                    RESERVED_TYPE_ARRAY => match &type_ref.type_arguments {
+                        Some(type_args) if type_args.params.len() == 1 => {
+                            let element_type = type_args.params.first().unwrap();
+                            let element_type = self.try_into_type_annotation(element_type)?;
+                            Ok(TypeAnnotation::Array(Box::new(element_type)))
+                        }
+                        _ => anyhow::bail!("Invalid array type"),
+                    },
+                    RESERVED_TYPE_RECORD => match &type_ref.type_arguments {
+                        Some(type_args) if type_args.params.len() == 2 => {
+                            if !matches!(type_args.params[0], TSType::TSStringKeyword(..)) {
+                                anyhow::bail!(INVALID_MAP_KEY);
+                            }
+                            let value_type = self.try_into_type_annotation(&type_args.params[1])?;
+                            Ok(TypeAnnotation::Map(Box::new(value_type)))
+                        }
+                        _ => anyhow::bail!("Invalid record type"),
+                    },
                     RESERVED_TYPE_PROMISE => match &type_ref.type_arguments {
                         Some(type_args) if type_args.params.len() == 1 => {
                             let resolved_type = type_args.params.first().unwrap();
@@ -519,20 +942,282 @@ impl<'a> NativeModuleAnalyzer<'a> {
                         }
                         _ => anyhow::bail!("Invalid promise type"),
                     },
-                    _ => Ok(TypeAnnotation::Ref(RefTypeAnnotation {
-                        ref_id: ident_ref.reference_id(),
-                        name: ident_ref.name.to_string(),
-                    })),
+                    RESERVED_TYPE_ASYNC_ITERABLE => anyhow::bail!(INVALID_STREAM),
+                    RESERVED_TYPE_ABORT_SIGNAL => anyhow::bail!(INVALID_ABORT_SIGNAL),
+                    _ => {
+                        let type_args = match &type_ref.type_arguments {
+                            Some(type_args) => type_args
+                                .params
+                                .iter()
+                                .map(|type_arg| self.try_into_type_annotation(type_arg))
+                                .collect::<Result<Vec<_>, _>>()?,
+                            None => vec![],
+                        };
+
+                        Ok(TypeAnnotation::Ref(RefTypeAnnotation {
+                            ref_id: ident_ref.reference_id(),
+                            name: ident_ref.name.to_string(),
+                            span: ident_ref.span,
+                            type_args,
+                        }))
+                    }
                 },
                 _ => anyhow::bail!(INVALID_TYPE_REFERENCE),
             },
+            TSType::TSLiteralType(lit_type) => match &lit_type.literal {
+                TSLiteral::StringLiteral(str_lit) => {
+                    Ok(TypeAnnotation::StringLiteral(str_lit.value.to_string()))
+                }
+                _ => anyhow::bail!(INVALID_SPEC),
+            },
+            TSType::TSParenthesizedType(paren_type) => {
+                self.try_into_type_annotation(&paren_type.type_annotation)
+            }
             TSType::TSUnionType(union_type) => self.try_into_nullable(union_type),
-            TSType::TSTypeLiteral { .. } => anyhow::bail!(INVALID_TYPE_LITERAL),
+            TSType::TSTypeLiteral(type_lit) => self.try_into_index_signature(type_lit),
             TSType::TSFunctionType { .. } => anyhow::bail!(INVALID_FUNC_PARAM),
             _ => anyhow::bail!(INVALID_SPEC),
         }
     }
 
+    /// Converts the function type to the callback type annotation (eg. `(progress: number) => void`).
+    fn try_into_function(
+        &mut self,
+        fn_type: &TSFunctionType<'a>,
+    ) -> Result<TypeAnnotation, anyhow::Error> {
+        if fn_type.params.rest.is_some() || fn_type.this_param.is_some() {
+            anyhow::bail!(INVALID_SPEC);
+        }
+
+        if !matches!(
+            fn_type.return_type.type_annotation,
+            TSType::TSVoidKeyword(..)
+        ) {
+            anyhow::bail!(INVALID_FUNC_RETURN);
+        }
+
+        let params = fn_type
+            .params
+            .items
+            .iter()
+            .map(|param| {
+                let type_annotation = param
+                    .pattern
+                    .type_annotation
+                    .as_ref()
+                    .ok_or_else(|| anyhow::anyhow!(INVALID_SPEC))?;
+                let type_annotation =
+                    self.try_into_type_annotation(&type_annotation.type_annotation)?;
+
+                if matches!(
+                    type_annotation,
+                    TypeAnnotation::Void | TypeAnnotation::Promise(..)
+                ) {
+                    anyhow::bail!(INVALID_FUNC_ARG);
+                }
+
+                if param.pattern.optional {
+                    self.try_into_optional(type_annotation)
+                } else {
+                    Ok(type_annotation)
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(TypeAnnotation::Function(FunctionTypeAnnotation { params }))
+    }
+
+    /// Returns `true` if the type is a string keyed map type (eg. `Record<string, T>`, `{ [key: string]: T }`).
+    fn is_map_type(ts_type: &TSType<'a>) -> bool {
+        match ts_type {
+            TSType::TSTypeLiteral(type_lit) => matches!(
+                type_lit.members.as_slice(),
+                [TSSignature::TSIndexSignature(..)]
+            ),
+            TSType::TSTypeReference(type_ref) => matches!(
+                &type_ref.type_name,
+                TSTypeName::IdentifierReference(ident_ref) if ident_ref.name == RESERVED_TYPE_RECORD
+            ),
+            _ => false,
+        }
+    }
+
+    fn try_into_index_signature(
+        &mut self,
+        type_lit: &TSTypeLiteral<'a>,
+    ) -> Result<TypeAnnotation, anyhow::Error> {
+        match type_lit.members.as_slice() {
+            [TSSignature::TSIndexSignature(index_sig)] => {
+                let is_string_key = match index_sig.parameters.as_slice() {
+                    [param] => matches!(
+                        param.type_annotation.type_annotation,
+                        TSType::TSStringKeyword(..)
+                    ),
+                    _ => false,
+                };
+
+                if !is_string_key {
+                    anyhow::bail!(INVALID_MAP_KEY);
+                }
+
+                let value_type =
+                    self.try_into_type_annotation(&index_sig.type_annotation.type_annotation)?;
+                Ok(TypeAnnotation::Map(Box::new(value_type)))
+            }
+            _ => anyhow::bail!(INVALID_TYPE_LITERAL),
+        }
+    }
+
+    /// Returns `true` if the union consists of literal types (and optionally `null`).
+    ///
+    /// eg. `'foo' | 'bar'`, `0 | 1 | null`
+    fn is_literal_union(union_type: &TSUnionType<'a>) -> bool {
+        let mut literals = 0;
+
+        for ts_type in &union_type.types {
+            match ts_type {
+                TSType::TSLiteralType(..) => literals += 1,
+                TSType::TSNullKeyword(..) => {}
+                _ => return false,
+            }
+        }
+
+        literals > 0
+    }
+
+    /// Converts the union of literal types into the enum type.
+    ///
+    /// The member names are derived from the literal values.
+    ///
+    /// eg. `'fast' | 'accurate'` -> `enum { Fast = 'fast', Accurate = 'accurate' }`, `0 | 1` -> `enum { Value0 = 0, Value1 = 1 }`
+    fn try_into_literal_enum(
+        &mut self,
+        name: String,
+        union_type: &TSUnionType<'a>,
+    ) -> Result<TypeAnnotation, anyhow::Error> {
+        let mut members: Vec<EnumMember> = vec![];
+        let mut nullable = false;
+
+        for ts_type in &union_type.types {
+            let lit_type = match ts_type {
+                TSType::TSNullKeyword(..) => {
+                    nullable = true;
+                    continue;
+                }
+                TSType::TSLiteralType(lit_type) => lit_type,
+                _ => anyhow::bail!(INVALID_UNION_TYPE),
+            };
+
+            let value = match &lit_type.literal {
+                TSLiteral::StringLiteral(str_lit) => {
+                    EnumMemberValue::String(str_lit.value.to_string())
+                }
+                TSLiteral::NumericLiteral(num_lit) if !num_lit.raw_str().contains(".") => {
+                    EnumMemberValue::Number(num_lit.value as usize)
+                }
+                _ => anyhow::bail!(INVALID_ENUM_MEMBER),
+            };
+
+            if let Some(first) = members.first() {
+                if std::mem::discriminant(&first.value) != std::mem::discriminant(&value) {
+                    anyhow::bail!(INVALID_MIXED_ENUM_MEMBER);
+                }
+            }
+
+            let member_name = match &value {
+                // The characters not allowed in the identifiers separate the words (eg. `image/png` to `ImagePng`)
+                EnumMemberValue::String(value) => pascal_case(
+                    &value
+                        .chars()
+                        .map(|c| {
+                            if c.is_ascii_alphanumeric() || c == '_' {
+                                c
+                            } else {
+                                ' '
+                            }
+                        })
+                        .collect::<String>(),
+                ),
+                EnumMemberValue::Number(value) => value.to_string(),
+            };
+            let member_name = if member_name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+                member_name
+            } else {
+                format!("Value{member_name}")
+            };
+
+            if members.iter().any(|member| member.name == member_name) {
+                anyhow::bail!("{} ({})", INVALID_DUPLICATE_ENUM_MEMBER, member_name);
+            }
+
+            members.push(EnumMember {
+                name: member_name,
+                value,
+                doc: JsDoc::default(),
+            });
+        }
+
+        let enum_type = TypeAnnotation::Enum(EnumTypeAnnotation {
+            name,
+            members,
+            doc: JsDoc::default(),
+        });
+
+        if nullable {
+            Ok(TypeAnnotation::Nullable(Box::new(enum_type)))
+        } else {
+            Ok(enum_type)
+        }
+    }
+
+    /// Returns `true` if the union consists of two or more type references (and optionally `null`).
+    ///
+    /// eg. `Foo | Bar`, `Foo | Bar | null`
+    fn is_object_union(union_type: &TSUnionType<'a>) -> bool {
+        let mut refs = 0;
+
+        for ts_type in &union_type.types {
+            match ts_type {
+                TSType::TSTypeReference(..) => refs += 1,
+                TSType::TSNullKeyword(..) => {}
+                _ => return false,
+            }
+        }
+
+        refs > 1
+    }
+
+    fn try_into_union(
+        &mut self,
+        name: String,
+        union_type: &TSUnionType<'a>,
+    ) -> Result<TypeAnnotation, anyhow::Error> {
+        let mut variants = vec![];
+        let mut nullable = false;
+
+        for ts_type in &union_type.types {
+            match ts_type {
+                TSType::TSNullKeyword(..) => nullable = true,
+                ts_type => match self.try_into_type_annotation(ts_type)? {
+                    variant @ TypeAnnotation::Ref(..) => variants.push(variant),
+                    _ => anyhow::bail!(INVALID_UNION_TYPE),
+                },
+            }
+        }
+
+        let union_type = TypeAnnotation::Union(UnionTypeAnnotation {
+            name,
+            discriminant: String::new(),
+            variants,
+        });
+
+        if nullable {
+            Ok(TypeAnnotation::Nullable(Box::new(union_type)))
+        } else {
+            Ok(union_type)
+        }
+    }
+
     fn try_into_nullable(
         &mut self,
         union_type: &TSUnionType<'a>,
@@ -555,6 +1240,17 @@ impl<'a> NativeModuleAnalyzer<'a> {
         Ok(TypeAnnotation::Nullable(Box::new(base)))
     }
 
+    fn try_into_optional(
+        &self,
+        type_annotation: TypeAnnotation,
+    ) -> Result<TypeAnnotation, anyhow::Error> {
+        match type_annotation {
+            TypeAnnotation::Promise(..) => anyhow::bail!("Promise type cannot be optional"),
+            nullable @ TypeAnnotation::Nullable(..) => Ok(nullable),
+            base => Ok(TypeAnnotation::Nullable(Box::new(base))),
+        }
+    }
+
     /// Check the specification interface extends `NativeModule` interface of 'craby-modules' package.
     fn is_spec(&self, it: &TSInterfaceDeclaration<'a>) -> bool {
         it.extends.iter().any(|ex| {
@@ -642,6 +1338,7 @@ impl<'a> NativeModuleAnalyzer<'a> {
         _decls: &FxHashMap<SymbolId, TypeAnnotation>,
         types: &mut FxHashSet<TypeAnnotation>,
         enums: &mut FxHashSet<TypeAnnotation>,
+        unions: &mut FxHashSet<TypeAnnotation>,
     ) {
         match type_annotation {
             obj_type @ TypeAnnotation::Object(obj) => {
@@ -653,66 +1350,333 @@ impl<'a> NativeModuleAnalyzer<'a> {
                         _decls,
                         types,
                         enums,
+                        unions,
                     );
                 }
             }
             enum_type @ TypeAnnotation::Enum(..) => {
                 enums.insert(enum_type.clone());
             }
+            union_type @ TypeAnnotation::Union(union) => {
+                unions.insert(union_type.clone());
+                for variant in &union.variants {
+                    NativeModuleAnalyzer::collect_types(
+                        variant, _scoping, _decls, types, enums, unions,
+                    );
+                }
+            }
             TypeAnnotation::Nullable(base_type) => {
-                NativeModuleAnalyzer::collect_types(base_type, _scoping, _decls, types, enums);
+                NativeModuleAnalyzer::collect_types(
+                    base_type, _scoping, _decls, types, enums, unions,
+                );
+            }
+            TypeAnnotation::Array(element_type) | TypeAnnotation::Map(element_type) => {
+                NativeModuleAnalyzer::collect_types(
+                    element_type,
+                    _scoping,
+                    _decls,
+                    types,
+                    enums,
+                    unions,
+                );
+            }
+            TypeAnnotation::Promise(resolved_type) | TypeAnnotation::Stream(resolved_type) => {
+                NativeModuleAnalyzer::collect_types(
+                    resolved_type,
+                    _scoping,
+                    _decls,
+                    types,
+                    enums,
+                    unions,
+                );
             }
-            TypeAnnotation::Promise(resolved_type) => {
-                NativeModuleAnalyzer::collect_types(resolved_type, _scoping, _decls, types, enums);
+            TypeAnnotation::Function(function_type) => {
+                for param in &function_type.params {
+                    NativeModuleAnalyzer::collect_types(
+                        param, _scoping, _decls, types, enums, unions,
+                    );
+                }
             }
             _ => {}
         }
     }
 
+    /// Replaces the type references with the declared types.
+    ///
+    /// Unresolved or unsupported references are collected into `diagnostics`.
     fn resolve_refs(
+        &self,
+        type_annotation: &mut TypeAnnotation,
+        diagnostics: &mut Vec<OxcDiagnostic>,
+    ) {
+        self.resolve_refs_with(
+            type_annotation,
+            &FxHashMap::default(),
+            &mut vec![],
+            diagnostics,
+        );
+    }
+
+    /// Replaces the type references with the declared types and the type parameters with `type_args`.
+    ///
+    /// `resolving` holds the declarations being resolved to report the recursive types
+    /// (eg. `type Node = { next: Node | null }`) instead of resolving them infinitely.
+    fn resolve_refs_with(
+        &self,
         type_annotation: &mut TypeAnnotation,
-        scoping: &Scoping,
-        decls: &FxHashMap<SymbolId, TypeAnnotation>,
+        type_args: &FxHashMap<SymbolId, TypeAnnotation>,
+        resolving: &mut Vec<SymbolId>,
+        diagnostics: &mut Vec<OxcDiagnostic>,
     ) {
         match type_annotation {
-            TypeAnnotation::Ref(RefTypeAnnotation { ref_id, .. }) => {
-                match scoping.get_reference(*ref_id).symbol_id() {
-                    Some(sym_id) => {
-                        match decls.get(&sym_id) {
-                            Some(resolved) => {
-                                let mut resolved = resolved.clone();
-                                NativeModuleAnalyzer::resolve_refs(&mut resolved, scoping, decls);
-                                *type_annotation = resolved;
+            TypeAnnotation::Ref(ref_type) => {
+                let RefTypeAnnotation { name, span, .. } = ref_type;
+                let sym_id = match self.scoping.get_reference(ref_type.ref_id).symbol_id() {
+                    Some(sym_id) => sym_id,
+                    None => {
+                        return diagnostics
+                            .push(error(&format!("Cannot find type `{}`", name), *span))
+                    }
+                };
+
+                // Type parameter of the generic type being instantiated
+                if let Some(type_arg) = type_args.get(&sym_id) {
+                    *type_annotation = type_arg.clone();
+                    return;
+                }
+
+                if let Some(generic) = self.generics.get(&sym_id) {
+                    let ref_type = ref_type.clone();
+                    if let Some(resolved) = self.instantiate_generic(
+                        sym_id,
+                        generic,
+                        &ref_type,
+                        type_args,
+                        resolving,
+                        diagnostics,
+                    ) {
+                        *type_annotation = resolved;
+                    }
+                    return;
+                }
+
+                if !ref_type.type_args.is_empty() {
+                    return diagnostics
+                        .push(error(&format!("Type `{}` is not generic", name), *span));
+                }
+
+                match self.decls.get(&sym_id) {
+                    Some(_) if resolving.contains(&sym_id) => {
+                        diagnostics.push(error(
+                            &format!("{INVALID_RECURSIVE_TYPE} (`{}`)", name),
+                            *span,
+                        ));
+                    }
+                    Some(resolved) => {
+                        let mut resolved = resolved.clone();
+                        let diagnostics_len = diagnostics.len();
+                        resolving.push(sym_id);
+                        self.resolve_refs_with(
+                            &mut resolved,
+                            &FxHashMap::default(),
+                            resolving,
+                            diagnostics,
+                        );
+                        resolving.pop();
+
+                        // The discriminant is found after the variants are resolved
+                        let union_type = match &resolved {
+                            TypeAnnotation::Nullable(base_type) => base_type.as_union(),
+                            resolved => resolved.as_union(),
+                        };
+                        if let Some(union_type) = union_type {
+                            if union_type.discriminant.is_empty()
+                                && diagnostics.len() == diagnostics_len
+                            {
+                                diagnostics.push(error(
+                                    &format!(
+                                        "{INVALID_DISCRIMINATED_UNION} (`{}`)",
+                                        union_type.name
+                                    ),
+                                    *span,
+                                ));
                             }
-                            _ => unreachable!(
-                                "Symbol not found (ref: {:?}, sym: {:?})",
-                                ref_id, sym_id
-                            ),
+                        }
+
+                        *type_annotation = resolved;
+                    }
+                    None => {
+                        let flags = self.scoping.symbol_flags(sym_id);
+                        let message = if flags.is_import() {
+                            format!(
+                                "Cannot resolve imported type `{}`. Only types declared in relative modules (eg. `./types`) are supported",
+                                name
+                            )
+                        } else if flags.is_class() {
+                            format!("Class type is not supported: `{}`", name)
+                        } else {
+                            format!("Unsupported type reference `{}`", name)
                         };
+                        diagnostics.push(error(&message, *span));
                     }
-                    _ => unreachable!("Unknown type reference (ref: {:?})", ref_id),
-                }
+                };
             }
             TypeAnnotation::Object(obj) => {
                 for prop in &mut obj.props {
-                    NativeModuleAnalyzer::resolve_refs(&mut prop.type_annotation, scoping, decls);
+                    self.resolve_refs_with(
+                        &mut prop.type_annotation,
+                        type_args,
+                        resolving,
+                        diagnostics,
+                    );
                 }
             }
             TypeAnnotation::Nullable(base_type) => {
-                NativeModuleAnalyzer::resolve_refs(base_type, scoping, decls);
+                self.resolve_refs_with(base_type, type_args, resolving, diagnostics);
+
+                // Nullable type argument of the nullable type parameter (eg. `T | null` with `Foo<number | null>`)
+                if base_type.is_nullable() {
+                    let base_type = (**base_type).clone();
+                    *type_annotation = base_type;
+                }
             }
-            TypeAnnotation::Promise(t) => {
-                NativeModuleAnalyzer::resolve_refs(&mut *t, scoping, decls);
+            TypeAnnotation::Array(element_type) | TypeAnnotation::Map(element_type) => {
+                self.resolve_refs_with(element_type, type_args, resolving, diagnostics);
+            }
+            TypeAnnotation::Union(union_type) => {
+                for variant in &mut union_type.variants {
+                    self.resolve_refs_with(variant, type_args, resolving, diagnostics);
+                }
+                union_type.discriminant = union_type.find_discriminant().unwrap_or_default();
+            }
+            TypeAnnotation::Promise(t) | TypeAnnotation::Stream(t) => {
+                self.resolve_refs_with(&mut *t, type_args, resolving, diagnostics);
+            }
+            TypeAnnotation::Function(function_type) => {
+                for param in &mut function_type.params {
+                    self.resolve_refs_with(param, type_args, resolving, diagnostics);
+                }
             }
             _ => {}
         }
     }
 
+    /// Instantiates the generic type alias with the type arguments (eg. `Page<User>`).
+    ///
+    /// The instantiated object type is named by the alias name followed by the type argument names (eg. `PageUser`).
+    ///
+    /// The alias referencing itself in the properties is reported as recursive, since it would be instantiated infinitely
+    /// even with the different type arguments (eg. `type Tree<T> = { children: Tree<T[]> }`).
+    fn instantiate_generic(
+        &self,
+        sym_id: SymbolId,
+        generic: &GenericAlias,
+        ref_type: &RefTypeAnnotation,
+        type_args: &FxHashMap<SymbolId, TypeAnnotation>,
+        resolving: &mut Vec<SymbolId>,
+        diagnostics: &mut Vec<OxcDiagnostic>,
+    ) -> Option<TypeAnnotation> {
+        let span = ref_type.span;
+
+        if ref_type.type_args.len() != generic.params.len() {
+            diagnostics.push(error(
+                &format!(
+                    "Generic type `{}` requires {} type argument(s)",
+                    generic.name,
+                    generic.params.len()
+                ),
+                span,
+            ));
+            return None;
+        }
+
+        let mut name = generic.name.clone();
+        let mut params = FxHashMap::default();
+
+        for (param, type_arg) in generic.params.iter().zip(&ref_type.type_args) {
+            let mut type_arg = type_arg.clone();
+            let diagnostics_len = diagnostics.len();
+            self.resolve_refs_with(&mut type_arg, type_args, resolving, diagnostics);
+
+            if diagnostics.len() > diagnostics_len {
+                return None;
+            }
+
+            match type_arg_name(&type_arg) {
+                Some(type_arg_name) => name.push_str(&type_arg_name),
+                None => {
+                    diagnostics.push(error(INVALID_TYPE_ARG, span));
+                    return None;
+                }
+            }
+
+            params.insert(*param, type_arg);
+        }
+
+        if self.scoping.get_root_binding(&name).is_some() {
+            diagnostics.push(error(
+                &format!(
+                    "Instantiated type `{}` conflicts with the declared type",
+                    name
+                ),
+                span,
+            ));
+            return None;
+        }
+
+        if resolving.contains(&sym_id) {
+            diagnostics.push(error(
+                &format!("{INVALID_RECURSIVE_GENERIC} (`{}`)", name),
+                span,
+            ));
+            return None;
+        }
+
+        let mut props = generic.props.clone();
+        resolving.push(sym_id);
+        for prop in &mut props {
+            self.resolve_refs_with(&mut prop.type_annotation, &params, resolving, diagnostics);
+        }
+        resolving.pop();
+
+        let instance = TypeAnnotation::Object(ObjectTypeAnnotation {
+            name: name.clone(),
+            props,
+            doc: generic.doc.clone(),
+        });
+
+        // Different instantiations with the same name (eg. `Page<UserArray>` and `Page<User[]>`)
+        let mut instances = self.instances.borrow_mut();
+        match instances.get(&name) {
+            Some(other) if *other != instance => {
+                diagnostics.push(error(
+                    &format!("Instantiated type `{name}` conflicts with the other instantiation"),
+                    span,
+                ));
+                None
+            }
+            Some(_) => Some(instance),
+            None => {
+                instances.insert(name, instance.clone());
+                Some(instance)
+            }
+        }
+    }
+
     fn try_assert_reserved_type(&self, name: &Atom<'a>) -> Result<(), anyhow::Error> {
         match name.as_str() {
-            RESERVED_TYPE_ARRAY_BUFFER | RESERVED_TYPE_PROMISE => {
+            RESERVED_TYPE_ARRAY
+            | RESERVED_TYPE_ARRAY_BUFFER
+            | RESERVED_TYPE_BIG_UINT64
+            | RESERVED_TYPE_PROMISE
+            | RESERVED_TYPE_RECORD
+            | RESERVED_TYPE_ASYNC_ITERABLE
+            | RESERVED_TYPE_ABORT_SIGNAL => {
                 anyhow::bail!("Cannot use reserved type: {}", name.as_str())
             }
+            name if TypedArrayType::from_name(name).is_some() => {
+                anyhow::bail!("Cannot use reserved type: {}", name)
+            }
             _ => {}
         }
 
@@ -723,12 +1687,14 @@ impl<'a> NativeModuleAnalyzer<'a> {
         Ok(())
     }
 
-    fn try_into_schema(self) -> Result<Vec<Schema>, anyhow::Error> {
+    fn try_into_schema(mut self) -> Result<Vec<Schema>, ParseError> {
         let mut schemas = Vec::with_capacity(self.specs.len());
+        let mut diagnostics = vec![];
 
-        for (id, spec) in self.specs {
+        for (id, spec) in std::mem::take(&mut self.specs) {
             let mut types = FxHashSet::default();
             let mut enums = FxHashSet::default();
+            let mut unions = FxHashSet::default();
             let module_name = self
                 .mods
                 .get(&id)
@@ -739,11 +1705,7 @@ impl<'a> NativeModuleAnalyzer<'a> {
                 .into_iter()
                 .map(|mut method| {
                     for param in &mut method.params {
-                        NativeModuleAnalyzer::resolve_refs(
-                            &mut param.type_annotation,
-                            self.scoping,
-                            &self.decls,
-                        );
+                        self.resolve_refs(&mut param.type_annotation, &mut diagnostics);
 
                         NativeModuleAnalyzer::collect_types(
                             &param.type_annotation,
@@ -751,15 +1713,27 @@ impl<'a> NativeModuleAnalyzer<'a> {
                             &self.decls,
                             &mut types,
                             &mut enums,
+                            &mut unions,
                         );
                     }
 
                     // Resolve type annotation of return value
-                    NativeModuleAnalyzer::resolve_refs(
-                        &mut method.ret_type,
-                        self.scoping,
-                        &self.decls,
-                    );
+                    let ret_span = match &method.ret_type {
+                        TypeAnnotation::Ref(ref_type) => Some(ref_type.span),
+                        _ => None,
+                    };
+                    let diagnostics_count = diagnostics.len();
+                    self.resolve_refs(&mut method.ret_type, &mut diagnostics);
+
+                    // Unresolved references are already reported
+                    if method.is_constants()
+                        && method.ret_type.as_object().is_none()
+                        && diagnostics.len() == diagnostics_count
+                    {
+                        if let Some(span) = ret_span {
+                            diagnostics.push(error(INVALID_CONSTANTS, span));
+                        }
+                    }
 
                     NativeModuleAnalyzer::collect_types(
                         &method.ret_type,
@@ -767,8 +1741,35 @@ impl<'a> NativeModuleAnalyzer<'a> {
                         &self.decls,
                         &mut types,
                         &mut enums,
+                        &mut unions,
                     );
 
+                    // The `@throws` tag is not a type reference, so the error code type is resolved by the name
+                    if let Some(name) = method.doc.throws() {
+                        let resolved = self
+                            .scoping
+                            .get_root_binding(&name)
+                            .and_then(|sym_id| self.decls.get(&sym_id))
+                            .filter(|type_annotation| {
+                                type_annotation.as_enum().is_some_and(|enum_type| {
+                                    enum_type.members.iter().all(|member| {
+                                        matches!(member.value, EnumMemberValue::String(..))
+                                    })
+                                })
+                            });
+
+                        match resolved {
+                            Some(enum_type) => {
+                                enums.insert(enum_type.clone());
+                                method.throws = Some(enum_type.clone());
+                            }
+                            None => diagnostics.push(OxcDiagnostic::error(format!(
+                                "{INVALID_THROWS} (`{name}` of `{}`)",
+                                method.name
+                            ))),
+                        }
+                    }
+
                     method
                 })
                 .collect::<Vec<Method>>();
@@ -778,7 +1779,7 @@ impl<'a> NativeModuleAnalyzer<'a> {
                 .into_iter()
                 .map(|mut signal| {
                     if let Some(ref mut payload_type) = signal.payload_type {
-                        NativeModuleAnalyzer::resolve_refs(payload_type, self.scoping, &self.decls);
+                        self.resolve_refs(payload_type, &mut diagnostics);
 
                         NativeModuleAnalyzer::collect_types(
                             payload_type,
@@ -786,18 +1787,36 @@ impl<'a> NativeModuleAnalyzer<'a> {
                             &self.decls,
                             &mut types,
                             &mut enums,
+                            &mut unions,
                         );
                     }
                     signal
                 })
                 .collect::<Vec<Signal>>();
 
+            // Keep collecting the diagnostics of the other specs
+            if !diagnostics.is_empty() {
+                continue;
+            }
+
             let mut aliases = types.into_iter().collect::<Vec<_>>();
             let mut enums = enums.into_iter().collect::<Vec<_>>();
+            let mut unions = unions.into_iter().collect::<Vec<_>>();
 
             // Sort collected metadata to ensure deterministic output (for hash)
             aliases.sort_by_key(|v| v.as_object().unwrap().name.to_lowercase());
+
+            // The conflicting instantiations are already reported at the use site
+            for pair in aliases.windows(2) {
+                let name = &pair[0].as_object().unwrap().name;
+                if *name == pair[1].as_object().unwrap().name {
+                    diagnostics.push(OxcDiagnostic::error(format!(
+                        "Conflicting type name: `{name}`"
+                    )));
+                }
+            }
             enums.sort_by_key(|v| v.as_enum().unwrap().name.to_lowercase());
+            unions.sort_by_key(|v| v.as_union().unwrap().name.to_lowercase());
             methods.sort_by_key(|v| v.name.to_lowercase());
             signals.sort_by_key(|v| v.name.to_lowercase());
 
@@ -805,17 +1824,372 @@ impl<'a> NativeModuleAnalyzer<'a> {
                 module_name: module_name.to_owned(),
                 aliases,
                 enums,
+                unions,
                 methods,
                 signals,
             });
         }
 
+        if !diagnostics.is_empty() {
+            return Err(ParseError::Oxc { diagnostics });
+        }
+
         Ok(schemas)
     }
+
+    /// Flattens the properties of the base types into the interface types.
+    fn flatten_interfaces(&mut self) {
+        let mut ids = self.bases.keys().copied().collect::<Vec<_>>();
+        // Flatten in declaration order to report the diagnostics deterministically
+        ids.sort();
+
+        for id in ids {
+            self.flatten_interface(id, &mut vec![]);
+        }
+    }
+
+    /// Flattens the interface type (eg. `interface User extends Base { .. }`).
+    ///
+    /// The properties of the base types come first (in the order of the `extends` clause),
+    /// so the flattened type is equivalent to the interface declaring all properties by itself.
+    fn flatten_interface(&mut self, id: SymbolId, visiting: &mut Vec<SymbolId>) {
+        let (decl_span, bases) = match self.bases.remove(&id) {
+            Some(bases) => bases,
+            None => return,
+        };
+
+        visiting.push(id);
+
+        let mut props = Vec::<Prop>::new();
+        let mut diagnostics = vec![];
+
+        for base in bases {
+            let span = base.span;
+
+            // Flatten the base type first if it also extends other types
+            if let Some(base_id) = self.scoping.get_reference(base.ref_id).symbol_id() {
+                if visiting.contains(&base_id) {
+                    self.collect_error(INVALID_CIRCULAR_EXTENDS, span);
+                    continue;
+                }
+                self.flatten_interface(base_id, visiting);
+            }
+
+            if let Some(base_props) = self.base_props(&base, &mut diagnostics) {
+                for prop in base_props {
+                    self.try_merge_prop(&mut props, prop, span, visiting);
+                }
+            }
+        }
+
+        self.diagnostics.extend(diagnostics);
+
+        if let Some(TypeAnnotation::Object(obj)) = self.decls.get(&id) {
+            let own_props = obj.props.clone();
+            for prop in own_props {
+                self.try_merge_prop(&mut props, prop, decl_span, visiting);
+            }
+        }
+
+        visiting.pop();
+
+        if let Some(TypeAnnotation::Object(obj)) = self.decls.get_mut(&id) {
+            obj.props = props;
+        }
+    }
+
+    /// Returns the properties of the base type.
+    ///
+    /// The property types are not resolved here since the referenced interfaces may not be flattened yet.
+    fn base_props(
+        &self,
+        base: &RefTypeAnnotation,
+        diagnostics: &mut Vec<OxcDiagnostic>,
+    ) -> Option<Vec<Prop>> {
+        if let Some(sym_id) = self.scoping.get_reference(base.ref_id).symbol_id() {
+            // Generic base type (eg. `extends Page<User>`)
+            if let Some(generic) = self.generics.get(&sym_id) {
+                if base.type_args.len() == generic.params.len() {
+                    let params = generic
+                        .params
+                        .iter()
+                        .copied()
+                        .zip(base.type_args.iter().cloned())
+                        .collect::<FxHashMap<_, _>>();
+                    let mut props = generic.props.clone();
+                    for prop in &mut props {
+                        self.substitute_type_params(&mut prop.type_annotation, &params);
+                    }
+                    return Some(props);
+                }
+            } else if let Some(TypeAnnotation::Object(obj)) = self.decls.get(&sym_id) {
+                if base.type_args.is_empty() {
+                    return Some(obj.props.clone());
+                }
+            }
+        }
+
+        // Report the unresolved or unsupported base type
+        let diagnostics_len = diagnostics.len();
+        let mut base_type = TypeAnnotation::Ref(base.clone());
+        self.resolve_refs(&mut base_type, diagnostics);
+
+        if diagnostics.len() == diagnostics_len {
+            diagnostics.push(error(INVALID_EXTENDS_TYPE, base.span));
+        }
+
+        None
+    }
+
+    /// Replaces the type parameters with the type arguments without resolving the other references.
+    fn substitute_type_params(
+        &self,
+        type_annotation: &mut TypeAnnotation,
+        params: &FxHashMap<SymbolId, TypeAnnotation>,
+    ) {
+        match type_annotation {
+            TypeAnnotation::Ref(ref_type) => {
+                let sym_id = self.scoping.get_reference(ref_type.ref_id).symbol_id();
+                match sym_id.and_then(|sym_id| params.get(&sym_id)) {
+                    Some(type_arg) => *type_annotation = type_arg.clone(),
+                    None => {
+                        for type_arg in &mut ref_type.type_args {
+                            self.substitute_type_params(type_arg, params);
+                        }
+                    }
+                }
+            }
+            TypeAnnotation::Object(obj) => {
+                for prop in &mut obj.props {
+                    self.substitute_type_params(&mut prop.type_annotation, params);
+                }
+            }
+            TypeAnnotation::Nullable(t)
+            | TypeAnnotation::Array(t)
+            | TypeAnnotation::Map(t)
+            | TypeAnnotation::Promise(t) => self.substitute_type_params(t, params),
+            TypeAnnotation::Union(union_type) => {
+                for variant in &mut union_type.variants {
+                    self.substitute_type_params(variant, params);
+                }
+            }
+            TypeAnnotation::Function(function_type) => {
+                for param in &mut function_type.params {
+                    self.substitute_type_params(param, params);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Merges the property into the flattened properties.
+    ///
+    /// Redeclared properties must have the same type as the inherited one.
+    /// The interfaces being flattened (`visiting`) are treated as being resolved,
+    /// so the property referencing them (eg. `interface Foo extends Bar { next: Foo }`) is reported as recursive.
+    fn try_merge_prop(
+        &mut self,
+        props: &mut Vec<Prop>,
+        prop: Prop,
+        span: Span,
+        visiting: &[SymbolId],
+    ) {
+        let idx = match props.iter().position(|p| p.name == prop.name) {
+            Some(idx) => idx,
+            None => return props.push(prop),
+        };
+
+        let mut diagnostics = vec![];
+        let mut existing = props[idx].clone();
+        let mut resolved = prop.clone();
+        for type_annotation in [&mut existing.type_annotation, &mut resolved.type_annotation] {
+            self.resolve_refs_with(
+                type_annotation,
+                &FxHashMap::default(),
+                &mut visiting.to_vec(),
+                &mut diagnostics,
+            );
+        }
+
+        // Unresolved or recursive references are reported when the redeclared type is resolved
+        if !diagnostics.is_empty() {
+            props[idx] = prop;
+            return;
+        }
+
+        if existing != resolved {
+            self.collect_error(
+                &format!(
+                    "Conflicting property `{}` in the inherited types",
+                    prop.name
+                ),
+                span,
+            );
+        }
+    }
+
+    /// Resolves the declared types of the imported module by their exported names.
+    fn try_into_types(
+        self,
+        names: &[&str],
+    ) -> Result<FxHashMap<String, TypeAnnotation>, ParseError> {
+        let mut types = FxHashMap::default();
+        let mut diagnostics = vec![];
+
+        for name in names {
+            let sym_id = self.scoping.get_root_binding(name);
+
+            // Generic types are instantiated with the declarations of this module
+            if let Some(generic) = sym_id.and_then(|sym_id| self.generics.get(&sym_id)) {
+                diagnostics.push(error(INVALID_GENERIC_IMPORT, generic.span));
+                continue;
+            }
+
+            if let Some(decl) = sym_id.and_then(|sym_id| self.decls.get(&sym_id)) {
+                let mut type_annotation = decl.clone();
+                self.resolve_refs(&mut type_annotation, &mut diagnostics);
+                types.insert(name.to_string(), type_annotation);
+            }
+        }
+
+        if !diagnostics.is_empty() {
+            return Err(ParseError::Oxc { diagnostics });
+        }
+
+        Ok(types)
+    }
+
+    fn collect_type_imports(&mut self, it: &ImportDeclaration<'a>) {
+        if let Some(specifiers) = &it.specifiers {
+            for specifier in specifiers {
+                if let ImportDeclarationSpecifier::ImportSpecifier(spec) = specifier {
+                    self.imports.push(TypeImport {
+                        sym_id: spec.local.symbol_id(),
+                        imported_name: spec.imported.name().to_string(),
+                        source: it.source.value.to_string(),
+                        span: spec.span,
+                    });
+                }
+            }
+        }
+    }
+
+    /// Parses the relative modules and collects the imported types as declarations.
+    ///
+    /// `importers` is the chain of the modules being parsed (to detect circular imports).
+    fn try_resolve_imports(
+        &mut self,
+        path: Option<&Path>,
+        importers: &mut Vec<PathBuf>,
+    ) -> Result<(), ParseError> {
+        let mut imports = BTreeMap::<String, Vec<TypeImport>>::new();
+        for import in std::mem::take(&mut self.imports) {
+            imports
+                .entry(import.source.clone())
+                .or_default()
+                .push(import);
+        }
+
+        for (source, imports) in imports {
+            let span = imports[0].span;
+            let module_path = match path.and_then(|path| resolve_module_path(path, &source)) {
+                Some(module_path) => module_path,
+                None => {
+                    self.collect_error(&format!("Cannot find module `{}`", source), span);
+                    continue;
+                }
+            };
+
+            if importers.contains(&module_path) {
+                self.collect_error(INVALID_CIRCULAR_IMPORT, span);
+                continue;
+            }
+
+            let src = fs::read_to_string(&module_path).map_err(anyhow::Error::from)?;
+            let names = imports
+                .iter()
+                .map(|import| import.imported_name.as_str())
+                .collect::<Vec<_>>();
+
+            importers.push(module_path.clone());
+            let types = try_analyze(&src, Some(&module_path), importers, |analyzer| {
+                analyzer.try_into_types(&names)
+            })
+            .map_err(|e| match e {
+                ParseError::Oxc { diagnostics } => ParseError::Import {
+                    path: module_path.clone(),
+                    src: src.clone(),
+                    diagnostics,
+                },
+                e => e,
+            })?;
+            importers.pop();
+
+            // Unresolved types are reported when they are referenced
+            for import in imports {
+                if let Some(type_annotation) = types.get(&import.imported_name) {
+                    self.decls.insert(import.sym_id, type_annotation.clone());
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the name of the type argument to be mangled into the instantiated type name (eg. `User` of `Page<User>`).
+fn type_arg_name(type_arg: &TypeAnnotation) -> Option<String> {
+    let name = match type_arg {
+        TypeAnnotation::Boolean => "Boolean".to_string(),
+        TypeAnnotation::Number => "Number".to_string(),
+        TypeAnnotation::String => "String".to_string(),
+        TypeAnnotation::BigInt => "BigInt".to_string(),
+        TypeAnnotation::BigUint64 => "BigUint64".to_string(),
+        TypeAnnotation::ArrayBuffer => "ArrayBuffer".to_string(),
+        TypeAnnotation::TypedArray(typed_array) => typed_array.name().to_string(),
+        TypeAnnotation::Object(ObjectTypeAnnotation { name, .. })
+        | TypeAnnotation::Enum(EnumTypeAnnotation { name, .. })
+        | TypeAnnotation::Union(UnionTypeAnnotation { name, .. }) => name.clone(),
+        TypeAnnotation::Array(element_type) => format!("{}Array", type_arg_name(element_type)?),
+        TypeAnnotation::Map(value_type) => format!("{}Map", type_arg_name(value_type)?),
+        TypeAnnotation::Nullable(base_type) => format!("Nullable{}", type_arg_name(base_type)?),
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+/// Parses the interval of the `@throttle` tag into milliseconds (eg. `16ms`, `1s`).
+fn parse_interval(interval: &str) -> Option<u64> {
+    let millis = match interval.strip_suffix("ms") {
+        Some(millis) => millis.parse::<u64>().ok()?,
+        None => interval
+            .strip_suffix('s')?
+            .parse::<u64>()
+            .ok()?
+            .checked_mul(1000)?,
+    };
+
+    (millis > 0).then_some(millis)
+}
+
+/// Resolves the path of the relative module (eg. `./types` to `./types.ts`).
+fn resolve_module_path(importer: &Path, source: &str) -> Option<PathBuf> {
+    let base = importer.parent()?.join(source);
+
+    ["", ".ts", ".tsx", "/index.ts", "/index.tsx"]
+        .iter()
+        .map(|suffix| PathBuf::from(format!("{}{}", base.display(), suffix)))
+        .find(|path| path.is_file())
+        .and_then(|path| path.canonicalize().ok())
 }
 
 impl<'a> Visit<'a> for NativeModuleAnalyzer<'a> {
     fn visit_import_declaration(&mut self, it: &ImportDeclaration<'a>) {
+        if it.source.value.starts_with('.') {
+            return self.collect_type_imports(it);
+        }
+
         if it.source.value.as_str() != NATIVE_MODULE_PKG {
             return;
         }
@@ -852,6 +2226,17 @@ impl<'a> Visit<'a> for NativeModuleAnalyzer<'a> {
         }
     }
 
+    fn visit_export_named_declaration(&mut self, it: &ExportNamedDeclaration<'a>) {
+        // JSDoc comment is attached to the `export` keyword (eg. `/** Foo */ export interface Foo {}`)
+        if let Some(decl) = &it.declaration {
+            if let Some(doc) = self.docs.get(&it.span.start).cloned() {
+                self.docs.insert(decl.span().start, doc);
+            }
+        }
+
+        walk::walk_export_named_declaration(self, it);
+    }
+
     fn visit_ts_interface_declaration(&mut self, it: &TSInterfaceDeclaration<'a>) {
         if it.declare {
             return;
@@ -864,239 +2249,1793 @@ impl<'a> Visit<'a> for NativeModuleAnalyzer<'a> {
             // Collect user defined type (interface)
             self.collect_interface_type(it);
         }
-    }
+    }
+
+    fn visit_ts_type_alias_declaration(&mut self, it: &TSTypeAliasDeclaration<'a>) {
+        if it.declare {
+            return;
+        }
+
+        // Collect user defined type (type alias)
+        self.collect_alias_type(it);
+    }
+
+    fn visit_ts_enum_declaration(&mut self, it: &TSEnumDeclaration<'a>) {
+        if it.declare {
+            return;
+        }
+
+        // Collect user defined enum type
+        self.collect_enum_type(it);
+    }
+
+    fn visit_call_expression(&mut self, it: &CallExpression<'a>) {
+        // Collect module name from `NativeModuleRegistry.get()` or `NativeModuleRegistry.getEnforcing()`
+        self.collect_mod(it);
+    }
+}
+
+/// Parses the spec source code without a file path.
+///
+/// Types imported from the relative modules cannot be resolved. Use `try_parse_schema_file` instead.
+pub fn try_parse_schema(src: &str) -> Result<Vec<Schema>, ParseError> {
+    try_analyze(src, None, &mut vec![], |analyzer| {
+        analyzer.try_into_schema()
+    })
+}
+
+/// Parses the spec file and the relative modules imported from it (eg. `import type { Foo } from './types'`).
+pub fn try_parse_schema_file(path: &Path, src: &str) -> Result<Vec<Schema>, ParseError> {
+    let mut importers = path.canonicalize().into_iter().collect::<Vec<_>>();
+    try_analyze(src, Some(path), &mut importers, |analyzer| {
+        analyzer.try_into_schema()
+    })
+}
+
+fn try_analyze<T>(
+    src: &str,
+    path: Option<&Path>,
+    importers: &mut Vec<PathBuf>,
+    f: impl FnOnce(NativeModuleAnalyzer<'_>) -> Result<T, ParseError>,
+) -> Result<T, ParseError> {
+    let allocator = Allocator::default();
+    let source_type = SourceType::tsx();
+    let ret = Parser::new(&allocator, src, source_type).parse();
+
+    if ret.panicked || !ret.errors.is_empty() {
+        return Err(ParseError::Oxc {
+            diagnostics: ret.errors,
+        });
+    }
+
+    let program = ret.program;
+    let ret = SemanticBuilder::new().build(&program);
+
+    if !ret.errors.is_empty() {
+        return Err(ParseError::Oxc {
+            diagnostics: ret.errors,
+        });
+    }
+
+    let scoping = ret.semantic.into_scoping();
+    let mut analyzer = NativeModuleAnalyzer::new(&scoping);
+
+    analyzer.collect_docs(src, &program.comments);
+    analyzer.visit_program(&program);
+    analyzer.try_resolve_imports(path, importers)?;
+    analyzer.flatten_interfaces();
+
+    if !analyzer.diagnostics.is_empty() {
+        return Err(ParseError::Oxc {
+            diagnostics: analyzer.diagnostics,
+        });
+    }
+
+    debug!("Collected decls: {:?}", analyzer.decls);
+
+    f(analyzer)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use insta::{assert_debug_snapshot, assert_snapshot};
+
+    use crate::{
+        parser::{
+            native_spec_parser::{try_parse_schema, try_parse_schema_file},
+            types::{ParseError, TypeAnnotation},
+        },
+        types::Schema,
+    };
+
+    /// Writes the given modules into a new temporary directory.
+    fn write_modules(name: &str, modules: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("craby-codegen-{}", name));
+        if dir.exists() {
+            fs::remove_dir_all(&dir).unwrap();
+        }
+
+        for (path, src) in modules {
+            let path = dir.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, src).unwrap();
+        }
+
+        dir
+    }
+
+    #[test]
+    fn test_common_spec() {
+        let src = "
+        import type { NativeModule, Signal } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface TestObject {
+            foo: string;
+            bar: number;
+            baz: boolean;
+            sub: SubObject | null;
+        }
+
+        export type SubObject = {
+            a: string | null;
+            b: number;
+            c: boolean;
+        };
+
+        export type MaybeNumber = number | null;
+
+        export enum MyEnum {
+            Foo = 'foo',
+            Bar = 'bar',
+            Baz = 'baz',
+        }
+
+        export enum SwitchState {
+            Off = 0,
+            On = 1,
+        }
+
+        export interface Spec extends NativeModule {
+            numericMethod(arg: number): number;
+            booleanMethod(arg: boolean): boolean;
+            stringMethod(arg: string): string;
+            objectMethod(arg: TestObject): TestObject;
+            arrayMethod(arg: number[]): number[];
+            enumMethod(arg0: MyEnum, arg1: SwitchState): string;
+            nullableMethod(arg: number | null): MaybeNumber;
+            promiseMethod(arg: number): Promise<number>;
+            onSignal: Signal;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
+
+        ";
+        let result = try_parse_schema(src).unwrap();
+
+        assert!(result.len() == 1);
+        assert_debug_snapshot!(result);
+    }
+
+    #[test]
+    fn test_spec_interface() {
+        let src = "
+        import type { NativeModule, Signal } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface Spec extends NativeModule {
+            myMethod(): void;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let schemas = try_parse_schema(src).unwrap();
+
+        assert!(schemas.len() == 1);
+        assert_debug_snapshot!(schemas);
+    }
+
+    #[test]
+    fn test_spec_import_without_type() {
+        let src = "
+        import { NativeModuleRegistry, NativeModule, Signal } from 'craby-modules';
+
+        export interface Spec extends NativeModule {
+            myMethod(): void;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let schemas = try_parse_schema(src).unwrap();
+
+        assert!(schemas.len() == 1);
+        assert_debug_snapshot!(schemas);
+    }
+
+    #[test]
+    fn test_spec_import_as_namespace() {
+        let src = "
+        import * as CrabyNativeModules from 'craby-modules';
+
+        export interface Spec extends CrabyNativeModules.NativeModule {
+            myMethod(): void;
+        }
+
+        export default CrabyNativeModules.NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let schemas = try_parse_schema(src).unwrap();
+
+        assert!(schemas.len() == 1);
+        assert_debug_snapshot!(schemas);
+    }
+
+    #[test]
+    fn test_spec_import_as_namespace_type() {
+        let src = "
+        import type * as CrabyNativeModules from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface Spec extends CrabyNativeModules.NativeModule {
+            myMethod(): void;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let schemas = try_parse_schema(src).unwrap();
+
+        assert!(schemas.len() == 1);
+        assert_debug_snapshot!(schemas);
+    }
+
+    #[test]
+    fn test_signals() {
+        let src = "
+        import type { NativeModule, Signal } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface Spec extends NativeModule {
+            onFoo: Signal;
+        }
+
+        export const Foo = NativeModuleRegistry.getEnforcing<Spec>('TestModule');
+        ";
+        let schemas = try_parse_schema(src).unwrap();
+
+        assert!(schemas.len() == 1);
+        assert!(schemas[0].signals.len() == 1);
+        assert_debug_snapshot!(schemas);
+    }
+
+    #[test]
+    fn test_ref_type() {
+        let src = "
+        import type { NativeModule, Signal } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        interface Foo {
+            bar: string;
+        }
+
+        export interface Spec extends NativeModule {
+            getFoo(): Promise<Foo>;
+        }
+
+        export const Foo = NativeModuleRegistry.getEnforcing<Spec>('TestModule');
+        ";
+        let schemas = try_parse_schema(src).unwrap();
+
+        assert!(schemas.len() == 1);
+        assert!(schemas[0].aliases.len() == 1);
+        assert_debug_snapshot!(schemas);
+    }
+
+    #[test]
+    fn test_array_types() {
+        let src = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        interface Point {
+            x: number;
+            y: number;
+        }
+
+        enum Direction {
+            Up = 'up',
+            Down = 'down',
+        }
+
+        export interface Spec extends NativeModule {
+            nested(arg: number[][]): Array<Array<string>>;
+            objects(arg: Point[]): Array<Point | null>;
+            nullables(arg: (number | null)[]): Direction[];
+        }
+
+        export const Foo = NativeModuleRegistry.getEnforcing<Spec>('TestModule');
+        ";
+        let schemas = try_parse_schema(src).unwrap();
+
+        assert!(schemas.len() == 1);
+        assert!(schemas[0].aliases.len() == 1);
+        assert!(schemas[0].enums.len() == 1);
+        assert_debug_snapshot!(schemas);
+    }
+
+    #[test]
+    fn test_map_types() {
+        let src = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        interface Point {
+            x: number;
+            y: number;
+        }
+
+        type Points = { [key: string]: Point };
+
+        export interface Spec extends NativeModule {
+            record(arg: Record<string, number>): Record<string, string | null>;
+            indexSignature(arg: { [key: string]: boolean }): Points;
+        }
+
+        export const Foo = NativeModuleRegistry.getEnforcing<Spec>('TestModule');
+        ";
+        let schemas = try_parse_schema(src).unwrap();
+
+        assert!(schemas.len() == 1);
+        assert!(schemas[0].aliases.len() == 1);
+        assert_debug_snapshot!(schemas);
+    }
+
+    #[test]
+    fn test_map_non_string_key() {
+        let src = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface Spec extends NativeModule {
+            record(arg: Record<number, number>): void;
+            indexSignature(arg: { [key: number]: boolean }): void;
+        }
+
+        export const Foo = NativeModuleRegistry.getEnforcing<Spec>('TestModule');
+        ";
+        let result = try_parse_schema(src);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_union_types() {
+        let src = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        type Circle = { kind: 'circle'; radius: number };
+        type Square = { kind: 'square'; size: number };
+        type Shape = Circle | Square;
+        type MaybeShape = Circle | Square | null;
+
+        export interface Spec extends NativeModule {
+            union(arg: Shape): MaybeShape;
+        }
+
+        export const Foo = NativeModuleRegistry.getEnforcing<Spec>('TestModule');
+        ";
+        let schemas = try_parse_schema(src).unwrap();
+
+        assert_debug_snapshot!(schemas);
+    }
+
+    #[test]
+    fn test_union_without_discriminant() {
+        let src = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        type Circle = { kind: 'circle'; radius: number };
+        type Square = { kind: 'circle'; size: number };
+        type Shape = Circle | Square;
+
+        export interface FooSpec extends NativeModule {
+            union(arg: Shape): void;
+        }
+
+        export interface BarSpec extends NativeModule {
+            bar(arg: Unknown): void;
+        }
+
+        export const Foo = NativeModuleRegistry.getEnforcing<FooSpec>('FooModule');
+        export const Bar = NativeModuleRegistry.getEnforcing<BarSpec>('BarModule');
+        ";
+
+        match try_parse_schema(src) {
+            Err(ParseError::Oxc { diagnostics }) => {
+                let diagnostic = diagnostics
+                    .iter()
+                    .find(|d| d.to_string().contains("discriminant property"))
+                    .expect("expected the discriminated union diagnostic");
+                let span = diagnostic.labels.as_ref().unwrap()[0].inner();
+
+                assert!(diagnostic.to_string().ends_with("(`Shape`)"));
+                assert_eq!(&src[span.offset()..span.offset() + span.len()], "Shape");
+                // The diagnostics of the other specs are kept
+                assert!(diagnostics
+                    .iter()
+                    .any(|d| d.to_string().contains("`Unknown`")));
+            }
+            _ => panic!("expected diagnostics"),
+        }
+    }
+
+    #[test]
+    fn test_literal_union_types() {
+        let src = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        type Mode = 'fast' | 'accurate' | 'low-power';
+        type Level = 0 | 1 | 2;
+        type MaybeMode = 'on' | 'off' | null;
+        type Mime = 'image/png' | 'a.b';
+
+        export interface Spec extends NativeModule {
+            literal(mode: Mode, level: Level): MaybeMode;
+            mime(mime: Mime): void;
+        }
+
+        export const Foo = NativeModuleRegistry.getEnforcing<Spec>('TestModule');
+        ";
+        let schemas = try_parse_schema(src).unwrap();
+
+        assert_debug_snapshot!(schemas);
+    }
+
+    #[test]
+    fn test_literal_union_mixed_members() {
+        let src = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        type Mode = 'fast' | 1;
+
+        export interface Spec extends NativeModule {
+            literal(mode: Mode): void;
+        }
+
+        export const Foo = NativeModuleRegistry.getEnforcing<Spec>('TestModule');
+        ";
+        let result = try_parse_schema(src);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_literal_union_duplicate_members() {
+        let src = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        type Mime = 'image/png' | 'image-png';
+
+        export interface Spec extends NativeModule {
+            mime(mime: Mime): void;
+        }
+
+        export const Foo = NativeModuleRegistry.getEnforcing<Spec>('TestModule');
+        ";
+
+        match try_parse_schema(src) {
+            Err(ParseError::Oxc { diagnostics }) => {
+                assert_eq!(
+                    diagnostics[0].to_string(),
+                    "Duplicate enum member (ImagePng)"
+                );
+            }
+            _ => panic!("expected diagnostics"),
+        }
+    }
+
+    #[test]
+    fn test_binary_types() {
+        let src = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface Spec extends NativeModule {
+            bigint(arg: bigint): bigint;
+            bigUint64(arg: BigUint64): BigUint64;
+            typedArray(arg0: Uint8Array, arg1: Int32Array): Float32Array;
+            wideTypedArray(arg0: Float64Array, arg1: BigInt64Array): BigUint64Array;
+        }
+
+        export const Foo = NativeModuleRegistry.getEnforcing<Spec>('TestModule');
+        ";
+        let schemas = try_parse_schema(src).unwrap();
+
+        assert_debug_snapshot!(schemas);
+    }
+
+    #[test]
+    fn test_callback_types() {
+        let src = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        type Progress = { loaded: number; total: number };
+
+        export interface Spec extends NativeModule {
+            download(url: string, onProgress: (progress: Progress) => void, onDone: () => void): Promise<void>;
+            subscribe(callback: (id: number, message?: string) => void): void;
+        }
+
+        export const Foo = NativeModuleRegistry.getEnforcing<Spec>('TestModule');
+        ";
+        let schemas = try_parse_schema(src).unwrap();
+
+        assert_debug_snapshot!(schemas);
+    }
+
+    #[test]
+    fn test_invalid_callback() {
+        let srcs = [
+            // Non-void return type
+            "subscribe(callback: (id: number) => number): void;",
+            // Optional callback
+            "subscribe(callback?: (id: number) => void): void;",
+            // Nested callback
+            "subscribe(callback: (done: () => void) => void): void;",
+            // Callback as a return type
+            "subscribe(): (id: number) => void;",
+        ];
+
+        for method in srcs {
+            let src = format!(
+                "
+                import type {{ NativeModule }} from 'craby-modules';
+                import {{ NativeModuleRegistry }} from 'craby-modules';
+
+                export interface Spec extends NativeModule {{
+                    {method}
+                }}
+
+                export const Foo = NativeModuleRegistry.getEnforcing<Spec>('TestModule');
+                "
+            );
+            let result = try_parse_schema(&src);
+
+            assert!(result.is_err(), "{method}");
+        }
+    }
+
+    #[test]
+    fn test_imported_types() {
+        let spec = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+        import type { User, Role as UserRole } from './types';
+
+        export interface Spec extends NativeModule {
+            getUser(role: UserRole): Promise<User | null>;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('UserModule');
+        ";
+        let types = "
+        import type { Id } from './common';
+
+        export type Role = 'admin' | 'guest';
+
+        export interface User {
+            id: Id;
+            role: Role;
+        }
+        ";
+        let common = "
+        export type Id = {
+            value: string;
+        };
+        ";
+        let dir = write_modules(
+            "imported-types",
+            &[
+                ("NativeUser.ts", spec),
+                ("types.ts", types),
+                ("common/index.ts", common),
+            ],
+        );
+        let schemas = try_parse_schema_file(&dir.join("NativeUser.ts"), spec).unwrap();
+
+        assert_debug_snapshot!(schemas);
+    }
+
+    #[test]
+    fn test_imported_types_error() {
+        let spec = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+        import type { User } from './types';
+
+        export interface Spec extends NativeModule {
+            getUser(): User;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('UserModule');
+        ";
+        let types = "
+        export interface User {
+            name: string;
+            createdAt: Date;
+        }
+        ";
+        let dir = write_modules(
+            "imported-types-error",
+            &[("NativeUser.ts", spec), ("types.ts", types)],
+        );
+        let result = try_parse_schema_file(&dir.join("NativeUser.ts"), spec);
+
+        // Diagnostics are reported with the source of the imported module
+        match result {
+            Err(ParseError::Import { path, src, .. }) => {
+                assert!(path.ends_with("types.ts"));
+                assert_eq!(src, types);
+            }
+            _ => panic!("expected import error"),
+        }
+    }
+
+    #[test]
+    fn test_unresolved_imports() {
+        let srcs = [
+            // Module not found
+            "import type { User } from './unknown';",
+            // Type not exported from the module
+            "import type { Unknown as User } from './types';",
+            // Circular import
+            "import type { User } from './circular';",
+        ];
+        let types = "
+        export type User = { name: string };
+        ";
+        let circular = "
+        import type { User as Base } from './NativeUser';
+
+        export type User = { base: Base };
+        ";
+
+        for src in srcs {
+            let spec = format!(
+                "
+                import type {{ NativeModule }} from 'craby-modules';
+                import {{ NativeModuleRegistry }} from 'craby-modules';
+                {src}
+
+                export type Local = {{ name: string }};
+
+                export interface Spec extends NativeModule {{
+                    getUser(): User;
+                }}
+
+                export default NativeModuleRegistry.getEnforcing<Spec>('UserModule');
+                "
+            );
+            let dir = write_modules(
+                "unresolved-imports",
+                &[
+                    ("NativeUser.ts", &spec),
+                    ("types.ts", types),
+                    ("circular.ts", circular),
+                ],
+            );
+            let result = try_parse_schema_file(&dir.join("NativeUser.ts"), &spec);
+
+            assert!(result.is_err());
+        }
+
+        // Relative imports are not resolved without the file path
+        let result = try_parse_schema(
+            "
+            import type { NativeModule } from 'craby-modules';
+            import { NativeModuleRegistry } from 'craby-modules';
+            import type { User } from './types';
+
+            export interface Spec extends NativeModule {
+                getUser(): User;
+            }
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('UserModule');
+            ",
+        );
+
+        assert!(result.is_err());
+    }
+
+    /// Returns the diagnostic messages of the spec that has the given method signatures.
+    fn diagnostic_messages(sigs: &str) -> Vec<String> {
+        let src = format!(
+            "
+            import type {{ NativeModule }} from 'craby-modules';
+            import {{ NativeModuleRegistry }} from 'craby-modules';
+            import type {{ Foo }} from 'some-package';
+
+            class Bar {{}}
+            declare interface Baz {{}}
+
+            export interface Spec extends NativeModule {{
+                {sigs}
+            }}
+
+            export default NativeModuleRegistry.getEnforcing<Spec>('TestModule');
+            "
+        );
+
+        match try_parse_schema(&src) {
+            Err(ParseError::Oxc { diagnostics }) => diagnostics
+                .iter()
+                .map(|diagnostic| diagnostic.to_string())
+                .collect(),
+            _ => panic!("expected diagnostics"),
+        }
+    }
+
+    #[test]
+    fn test_unresolved_global_type() {
+        assert_eq!(
+            diagnostic_messages("getDate(): Date;"),
+            ["Cannot find type `Date`"]
+        );
+    }
+
+    #[test]
+    fn test_unresolved_undeclared_type() {
+        assert_eq!(
+            diagnostic_messages("getUser(arg: Array<Unknown>): void;"),
+            ["Cannot find type `Unknown`"]
+        );
+    }
+
+    #[test]
+    fn test_unresolved_package_type() {
+        assert_eq!(
+            diagnostic_messages("getFoo(): Promise<Foo | null>;"),
+            ["Cannot resolve imported type `Foo`. Only types declared in relative modules (eg. `./types`) are supported"]
+        );
+    }
+
+    #[test]
+    fn test_unresolved_class_type() {
+        assert_eq!(
+            diagnostic_messages("getBar(callback: (bar: Bar) => void): void;"),
+            ["Class type is not supported: `Bar`"]
+        );
+    }
+
+    #[test]
+    fn test_unresolved_ambient_type() {
+        assert_eq!(
+            diagnostic_messages("getBaz(): Baz;"),
+            ["Unsupported type reference `Baz`"]
+        );
+    }
+
+    #[test]
+    fn test_unresolved_spec_type() {
+        assert_eq!(
+            diagnostic_messages("getSpec(): Spec;"),
+            ["Unsupported type reference `Spec`"]
+        );
+    }
+
+    #[test]
+    fn test_unresolved_multiple_types() {
+        // Every unresolved reference is reported at once
+        let messages = diagnostic_messages(
+            "
+            getDate(): Date;
+            getFoo(arg: Foo): Bar;
+            ",
+        );
+
+        assert_eq!(messages.len(), 3);
+    }
+
+    #[test]
+    fn test_unresolved_diagnostic_span() {
+        let src = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface Spec extends NativeModule {
+            getDate(): Date;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('TestModule');
+        ";
+
+        match try_parse_schema(src) {
+            Err(ParseError::Oxc { diagnostics }) => {
+                let span = diagnostics[0].labels.as_ref().unwrap()[0].inner();
+                assert_eq!(&src[span.offset()..span.offset() + span.len()], "Date");
+            }
+            _ => panic!("expected diagnostics"),
+        }
+    }
+
+    #[test]
+    fn test_recursive_type() {
+        let src = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        type Node = {
+            value: number;
+            next: Node | null;
+        };
+
+        export interface Spec extends NativeModule {
+            getNode(): Node;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('TestModule');
+        ";
+
+        match try_parse_schema(src) {
+            Err(ParseError::Oxc { diagnostics }) => {
+                assert_eq!(
+                    diagnostics[0].to_string(),
+                    "Recursive type is not supported (`Node`)"
+                );
+                let span = diagnostics[0].labels.as_ref().unwrap()[0].inner();
+                assert_eq!(&src[span.offset()..span.offset() + span.len()], "Node");
+                assert!(span.offset() > src.find("next:").unwrap());
+            }
+            _ => panic!("expected diagnostics"),
+        }
+    }
+
+    #[test]
+    fn test_recursive_extends() {
+        let cases = [
+            // Mutually recursive interfaces
+            (
+                "interface A extends B { x: number } interface B { y: A | null }",
+                "getA(): A;",
+                "A",
+            ),
+            // Redeclared property referencing the interface itself
+            (
+                "interface A { x: number } interface B extends A { x: B }",
+                "getB(): B;",
+                "B",
+            ),
+            // Base interface referencing the derived interface
+            (
+                "interface A { x: B | null } interface B extends A { y: number }",
+                "getB(arg: B): void;",
+                "B",
+            ),
+        ];
+
+        for (decls, sig, name) in cases {
+            let src = format!(
+                "
+                import type {{ NativeModule }} from 'craby-modules';
+                import {{ NativeModuleRegistry }} from 'craby-modules';
+
+                {decls}
+
+                export interface Spec extends NativeModule {{
+                    {sig}
+                }}
+
+                export default NativeModuleRegistry.getEnforcing<Spec>('TestModule');
+                "
+            );
+
+            match try_parse_schema(&src) {
+                Err(ParseError::Oxc { diagnostics }) => {
+                    let message = format!("Recursive type is not supported (`{name}`)");
+                    assert!(
+                        diagnostics.iter().any(|d| d.to_string() == message),
+                        "{decls}"
+                    );
+                }
+                _ => panic!("expected diagnostics: {decls}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_interface_extends() {
+        let src = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface User extends Named, Timestamped {
+            email: string | null;
+        }
+
+        interface Named extends Base {
+            name: string;
+        }
+
+        type Timestamped = {
+            id: string;
+            createdAt: number;
+        };
+
+        interface Base {
+            id: string;
+        }
+
+        export interface Spec extends NativeModule {
+            getUser(): User;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('UserModule');
+        ";
+        let schemas = try_parse_schema(src).unwrap();
+
+        assert_debug_snapshot!(schemas);
+    }
+
+    #[test]
+    fn test_interface_extends_hash() {
+        let src_1 = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        interface Base {
+            id: string;
+        }
+
+        interface User extends Base {
+            name: string;
+        }
+
+        export interface Spec extends NativeModule {
+            getUser(): User;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('UserModule');
+        ";
+        let src_2 = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        interface User {
+            id: string;
+            name: string;
+        }
+
+        export interface Spec extends NativeModule {
+            getUser(): User;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('UserModule');
+        ";
+        let hash_1 = Schema::to_hash(&try_parse_schema(src_1).unwrap());
+        let hash_2 = Schema::to_hash(&try_parse_schema(src_2).unwrap());
+
+        assert_eq!(hash_1, hash_2);
+    }
+
+    #[test]
+    fn test_invalid_interface_extends() {
+        let cases = [
+            // Conflicting property
+            (
+                "interface User extends Base { id: number; }",
+                "Conflicting property `id` in the inherited types",
+            ),
+            // Conflicting property between the base types
+            (
+                "interface Other { id: number; } interface User extends Base, Other {}",
+                "Conflicting property `id` in the inherited types",
+            ),
+            // Type arguments of the non-generic base type
+            (
+                "interface User extends Base<string> {}",
+                "Type `Base` is not generic",
+            ),
+            // Non-object base type
+            (
+                "enum Kind { A, B } interface User extends Kind {}",
+                "Interface can only extend object types",
+            ),
+            // Class base type
+            (
+                "class Model {} interface User extends Model {}",
+                "Class type is not supported: `Model`",
+            ),
+            // Circular inheritance
+            (
+                "interface User extends Other {} interface Other extends User {}",
+                "Circular inheritance is not supported",
+            ),
+        ];
+
+        for (decl, message) in cases {
+            let src = format!(
+                "
+                import type {{ NativeModule }} from 'craby-modules';
+                import {{ NativeModuleRegistry }} from 'craby-modules';
+
+                interface Base {{
+                    id: string;
+                }}
+
+                {decl}
+
+                export interface Spec extends NativeModule {{
+                    getUser(): User;
+                }}
+
+                export default NativeModuleRegistry.getEnforcing<Spec>('UserModule');
+                "
+            );
+
+            match try_parse_schema(&src) {
+                Err(ParseError::Oxc { diagnostics }) => {
+                    assert!(
+                        diagnostics.iter().any(|d| d.to_string() == message),
+                        "{decl}"
+                    );
+                }
+                _ => panic!("expected diagnostics: {decl}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_generic_alias() {
+        let src = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        type Page<T> = {
+            items: T[];
+            cursor: string | null;
+        };
+
+        type Entry<K, V> = {
+            key: K;
+            value?: V;
+        };
+
+        type Response<T> = {
+            page: Page<T>;
+        };
+
+        interface User {
+            name: string;
+        }
+
+        interface UserPage extends Page<User> {
+            total: number;
+        }
 
-    fn visit_ts_type_alias_declaration(&mut self, it: &TSTypeAliasDeclaration<'a>) {
-        if it.declare {
-            return;
+        export interface Spec extends NativeModule {
+            getUsers(): Promise<Page<User>>;
+            getUserPage(): UserPage;
+            getEntry(arg: Entry<string, number | null>): Response<number>;
         }
 
-        // Collect user defined type (type alias)
-        self.collect_alias_type(it);
+        export default NativeModuleRegistry.getEnforcing<Spec>('TestModule');
+        ";
+        let schemas = try_parse_schema(src).unwrap();
+
+        assert_debug_snapshot!(schemas);
     }
 
-    fn visit_ts_enum_declaration(&mut self, it: &TSEnumDeclaration<'a>) {
-        if it.declare {
-            return;
-        }
+    #[test]
+    fn test_recursive_generic_alias() {
+        let cases = [
+            (
+                "type Page<T> = { items: T[]; next: Page<T> | null };",
+                "getPage(): Page<number>;",
+                "PageNumber",
+            ),
+            // Type arguments growing at each instantiation
+            (
+                "type Tree<T> = { value: T; children: Tree<T[]>[] };",
+                "getTree(): Tree<string>;",
+                "TreeStringArray",
+            ),
+            // Mutually recursive generic types
+            (
+                "type Foo<T> = { bar: Bar<T> | null }; type Bar<T> = { foo: Foo<T> };",
+                "getFoo(arg: Foo<boolean>): void;",
+                "FooBoolean",
+            ),
+        ];
+
+        for (decl, sig, name) in cases {
+            let src = format!(
+                "
+                import type {{ NativeModule }} from 'craby-modules';
+                import {{ NativeModuleRegistry }} from 'craby-modules';
+
+                {decl}
+
+                export interface Spec extends NativeModule {{
+                    {sig}
+                }}
+
+                export default NativeModuleRegistry.getEnforcing<Spec>('TestModule');
+                "
+            );
 
-        // Collect user defined enum type
-        self.collect_enum_type(it);
+            match try_parse_schema(&src) {
+                Err(ParseError::Oxc { diagnostics }) => {
+                    assert_eq!(
+                        diagnostics[0].to_string(),
+                        format!("Recursive generic type is not supported (`{name}`)")
+                    );
+                    let span = diagnostics[0].labels.as_ref().unwrap()[0].inner();
+                    assert!(src[span.offset()..].starts_with(&name[..3]), "{decl}");
+                }
+                _ => panic!("expected diagnostics: {decl}"),
+            }
+        }
     }
 
-    fn visit_call_expression(&mut self, it: &CallExpression<'a>) {
-        // Collect module name from `NativeModuleRegistry.get()` or `NativeModuleRegistry.getEnforcing()`
-        self.collect_mod(it);
+    #[test]
+    fn test_invalid_generic_alias() {
+        let cases = [
+            // Missing type argument
+            ("type Page<T> = { items: T[] };", "getPage(): Page;"),
+            // Too many type arguments
+            (
+                "type Page<T> = { items: T[] };",
+                "getPage(): Page<number, string>;",
+            ),
+            // Type arguments of the non-generic type
+            (
+                "type Page = { items: number[] };",
+                "getPage(): Page<number>;",
+            ),
+            // Non-object generic type alias
+            ("type Maybe<T> = T | null;", "getPage(): Maybe<number>;"),
+            // Type parameter constraint
+            (
+                "type Page<T extends object> = { items: T[] };",
+                "getPage(): Page<number>;",
+            ),
+            // Type parameter default
+            (
+                "type Page<T = number> = { items: T[] };",
+                "getPage(): Page<number>;",
+            ),
+            // Unsupported type argument
+            (
+                "type Page<T> = { items: T[] };",
+                "getPage(): Page<Promise<number>>;",
+            ),
+            // Instantiated type name conflicts with the declared type
+            (
+                "type Page<T> = { items: T[] }; type PageNumber = { items: number[] };",
+                "getPage(): Page<number>; getOther(): PageNumber;",
+            ),
+        ];
+
+        for (decl, method) in cases {
+            let src = format!(
+                "
+                import type {{ NativeModule }} from 'craby-modules';
+                import {{ NativeModuleRegistry }} from 'craby-modules';
+
+                {decl}
+
+                export interface Spec extends NativeModule {{
+                    {method}
+                }}
+
+                export default NativeModuleRegistry.getEnforcing<Spec>('TestModule');
+                "
+            );
+            let result = try_parse_schema(&src);
+
+            assert!(result.is_err());
+        }
     }
-}
 
-pub fn try_parse_schema(src: &str) -> Result<Vec<Schema>, ParseError> {
-    let allocator = Allocator::default();
-    let source_type = SourceType::tsx();
-    let ret = Parser::new(&allocator, src, source_type).parse();
+    #[test]
+    fn test_conflicting_generic_instances() {
+        let src = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
 
-    if ret.panicked || !ret.errors.is_empty() {
-        return Err(ParseError::Oxc {
-            diagnostics: ret.errors,
-        });
-    }
+        type User = { name: string };
+        type UserArray = { users: User[] };
+        type Page<T> = { items: T[] };
 
-    let program = ret.program;
-    let ret = SemanticBuilder::new().build(&program);
+        export interface Spec extends NativeModule {
+            getUsers(): Page<User[]>;
+            getGroups(): Page<UserArray>;
+        }
 
-    if !ret.errors.is_empty() {
-        return Err(ParseError::Oxc {
-            diagnostics: ret.errors,
-        });
+        export default NativeModuleRegistry.getEnforcing<Spec>('TestModule');
+        ";
+
+        match try_parse_schema(src) {
+            Err(ParseError::Oxc { diagnostics }) => {
+                assert_eq!(diagnostics.len(), 1);
+                assert_eq!(
+                    diagnostics[0].to_string(),
+                    "Instantiated type `PageUserArray` conflicts with the other instantiation"
+                );
+                let span = diagnostics[0].labels.as_ref().unwrap()[0].inner();
+                assert_eq!(span.offset(), src.find("Page<UserArray>").unwrap());
+            }
+            _ => panic!("expected diagnostics"),
+        }
     }
 
-    let scoping = ret.semantic.into_scoping();
-    let mut analyzer = NativeModuleAnalyzer::new(&scoping);
+    #[test]
+    fn test_constants() {
+        let src = "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
 
-    analyzer.visit_program(&program);
+        export type Constants = {
+            version: string;
+            maxCount: number;
+        };
 
-    if !analyzer.diagnostics.is_empty() {
-        return Err(ParseError::Oxc {
-            diagnostics: analyzer.diagnostics,
-        });
-    }
+        export interface Spec extends NativeModule {
+            getConstants(): Constants;
+        }
 
-    debug!("Collected decls: {:?}", analyzer.decls);
+        export default NativeModuleRegistry.getEnforcing<Spec>('TestModule');
+        ";
+        let schemas = try_parse_schema(src).unwrap();
+        let method = &schemas[0].methods[0];
 
-    let schemas = analyzer.try_into_schema()?;
+        assert!(method.is_constants());
+        assert_eq!(method.ret_type.as_object().unwrap().name, "Constants");
+    }
 
-    Ok(schemas)
-}
+    #[test]
+    fn test_invalid_constants() {
+        // (method, labeled source)
+        let cases = [
+            // Parameters
+            (
+                "getConstants(arg: number): Constants;",
+                "getConstants(arg: number): Constants;",
+            ),
+            // Non-object type
+            ("getConstants(): number;", "getConstants(): number;"),
+            (
+                "getConstants(): Promise<Constants>;",
+                "getConstants(): Promise<Constants>;",
+            ),
+            (
+                "getConstants(): Constants | null;",
+                "getConstants(): Constants | null;",
+            ),
+            // Reported at the reference once resolved
+            ("getConstants(): Mode;", "Mode"),
+        ];
+
+        for (method, label) in cases {
+            let src = format!(
+                "
+                import type {{ NativeModule }} from 'craby-modules';
+                import {{ NativeModuleRegistry }} from 'craby-modules';
+
+                type Constants = {{ version: string }};
+
+                enum Mode {{ Foo = 0, Bar = 1 }}
+
+                export interface Spec extends NativeModule {{
+                    {method}
+                }}
+
+                export default NativeModuleRegistry.getEnforcing<Spec>('TestModule');
+                "
+            );
 
-#[cfg(test)]
-mod tests {
-    use insta::{assert_debug_snapshot, assert_snapshot};
+            match try_parse_schema(&src) {
+                Err(ParseError::Oxc { diagnostics }) => {
+                    assert_eq!(diagnostics.len(), 1, "{method}");
+                    assert_eq!(
+                        diagnostics[0].to_string(),
+                        "`getConstants` must have no parameters and return an object type (eg. `getConstants(): Constants`)",
+                        "{method}"
+                    );
 
-    use crate::{parser::native_spec_parser::try_parse_schema, types::Schema};
+                    let span = diagnostics[0].labels.as_ref().unwrap()[0].inner();
+                    assert_eq!(&src[span.offset()..span.offset() + span.len()], label);
+                }
+                _ => panic!("expected diagnostics: {method}"),
+            }
+        }
+    }
 
     #[test]
-    fn test_common_spec() {
+    fn test_jsdoc() {
         let src = "
         import type { NativeModule, Signal } from 'craby-modules';
         import { NativeModuleRegistry } from 'craby-modules';
 
-        export interface TestObject {
-            foo: string;
-            bar: number;
-            baz: boolean;
-            sub: SubObject | null;
-        }
-
-        export type SubObject = {
-            a: string | null;
-            b: number;
-            c: boolean;
-        };
-
-        export type MaybeNumber = number | null;
-
-        export enum MyEnum {
-            Foo = 'foo',
-            Bar = 'bar',
-            Baz = 'baz',
+        /** User information */
+        export interface User {
+            /**
+             * Display name
+             *
+             * Can be empty
+             */
+            name: string;
+            // Not a JSDoc comment
+            age: number;
         }
 
-        export enum SwitchState {
-            Off = 0,
-            On = 1,
+        enum Role {
+            /** Administrator */
+            Admin = 0,
+            Guest = 1,
         }
 
         export interface Spec extends NativeModule {
-            numericMethod(arg: number): number;
-            booleanMethod(arg: boolean): boolean;
-            stringMethod(arg: string): string;
-            objectMethod(arg: TestObject): TestObject;
-            arrayMethod(arg: number[]): number[];
-            enumMethod(arg0: MyEnum, arg1: SwitchState): string;
-            nullableMethod(arg: number | null): MaybeNumber;
-            promiseMethod(arg: number): Promise<number>;
-            onSignal: Signal;
+            /**
+             * Returns the user
+             *
+             * @param role The role of the user
+             * @returns The user
+             * @deprecated Use `getUserV2` instead
+             */
+            getUser(role: Role): User;
+            /** Emitted when the user is updated */
+            onUpdate: Signal;
         }
 
-        export default NativeModuleRegistry.getEnforcing<Spec>('CrabyTest');
-
+        export default NativeModuleRegistry.getEnforcing<Spec>('TestModule');
         ";
-        let result = try_parse_schema(src).unwrap();
+        let schemas = try_parse_schema(src).unwrap();
+        let schema = &schemas[0];
+        let user = schema.aliases[0].as_object().unwrap();
+        let role = schema.enums[0].as_enum().unwrap();
+
+        assert_eq!(user.doc.0.as_deref(), Some("User information"));
+        assert_eq!(
+            user.props[0].doc.0.as_deref(),
+            Some("Display name\n\nCan be empty")
+        );
+        assert_eq!(user.props[1].doc.0, None);
+        assert_eq!(role.doc.0, None);
+        assert_eq!(role.members[0].doc.0.as_deref(), Some("Administrator"));
+        assert_eq!(role.members[1].doc.0, None);
+        assert_eq!(
+            schema.methods[0].doc.0.as_deref(),
+            Some(
+                "Returns the user\n\n@param role The role of the user\n@returns The user\n@deprecated Use `getUserV2` instead"
+            )
+        );
+        assert_eq!(
+            schema.signals[0].doc.0.as_deref(),
+            Some("Emitted when the user is updated")
+        );
 
-        assert!(result.len() == 1);
-        assert_debug_snapshot!(result);
+        // Comment-only changes do not affect the hash except the `@deprecated` tag
+        let uncommented = src
+            .lines()
+            .filter(|line| {
+                let line = line.trim();
+                !line.starts_with("/*") && !line.starts_with('*') && !line.starts_with("//")
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let mut uncommented_schemas = try_parse_schema(&uncommented).unwrap();
+
+        assert_ne!(
+            Schema::to_hash(&schemas),
+            Schema::to_hash(&uncommented_schemas)
+        );
+        assert_eq!(uncommented_schemas[0].methods[0].doc.0, None);
+
+        uncommented_schemas[0].methods[0].deprecated = Some("Use `getUserV2` instead".to_string());
+
+        assert_eq!(
+            Schema::to_hash(&schemas),
+            Schema::to_hash(&uncommented_schemas)
+        );
     }
 
     #[test]
-    fn test_spec_interface() {
+    fn test_stream() {
         let src = "
-        import type { NativeModule, Signal } from 'craby-modules';
+        import type { NativeModule } from 'craby-modules';
         import { NativeModuleRegistry } from 'craby-modules';
 
+        export type Chunk = {
+            index: number;
+            data: ArrayBuffer;
+        };
+
         export interface Spec extends NativeModule {
-            myMethod(): void;
+            readChunks(path: string): AsyncIterable<Chunk>;
+            counter(): AsyncIterable<number | null>;
         }
 
-        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        export default NativeModuleRegistry.getEnforcing<Spec>('TestModule');
         ";
         let schemas = try_parse_schema(src).unwrap();
-
-        assert!(schemas.len() == 1);
-        assert_debug_snapshot!(schemas);
+        // Methods are sorted by name
+        let methods = &schemas[0].methods;
+
+        assert_eq!(methods[1].params.len(), 1);
+        assert_eq!(
+            methods[1]
+                .ret_type
+                .as_stream()
+                .unwrap()
+                .as_object()
+                .unwrap()
+                .name,
+            "Chunk"
+        );
+        assert_eq!(
+            methods[0].ret_type,
+            TypeAnnotation::Stream(Box::new(TypeAnnotation::Nullable(Box::new(
+                TypeAnnotation::Number
+            ))))
+        );
     }
 
     #[test]
-    fn test_spec_import_without_type() {
+    fn test_invalid_stream() {
+        let cases = [
+            // Not a return type
+            "method(arg: AsyncIterable<number>): void;",
+            "method(): Promise<AsyncIterable<number>>;",
+            "method(): AsyncIterable<number> | null;",
+            "method(): AsyncIterable<number>[];",
+            // Invalid item type
+            "method(): AsyncIterable<void>;",
+            "method(): AsyncIterable<Promise<number>>;",
+            "method(): AsyncIterable;",
+            // Reserved argument name
+            "method(stream: number): AsyncIterable<number>;",
+        ];
+
+        for method in cases {
+            let src = format!(
+                "
+                import type {{ NativeModule }} from 'craby-modules';
+                import {{ NativeModuleRegistry }} from 'craby-modules';
+
+                export interface Spec extends NativeModule {{
+                    {method}
+                }}
+
+                export default NativeModuleRegistry.getEnforcing<Spec>('TestModule');
+                "
+            );
+            let result = try_parse_schema(&src);
+
+            assert!(result.is_err());
+        }
+
+        // Object property
         let src = "
-        import { NativeModuleRegistry, NativeModule, Signal } from 'craby-modules';
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        type Chunks = { items: AsyncIterable<number> };
 
         export interface Spec extends NativeModule {
-            myMethod(): void;
+            method(): Chunks;
         }
 
-        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        export default NativeModuleRegistry.getEnforcing<Spec>('TestModule');
         ";
-        let schemas = try_parse_schema(src).unwrap();
 
-        assert!(schemas.len() == 1);
-        assert_debug_snapshot!(schemas);
+        match try_parse_schema(src) {
+            Err(ParseError::Oxc { diagnostics }) => {
+                let message = "AsyncIterable type is only supported as a method return type";
+                assert!(diagnostics.iter().any(|d| d.to_string() == message));
+            }
+            _ => panic!("expected diagnostics"),
+        }
     }
 
     #[test]
-    fn test_spec_import_as_namespace() {
+    fn test_abort_signal() {
         let src = "
-        import * as CrabyNativeModules from 'craby-modules';
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
 
-        export interface Spec extends CrabyNativeModules.NativeModule {
-            myMethod(): void;
+        export interface Spec extends NativeModule {
+            download(url: string, signal: AbortSignal): Promise<void>;
+            fetch(url: string, signal?: AbortSignal): Promise<string>;
         }
 
-        export default CrabyNativeModules.NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        export default NativeModuleRegistry.getEnforcing<Spec>('TestModule');
         ";
         let schemas = try_parse_schema(src).unwrap();
+        // Methods are sorted by name
+        let methods = &schemas[0].methods;
 
-        assert!(schemas.len() == 1);
-        assert_debug_snapshot!(schemas);
+        assert_eq!(
+            methods[0].params[1].type_annotation,
+            TypeAnnotation::AbortSignal
+        );
+        assert!(!methods[0].params[1].optional);
+        // The optional signal is not represented as a nullable type
+        assert_eq!(
+            methods[1].params[1].type_annotation,
+            TypeAnnotation::AbortSignal
+        );
+        assert!(methods[1].params[1].optional);
+        assert_eq!(methods[1].min_args_count(), 1);
     }
 
     #[test]
-    fn test_spec_import_as_namespace_type() {
+    fn test_invalid_abort_signal() {
+        let cases = [
+            // Not a Promise method
+            "method(signal: AbortSignal): void;",
+            "method(signal: AbortSignal): number;",
+            "method(signal: AbortSignal): AsyncIterable<number>;",
+            // Not a method parameter
+            "method(signal: AbortSignal | null): Promise<void>;",
+            "method(signals: AbortSignal[]): Promise<void>;",
+            "method(): Promise<AbortSignal>;",
+            // Multiple signals
+            "method(a: AbortSignal, b: AbortSignal): Promise<void>;",
+        ];
+
+        for method in cases {
+            let src = format!(
+                "
+                import type {{ NativeModule }} from 'craby-modules';
+                import {{ NativeModuleRegistry }} from 'craby-modules';
+
+                export interface Spec extends NativeModule {{
+                    {method}
+                }}
+
+                export default NativeModuleRegistry.getEnforcing<Spec>('TestModule');
+                "
+            );
+            let result = try_parse_schema(&src);
+
+            assert!(result.is_err());
+        }
+    }
+
+    #[test]
+    fn test_throws() {
         let src = "
-        import type * as CrabyNativeModules from 'craby-modules';
+        import type { NativeModule } from 'craby-modules';
         import { NativeModuleRegistry } from 'craby-modules';
 
-        export interface Spec extends CrabyNativeModules.NativeModule {
-            myMethod(): void;
+        export enum DownloadError {
+            NotFound = 'NOT_FOUND',
+            Timeout = 'TIMEOUT',
         }
 
-        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        type ReadError = 'EOF' | 'EACCES';
+
+        export interface Spec extends NativeModule {
+            /**
+             * @throws DownloadError
+             */
+            download(url: string): Promise<void>;
+            /**
+             * @throws {ReadError} When the file cannot be read
+             */
+            read(path: string): Promise<string>;
+            /**
+             * @throws ReadError
+             */
+            readSync(path: string): string;
+            write(path: string): Promise<void>;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('TestModule');
         ";
         let schemas = try_parse_schema(src).unwrap();
+        // Methods are sorted by name
+        let methods = &schemas[0].methods;
+
+        let download_error = methods[0].throws.as_ref().unwrap().as_enum().unwrap();
+        assert_eq!(download_error.name, "DownloadError");
+        assert_eq!(download_error.members.len(), 2);
+
+        let read_error = methods[1].throws.as_ref().unwrap().as_enum().unwrap();
+        assert_eq!(read_error.name, "ReadError");
+        assert_eq!(read_error.members.len(), 2);
+
+        // Synchronous methods
+        assert_eq!(methods[2].throws, methods[1].throws);
+        assert!(methods[3].throws.is_none());
+        // The error code types are generated even if they are not referenced by the signatures
+        assert_eq!(schemas[0].enums.len(), 2);
+    }
 
-        assert!(schemas.len() == 1);
-        assert_debug_snapshot!(schemas);
+    #[test]
+    fn test_invalid_throws() {
+        let cases = [
+            // AsyncIterable method
+            "/** @throws StringError */ method(): AsyncIterable<number>;",
+            // Not a string enum
+            "/** @throws NumericError */ method(): Promise<void>;",
+            "/** @throws ObjectError */ method(): Promise<void>;",
+            // Unknown type
+            "/** @throws UnknownError */ method(): Promise<void>;",
+        ];
+
+        for method in cases {
+            let src = format!(
+                "
+                import type {{ NativeModule }} from 'craby-modules';
+                import {{ NativeModuleRegistry }} from 'craby-modules';
+
+                enum StringError {{
+                    Foo = 'FOO',
+                }}
+
+                enum NumericError {{
+                    Foo = 0,
+                }}
+
+                type ObjectError = {{
+                    code: string;
+                }};
+
+                export interface Spec extends NativeModule {{
+                    {method}
+                }}
+
+                export default NativeModuleRegistry.getEnforcing<Spec>('TestModule');
+                "
+            );
+            let result = try_parse_schema(&src);
+
+            assert!(result.is_err());
+        }
     }
 
     #[test]
-    fn test_signals() {
+    fn test_signal_policies() {
         let src = "
         import type { NativeModule, Signal } from 'craby-modules';
         import { NativeModuleRegistry } from 'craby-modules';
 
         export interface Spec extends NativeModule {
-            onFoo: Signal;
+            /** @buffer 8 */
+            onMessage: Signal<string>;
+            /**
+             * Emitted while downloading
+             *
+             * @throttle 16ms
+             * @latestOnly
+             */
+            onProgress: Signal<number>;
+            onReady: Signal;
+            /** @throttle 1s */
+            onTick: Signal;
         }
 
-        export const Foo = NativeModuleRegistry.getEnforcing<Spec>('TestModule');
+        export default NativeModuleRegistry.getEnforcing<Spec>('TestModule');
         ";
         let schemas = try_parse_schema(src).unwrap();
+        let signals = &schemas[0].signals;
+
+        assert_eq!(signals[0].buffer, Some(8));
+        assert_eq!(signals[0].throttle, None);
+        assert_eq!(signals[1].throttle, Some(16));
+        assert!(signals[1].latest_only);
+        assert_eq!(signals[2].throttle, None);
+        assert!(!signals[2].latest_only);
+        assert_eq!(signals[2].buffer, None);
+        assert_eq!(signals[3].throttle, Some(1000));
+    }
 
-        assert!(schemas.len() == 1);
-        assert!(schemas[0].signals.len() == 1);
-        assert_debug_snapshot!(schemas);
+    #[test]
+    fn test_invalid_signal_policies() {
+        let cases = [
+            // Missing or invalid interval
+            "/** @throttle */ onFoo: Signal;",
+            "/** @throttle 16 */ onFoo: Signal;",
+            "/** @throttle 0ms */ onFoo: Signal;",
+            "/** @throttle fast */ onFoo: Signal;",
+            // Missing or invalid size
+            "/** @buffer */ onFoo: Signal;",
+            "/** @buffer 0 */ onFoo: Signal;",
+            "/** @buffer -1 */ onFoo: Signal;",
+        ];
+
+        for signal in cases {
+            let src = format!(
+                "
+                import type {{ NativeModule, Signal }} from 'craby-modules';
+                import {{ NativeModuleRegistry }} from 'craby-modules';
+
+                export interface Spec extends NativeModule {{
+                    {signal}
+                }}
+
+                export default NativeModuleRegistry.getEnforcing<Spec>('TestModule');
+                "
+            );
+            let result = try_parse_schema(&src);
+
+            assert!(result.is_err());
+        }
     }
 
     #[test]
-    fn test_ref_type() {
+    fn test_serial() {
         let src = "
-        import type { NativeModule, Signal } from 'craby-modules';
+        import type { NativeModule } from 'craby-modules';
         import { NativeModuleRegistry } from 'craby-modules';
 
-        interface Foo {
-            bar: string;
-        }
-
         export interface Spec extends NativeModule {
-            getFoo(): Promise<Foo>;
+            /** @serial */
+            save(value: string): Promise<void>;
+            /**
+             * Loads the stored value
+             *
+             * @serial storage
+             */
+            load(): Promise<string>;
+            /** @serial storage */
+            watch(): AsyncIterable<string>;
+            fetch(): Promise<string>;
         }
 
-        export const Foo = NativeModuleRegistry.getEnforcing<Spec>('TestModule');
+        export default NativeModuleRegistry.getEnforcing<Spec>('TestModule');
         ";
         let schemas = try_parse_schema(src).unwrap();
+        let serial_of = |name: &str| {
+            schemas[0]
+                .methods
+                .iter()
+                .find(|method| method.name == name)
+                .and_then(|method| method.serial.as_deref())
+        };
 
-        assert!(schemas.len() == 1);
-        assert!(schemas[0].aliases.len() == 1);
-        assert_debug_snapshot!(schemas);
+        assert_eq!(serial_of("save"), Some("save"));
+        assert_eq!(serial_of("load"), Some("storage"));
+        assert_eq!(serial_of("watch"), Some("storage"));
+        assert_eq!(serial_of("fetch"), None);
+    }
+
+    #[test]
+    fn test_invalid_serial() {
+        let cases = [
+            // Synchronous methods
+            "/** @serial */ foo(): void;",
+            "/** @serial storage */ foo(): number;",
+            // Invalid queue name
+            "/** @serial my-queue */ foo(): Promise<void>;",
+            "/** @serial 'storage' */ foo(): Promise<void>;",
+        ];
+
+        for method in cases {
+            let src = format!(
+                "
+                import type {{ NativeModule }} from 'craby-modules';
+                import {{ NativeModuleRegistry }} from 'craby-modules';
+
+                export interface Spec extends NativeModule {{
+                    {method}
+                }}
+
+                export default NativeModuleRegistry.getEnforcing<Spec>('TestModule');
+                "
+            );
+            let result = try_parse_schema(&src);
+
+            assert!(result.is_err());
+        }
     }
 
     #[test]
@@ -1297,6 +4236,27 @@ mod tests {
             myMethod(arg?: number): void;
         }
 
+        export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+        ";
+        let schemas = try_parse_schema(src).unwrap();
+        let method = &schemas[0].methods[0];
+
+        assert!(method.params[0].optional);
+        assert!(method.params[0].type_annotation.is_nullable());
+        assert_eq!(method.min_args_count(), 0);
+        assert_debug_snapshot!(schemas);
+    }
+
+    #[test]
+    fn test_optional_non_trailing_param() {
+        let src: &'static str = "
+        import type { NativeModule, Signal } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface Spec extends NativeModule {
+            myMethod(a?: number, b: number): void;
+        }
+
         export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
         ";
         let result = try_parse_schema(src);
@@ -1311,7 +4271,8 @@ mod tests {
         import { NativeModuleRegistry } from 'craby-modules';
 
         interface Foo {
-            bar?: number
+            bar?: number;
+            baz?: string | null;
         }
 
         export interface Spec extends NativeModule {
@@ -1320,9 +4281,12 @@ mod tests {
 
         export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
         ";
-        let result = try_parse_schema(src);
+        let schemas = try_parse_schema(src).unwrap();
+        let props = &schemas[0].aliases[0].as_object().unwrap().props;
 
-        assert!(result.is_err());
+        assert!(props.iter().all(|prop| prop.optional));
+        assert!(props.iter().all(|prop| prop.type_annotation.is_nullable()));
+        assert_debug_snapshot!(schemas);
     }
 
     #[test]
@@ -1341,9 +4305,10 @@ mod tests {
 
         export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
         ";
-        let result = try_parse_schema(src);
+        let schemas = try_parse_schema(src).unwrap();
 
-        assert!(result.is_err());
+        assert!(schemas[0].aliases[0].as_object().unwrap().props[0].optional);
+        assert_debug_snapshot!(schemas);
     }
 
     #[test]
@@ -1399,6 +4364,32 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_reserved_signal_method_name() {
+        for method_name in [
+            "emitter",
+            "hasListeners",
+            "onListenerAdded",
+            "onListenerRemoved",
+        ] {
+            let src = format!(
+                "
+                import type {{ NativeModule }} from 'craby-modules';
+                import {{ NativeModuleRegistry }} from 'craby-modules';
+
+                export interface Spec extends NativeModule {{
+                    {method_name}(): void;
+                }}
+
+                export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+                "
+            );
+            let result = try_parse_schema(&src);
+
+            assert!(result.is_err());
+        }
+    }
+
     #[test]
     fn test_optional_method() {
         let src: &'static str = "
@@ -1480,4 +4471,32 @@ mod tests {
         assert_ne!(hash_1, hash_3);
         assert_snapshot!([hash_1, hash_2, hash_3].join("\n"));
     }
+
+    #[test]
+    fn test_deprecated_hash() {
+        let src = |doc: &str| {
+            format!(
+                "
+                import type {{ NativeModule }} from 'craby-modules';
+                import {{ NativeModuleRegistry }} from 'craby-modules';
+
+                export interface Spec extends NativeModule {{
+                    {doc}
+                    foo(): Promise<void>;
+                }}
+
+                export default NativeModuleRegistry.getEnforcing<Spec>('MyModule');
+                "
+            )
+        };
+        let hash = |doc: &str| Schema::to_hash(&try_parse_schema(&src(doc)).unwrap());
+
+        // The `@deprecated` tag changes the generated code (eg. the default body of the trait method)
+        assert_ne!(hash(""), hash("/** @deprecated */"));
+        assert_ne!(
+            hash("/** @deprecated */"),
+            hash("/** @deprecated Use `bar` */")
+        );
+        assert_eq!(hash(""), hash("/** Foo */"));
+    }
 }
@@ -1,15 +1,40 @@
-use std::hash::{DefaultHasher, Hash, Hasher};
+use std::{
+    cmp::Ordering,
+    hash::{DefaultHasher, Hash, Hasher},
+    path::PathBuf,
+};
 
-use oxc::{diagnostics::OxcDiagnostic, semantic::ReferenceId};
+use oxc::{
+    diagnostics::OxcDiagnostic,
+    semantic::{ReferenceId, SymbolId},
+    span::Span,
+};
 use serde::Serialize;
 use thiserror::Error;
 
+use crate::constants::specs::*;
+
 #[derive(Error, Debug)]
 pub enum ParseError {
     #[error("General error")]
     General(#[from] anyhow::Error),
     #[error("Oxc error")]
     Oxc { diagnostics: Vec<OxcDiagnostic> },
+    /// Diagnostics of the module imported from the spec file (eg. `./types.ts`)
+    #[error("Oxc error in imported module")]
+    Import {
+        path: PathBuf,
+        src: String,
+        diagnostics: Vec<OxcDiagnostic>,
+    },
+}
+
+impl From<OxcDiagnostic> for ParseError {
+    fn from(diagnostic: OxcDiagnostic) -> Self {
+        ParseError::Oxc {
+            diagnostics: vec![diagnostic],
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -27,12 +52,41 @@ pub struct Method {
     pub name: String,
     pub params: Vec<Param>,
     pub ret_type: TypeAnnotation,
+    /// Error code type of the `@throws` tag (`TypeAnnotation::Enum` of the string members)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub throws: Option<TypeAnnotation>,
+    /// Serial queue name of the `@serial` tag (the method name if no name is given)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub serial: Option<String>,
+    /// Reason of the `@deprecated` tag (empty if no reason is given)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<String>,
+    #[serde(skip)]
+    pub doc: JsDoc,
+}
+
+impl Method {
+    /// Returns the number of required (non-optional) parameters.
+    pub fn min_args_count(&self) -> usize {
+        self.params.iter().filter(|param| !param.optional).count()
+    }
+
+    /// Returns `true` if the method provides the module constants (eg. `getConstants(): Constants`).
+    pub fn is_constants(&self) -> bool {
+        self.name == RESERVED_METHOD_NAME_CONSTANTS
+    }
 }
 
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Serialize)]
 pub struct Param {
     pub name: String,
     pub type_annotation: TypeAnnotation,
+    /// Optional parameter (eg. `arg?: T`)
+    ///
+    /// The `type_annotation` is always `TypeAnnotation::Nullable` for optional parameters,
+    /// except `TypeAnnotation::AbortSignal` (the omitted signal is never cancelled).
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub optional: bool,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Serialize, Hash)]
@@ -49,6 +103,24 @@ pub enum TypeAnnotation {
     Nullable(Box<TypeAnnotation>),
     // Reference to `TypeAnnotation::Object` or `TypeAnnotation::Enum` or Alias types (eg. `Promise`)
     Ref(RefTypeAnnotation),
+    // String keyed map (eg. `Record<string, T>`, `{ [key: string]: T }`)
+    Map(Box<TypeAnnotation>),
+    // String literal type (eg. `'foo'`)
+    StringLiteral(String),
+    // Discriminated union of object types (eg. `{ kind: 'foo' } | { kind: 'bar' }`)
+    Union(UnionTypeAnnotation),
+    // 64-bit signed integer (eg. `bigint`)
+    BigInt,
+    // 64-bit unsigned integer (eg. `BigUint64`)
+    BigUint64,
+    // Typed array (eg. `Uint8Array`, `Float32Array`)
+    TypedArray(TypedArrayType),
+    // Callback function (eg. `(progress: number) => void`)
+    Function(FunctionTypeAnnotation),
+    // Async iterable of the method return type (eg. `AsyncIterable<number>`)
+    Stream(Box<TypeAnnotation>),
+    // Cancellation token of the Promise method parameter (eg. `signal: AbortSignal`)
+    AbortSignal,
 }
 
 impl TypeAnnotation {
@@ -72,6 +144,31 @@ impl TypeAnnotation {
         }
     }
 
+    pub fn as_union(&self) -> Option<&UnionTypeAnnotation> {
+        match self {
+            TypeAnnotation::Union(union_type) => Some(union_type),
+            _ => None,
+        }
+    }
+
+    pub fn as_function(&self) -> Option<&FunctionTypeAnnotation> {
+        match self {
+            TypeAnnotation::Function(function_type) => Some(function_type),
+            _ => None,
+        }
+    }
+
+    pub fn as_stream(&self) -> Option<&TypeAnnotation> {
+        match self {
+            TypeAnnotation::Stream(item_type) => Some(item_type),
+            _ => None,
+        }
+    }
+
+    pub fn is_abort_signal(&self) -> bool {
+        matches!(self, TypeAnnotation::AbortSignal)
+    }
+
     pub fn is_nullable(&self) -> bool {
         matches!(self, TypeAnnotation::Nullable(..))
     }
@@ -81,24 +178,37 @@ impl TypeAnnotation {
 pub struct ObjectTypeAnnotation {
     pub name: String,
     pub props: Vec<Prop>,
+    #[serde(skip)]
+    pub doc: JsDoc,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Serialize, Hash)]
 pub struct Prop {
     pub name: String,
     pub type_annotation: TypeAnnotation,
+    /// Optional property (eg. `foo?: T`)
+    ///
+    /// The `type_annotation` is always `TypeAnnotation::Nullable` for optional properties.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub optional: bool,
+    #[serde(skip)]
+    pub doc: JsDoc,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Serialize, Hash)]
 pub struct EnumTypeAnnotation {
     pub name: String,
     pub members: Vec<EnumMember>,
+    #[serde(skip)]
+    pub doc: JsDoc,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Serialize, Hash)]
 pub struct EnumMember {
     pub name: String,
     pub value: EnumMemberValue,
+    #[serde(skip)]
+    pub doc: JsDoc,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Serialize, Hash)]
@@ -107,17 +217,315 @@ pub enum EnumMemberValue {
     Number(usize),
 }
 
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Serialize, Hash)]
+pub struct UnionTypeAnnotation {
+    pub name: String,
+    /// The property name that discriminates the variants (eg. `kind`)
+    ///
+    /// Empty until the variants are resolved. Remains empty if there is no valid discriminant.
+    pub discriminant: String,
+    /// `TypeAnnotation::Object` (or `TypeAnnotation::Ref` before resolved)
+    pub variants: Vec<TypeAnnotation>,
+}
+
+impl UnionTypeAnnotation {
+    /// Finds the property that every variant has with a distinct string literal type.
+    pub fn find_discriminant(&self) -> Option<String> {
+        let first = self.variants.first()?.as_object()?;
+
+        first
+            .props
+            .iter()
+            .filter(|prop| matches!(prop.type_annotation, TypeAnnotation::StringLiteral(..)))
+            .find(|prop| {
+                let mut tags = self
+                    .variants
+                    .iter()
+                    .map(|variant| Self::tag_of(variant, &prop.name))
+                    .collect::<Option<Vec<_>>>()
+                    .unwrap_or_default();
+                let len = tags.len();
+
+                tags.sort();
+                tags.dedup();
+                len == self.variants.len() && tags.len() == len
+            })
+            .map(|prop| prop.name.clone())
+    }
+
+    /// Returns the discriminant value of the variant (eg. `foo` of `{ kind: 'foo' }`).
+    pub fn tag(&self, variant: &TypeAnnotation) -> Option<String> {
+        Self::tag_of(variant, &self.discriminant).map(|tag| tag.to_string())
+    }
+
+    fn tag_of<'a>(variant: &'a TypeAnnotation, discriminant: &str) -> Option<&'a str> {
+        variant
+            .as_object()?
+            .props
+            .iter()
+            .find(|prop| prop.name == discriminant)
+            .and_then(|prop| match &prop.type_annotation {
+                TypeAnnotation::StringLiteral(value) => Some(value.as_str()),
+                _ => None,
+            })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Serialize, Hash)]
+pub enum TypedArrayType {
+    Int8,
+    Uint8,
+    Int16,
+    Uint16,
+    Int32,
+    Uint32,
+    Float32,
+    Float64,
+    BigInt64,
+    BigUint64,
+}
+
+impl TypedArrayType {
+    pub const ALL: [TypedArrayType; 10] = [
+        TypedArrayType::Int8,
+        TypedArrayType::Uint8,
+        TypedArrayType::Int16,
+        TypedArrayType::Uint16,
+        TypedArrayType::Int32,
+        TypedArrayType::Uint32,
+        TypedArrayType::Float32,
+        TypedArrayType::Float64,
+        TypedArrayType::BigInt64,
+        TypedArrayType::BigUint64,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|typed_array| typed_array.name() == name)
+    }
+
+    /// Returns the JavaScript constructor name (eg. `Uint8Array`).
+    pub fn name(&self) -> &'static str {
+        match self {
+            TypedArrayType::Int8 => RESERVED_TYPE_INT8_ARRAY,
+            TypedArrayType::Uint8 => RESERVED_TYPE_UINT8_ARRAY,
+            TypedArrayType::Int16 => RESERVED_TYPE_INT16_ARRAY,
+            TypedArrayType::Uint16 => RESERVED_TYPE_UINT16_ARRAY,
+            TypedArrayType::Int32 => RESERVED_TYPE_INT32_ARRAY,
+            TypedArrayType::Uint32 => RESERVED_TYPE_UINT32_ARRAY,
+            TypedArrayType::Float32 => RESERVED_TYPE_FLOAT32_ARRAY,
+            TypedArrayType::Float64 => RESERVED_TYPE_FLOAT64_ARRAY,
+            TypedArrayType::BigInt64 => RESERVED_TYPE_BIG_INT64_ARRAY,
+            TypedArrayType::BigUint64 => RESERVED_TYPE_BIG_UINT64_ARRAY,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Serialize, Hash)]
+pub struct FunctionTypeAnnotation {
+    /// Parameter types of the function
+    ///
+    /// The function always returns `void` since it is invoked asynchronously on the JS thread.
+    pub params: Vec<TypeAnnotation>,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Serialize, Hash)]
 pub struct RefTypeAnnotation {
     #[serde(skip)]
     pub ref_id: ReferenceId,
     pub name: String,
+    #[serde(skip)]
+    pub span: Span,
+    /// Type arguments of the generic type (eg. `User` of `Page<User>`)
+    #[serde(skip)]
+    pub type_args: Vec<TypeAnnotation>,
+}
+
+/// Generic type alias (eg. `type Page<T> = { items: T[] }`)
+///
+/// Instantiated into the object type at each use site (eg. `Page<User>` to `PageUser`).
+#[derive(Debug)]
+pub struct GenericAlias {
+    pub name: String,
+    /// Symbol IDs of the type parameters
+    pub params: Vec<SymbolId>,
+    /// Properties that may refer to the type parameters
+    pub props: Vec<Prop>,
+    pub span: Span,
+    pub doc: JsDoc,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Serialize)]
 pub struct Signal {
     pub name: String,
     pub payload_type: Option<TypeAnnotation>,
+    /// Minimum interval in milliseconds between the deliveries (`@throttle 16ms`)
+    ///
+    /// Only the latest emission of the interval is delivered.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub throttle: Option<u64>,
+    /// Whether only the latest of the pending emissions is delivered (`@latestOnly`)
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub latest_only: bool,
+    /// Number of emissions kept until the first listener subscribes (`@buffer 8`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub buffer: Option<usize>,
+    #[serde(skip)]
+    pub doc: JsDoc,
+}
+
+/// Leading JSDoc comment of the declaration (eg. `/** Returns the user */`)
+///
+/// Always equal to each other and not hashed,
+/// so comment-only changes affect neither the type identity nor the schema hash.
+#[derive(Debug, Clone, Default)]
+pub struct JsDoc(pub Option<String>);
+
+impl JsDoc {
+    /// Converts the JSDoc into the comment lines with the given prefix (eg. `///`, `//`).
+    ///
+    /// Returns an empty string if there is no JSDoc, otherwise each line ends with a newline.
+    ///
+    /// ```rust,ignore
+    /// /// Returns the user
+    /// ///
+    /// /// @param id The user ID
+    /// ```
+    pub fn to_comment(&self, prefix: &str) -> String {
+        match &self.0 {
+            Some(doc) => doc
+                .lines()
+                .map(|line| {
+                    if line.is_empty() {
+                        format!("{prefix}\n")
+                    } else {
+                        format!("{prefix} {line}\n")
+                    }
+                })
+                .collect(),
+            None => String::new(),
+        }
+    }
+}
+
+impl JsDoc {
+    /// Returns the reason of the `@deprecated` tag (empty if no reason is given).
+    ///
+    /// The reason continues until the blank line or the next tag.
+    ///
+    /// ```ts
+    /// /**
+    ///  * @deprecated Use `bar` instead
+    ///  */
+    /// ```
+    pub fn deprecated(&self) -> Option<String> {
+        let doc = self.0.as_ref()?;
+        let mut lines = doc.lines();
+        let first = lines.find_map(|line| {
+            line.strip_prefix("@deprecated")
+                .filter(|rest| rest.is_empty() || rest.starts_with(char::is_whitespace))
+        })?;
+
+        let reason = std::iter::once(first)
+            .chain(lines.take_while(|line| !line.trim().is_empty() && !line.starts_with('@')))
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        Some(reason)
+    }
+
+    /// Returns the type name of the `@throws` tag.
+    ///
+    /// The JSDoc type expression (eg. `{DownloadError}`) is also supported.
+    ///
+    /// ```ts
+    /// /**
+    ///  * @throws DownloadError
+    ///  */
+    /// ```
+    pub fn throws(&self) -> Option<String> {
+        let doc = self.0.as_ref()?;
+        let rest = doc.lines().find_map(|line| {
+            line.strip_prefix("@throws")
+                .filter(|rest| rest.is_empty() || rest.starts_with(char::is_whitespace))
+        })?;
+
+        rest.split_whitespace().next().map(|name| {
+            name.trim_start_matches('{')
+                .trim_end_matches('}')
+                .to_string()
+        })
+    }
+
+    /// Returns the interval of the `@throttle` tag (eg. `16ms`).
+    ///
+    /// ```ts
+    /// /**
+    ///  * @throttle 16ms
+    ///  */
+    /// ```
+    pub fn throttle(&self) -> Option<&str> {
+        self.tag("@throttle")
+    }
+
+    /// Returns `true` if the `@latestOnly` tag exists.
+    pub fn latest_only(&self) -> bool {
+        self.tag("@latestOnly").is_some()
+    }
+
+    /// Returns the size of the `@buffer` tag (eg. `8`).
+    pub fn buffer(&self) -> Option<&str> {
+        self.tag("@buffer")
+    }
+
+    /// Returns the queue name of the `@serial` tag (empty if no name is given).
+    ///
+    /// ```ts
+    /// /**
+    ///  * @serial storage
+    ///  */
+    /// ```
+    pub fn serial(&self) -> Option<&str> {
+        self.tag("@serial")
+    }
+
+    /// Returns the first word after the tag (empty if no value is given).
+    fn tag(&self, name: &str) -> Option<&str> {
+        let doc = self.0.as_ref()?;
+        let rest = doc.lines().find_map(|line| {
+            line.strip_prefix(name)
+                .filter(|rest| rest.is_empty() || rest.starts_with(char::is_whitespace))
+        })?;
+
+        Some(rest.split_whitespace().next().unwrap_or_default())
+    }
+}
+
+impl PartialEq for JsDoc {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for JsDoc {}
+
+impl PartialOrd for JsDoc {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for JsDoc {
+    fn cmp(&self, _: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl Hash for JsDoc {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
 }
 
 #[cfg(test)]
@@ -131,7 +539,10 @@ mod tests {
             props: vec![Prop {
                 name: "prop".to_string(),
                 type_annotation: TypeAnnotation::String,
+                optional: false,
+                doc: JsDoc::default(),
             }],
+            doc: JsDoc::default(),
         });
 
         let t2 = TypeAnnotation::Object(ObjectTypeAnnotation {
@@ -139,7 +550,10 @@ mod tests {
             props: vec![Prop {
                 name: "prop".to_string(),
                 type_annotation: TypeAnnotation::String,
+                optional: false,
+                doc: JsDoc::default(),
             }],
+            doc: JsDoc::default(),
         });
 
         let t3 = TypeAnnotation::Object(ObjectTypeAnnotation {
@@ -148,15 +562,42 @@ mod tests {
                 Prop {
                     name: "prop".to_string(),
                     type_annotation: TypeAnnotation::String,
+                    optional: false,
+                    doc: JsDoc::default(),
                 },
                 Prop {
                     name: "prop2".to_string(),
                     type_annotation: TypeAnnotation::String,
+                    optional: false,
+                    doc: JsDoc::default(),
                 },
             ],
+            doc: JsDoc::default(),
         });
 
+        // Comment-only changes do not affect the type identity
+        let mut t4 = t1.clone();
+        if let TypeAnnotation::Object(obj) = &mut t4 {
+            obj.doc = JsDoc(Some("Object".to_string()));
+            obj.props[0].doc = JsDoc(Some("Property".to_string()));
+        }
+
         assert_eq!(t1.to_id(), t2.to_id());
         assert_ne!(t1.to_id(), t3.to_id());
+        assert_eq!(t1.to_id(), t4.to_id());
+    }
+
+    #[test]
+    fn test_deprecated() {
+        let doc = |doc: &str| JsDoc(Some(doc.to_string()));
+
+        assert_eq!(doc("Foo").deprecated(), None);
+        assert_eq!(doc("@deprecatedFoo").deprecated(), None);
+        assert_eq!(doc("@deprecated").deprecated(), Some(String::new()));
+        assert_eq!(
+            doc("Foo\n\n@deprecated Use `bar`\ninstead\n@returns Foo").deprecated(),
+            Some("Use `bar` instead".to_string())
+        );
+        assert_eq!(JsDoc::default().deprecated(), None);
     }
 }
@@ -7,11 +7,14 @@ use template::{cxx_arg_ref, cxx_arg_var};
 
 use crate::{
     common::IntoCode,
-    constants::specs::RESERVED_ARG_NAME_MODULE,
-    parser::types::{EnumTypeAnnotation, Method, ObjectTypeAnnotation, TypeAnnotation},
+    constants::specs::{RESERVED_ARG_NAME_MODULE, RESERVED_ARG_NAME_STREAM},
+    parser::types::{
+        EnumTypeAnnotation, FunctionTypeAnnotation, Method, ObjectTypeAnnotation, TypeAnnotation,
+        TypedArrayType,
+    },
     platform::cxx::template::CxxBridgingTemplate,
     types::{CxxModuleName, CxxNamespace, Schema},
-    utils::{calc_deps_order, indent_str},
+    utils::{calc_deps_order, cxx_string_literal, indent_str},
 };
 
 #[derive(Debug)]
@@ -58,19 +61,34 @@ impl TypeAnnotation {
     /// rust::Str                     // String (arguments)
     /// rust::String                  // String
     /// rust::Vec<double>             // Array<Number>
+    /// int64_t                       // BigInt
+    /// uint64_t                      // BigUint64
+    /// rust::Vec<float>              // Float32Array
     /// craby::mymodule::bridging::MyEnum       // Enum
     /// craby::mymodule::bridging::MyStruct     // Object
+    /// rust::Vec<craby::mymodule::bridging::NumberArray>  // Array<Array<Number>>
     /// craby::mymodule::bridging::NullableNumber  // Nullable<Number>
+    /// craby::mymodule::bridging::NumberMap       // Map<Number>
+    /// craby::mymodule::bridging::MyUnionUnion    // Union
+    /// std::shared_ptr<craby::mymodule::callbacks::NumberCallback>  // Function
+    /// std::shared_ptr<craby::mymodule::streams::NumberStream>      // Stream
     /// ```
     pub fn as_cxx_type(&self, cxx_ns: &CxxNamespace) -> Result<String, anyhow::Error> {
         let cxx_type = match self {
             TypeAnnotation::Void => "void".to_string(),
             TypeAnnotation::Boolean => "bool".to_string(),
             TypeAnnotation::Number => "double".to_string(),
-            TypeAnnotation::String => "rust::String".to_string(),
+            TypeAnnotation::String | TypeAnnotation::StringLiteral(..) => {
+                "rust::String".to_string()
+            }
             TypeAnnotation::ArrayBuffer => "rust::Vec<uint8_t>".to_string(),
+            TypeAnnotation::BigInt => "int64_t".to_string(),
+            TypeAnnotation::BigUint64 => "uint64_t".to_string(),
+            TypeAnnotation::TypedArray(typed_array) => {
+                format!("rust::Vec<{}>", typed_array.as_cxx_element_type())
+            }
             TypeAnnotation::Array(element_type) => {
-                format!("rust::Vec<{}>", element_type.as_cxx_type(cxx_ns)?)
+                format!("rust::Vec<{}>", element_type.as_cxx_element_type(cxx_ns)?)
             }
             TypeAnnotation::Enum(EnumTypeAnnotation { name, .. }) => {
                 format!("{cxx_ns}::bridging::{name}")
@@ -78,45 +96,20 @@ impl TypeAnnotation {
             TypeAnnotation::Object(ObjectTypeAnnotation { name, .. }) => {
                 format!("{cxx_ns}::bridging::{name}")
             }
-            TypeAnnotation::Nullable(type_annotation) => {
-                let cxx_struct = match &**type_annotation {
-                    TypeAnnotation::Boolean => "NullableBoolean".to_string(),
-                    TypeAnnotation::Number => "NullableNumber".to_string(),
-                    TypeAnnotation::String => "NullableString".to_string(),
-                    TypeAnnotation::Void => "NullableVoid".to_string(), 
-                    TypeAnnotation::Object(ObjectTypeAnnotation { name, .. }) => format!("Nullable{}", name),
-                    TypeAnnotation::Enum(EnumTypeAnnotation { name, .. }) => format!("Nullable{}", name),
-                    TypeAnnotation::ArrayBuffer => "NullableArrayBuffer".to_string(),
-                    TypeAnnotation::Array(element_type) => match &**element_type {
-                        TypeAnnotation::Boolean => "NullableBooleanArray".to_string(),
-                        TypeAnnotation::Number=> {
-                            "NullableNumberArray".to_string()
-                        }
-                        TypeAnnotation::String => {
-                            "NullableStringArray".to_string()
-                        }
-                        TypeAnnotation::Object(ObjectTypeAnnotation { name, .. }) => {
-                            format!("Nullable{name}Array")
-                        }
-                        TypeAnnotation::Enum(EnumTypeAnnotation { name, .. }) => {
-                            format!("Nullable{name}Array")
-                        }
-                        _ => {
-                            return Err(anyhow::anyhow!(
-                                "[as_cxx_type] Unsupported type annotation for nullable array type: {:?}",
-                                element_type
-                            ))
-                        }
-                    },
-                    _ => {
-                        return Err(anyhow::anyhow!(
-                            "[as_cxx_type] Unsupported type annotation for nullable type: {:?}",
-                            type_annotation
-                        ))
-                    }
-                };
-
-                format!("{cxx_ns}::bridging::{cxx_struct}")
+            TypeAnnotation::Nullable(..) | TypeAnnotation::Map(..) | TypeAnnotation::Union(..) => {
+                format!("{cxx_ns}::bridging::{}", self.as_rs_struct_name()?)
+            }
+            TypeAnnotation::Function(..) => {
+                format!(
+                    "std::shared_ptr<{cxx_ns}::callbacks::{}>",
+                    self.as_rs_struct_name()?
+                )
+            }
+            TypeAnnotation::Stream(..) => {
+                format!(
+                    "std::shared_ptr<{cxx_ns}::streams::{}>",
+                    self.as_rs_struct_name()?
+                )
             }
             _ => {
                 return Err(anyhow::anyhow!(
@@ -129,6 +122,20 @@ impl TypeAnnotation {
         Ok(cxx_type)
     }
 
+    /// Converts TypeAnnotation to C++ type of the array element (or map value).
+    ///
+    /// `rust::Vec<rust::Vec<T>>` is not supported by cxx, so nested arrays and binary types are wrapped with a generated struct.
+    pub fn as_cxx_element_type(&self, cxx_ns: &CxxNamespace) -> Result<String, anyhow::Error> {
+        if self.is_rs_wrapped_element() {
+            Ok(format!(
+                "{cxx_ns}::bridging::{}",
+                self.as_rs_generated_struct_name()?
+            ))
+        } else {
+            self.as_cxx_type(cxx_ns)
+        }
+    }
+
     /// Generates default value for C++ types.
     ///
     /// # Generated Code Examples
@@ -137,18 +144,24 @@ impl TypeAnnotation {
     /// false                                 // Boolean
     /// 0.0                                   // Number
     /// rust::String()                        // String
+    /// rust::String("foo")                   // StringLiteral
     /// rust::Vec<double>()                   // Array<Number>
     /// MyEnum::FirstMember                   // Enum
     /// craby::mymodule::bridging::MyStruct{} // Object
+    /// craby::mymodule::bridging::NullableNumber{true, 0.0} // Nullable<Number>
     /// ```
     pub fn as_cxx_default_val(&self, cxx_ns: &CxxNamespace) -> Result<String, anyhow::Error> {
         let default_val = match self {
             TypeAnnotation::Boolean => "false".to_string(),
             TypeAnnotation::Number => "0.0".to_string(),
             TypeAnnotation::String => "rust::String()".to_string(),
+            TypeAnnotation::StringLiteral(value) => {
+                format!("rust::String({})", cxx_string_literal(value))
+            }
             TypeAnnotation::ArrayBuffer => "rust::Vec<uint8_t>()".to_string(),
-            TypeAnnotation::Array(element_type) => {
-                format!("rust::Vec<{}>()", element_type.as_cxx_type(cxx_ns)?)
+            TypeAnnotation::BigInt | TypeAnnotation::BigUint64 => "0".to_string(),
+            TypeAnnotation::Array(..) | TypeAnnotation::TypedArray(..) => {
+                format!("{}()", self.as_cxx_type(cxx_ns)?)
             }
             TypeAnnotation::Enum(EnumTypeAnnotation { members, .. }) => {
                 let enum_type = self.as_cxx_type(cxx_ns)?;
@@ -158,21 +171,14 @@ impl TypeAnnotation {
 
                 format!("{enum_type}::{}", first_member.name)
             }
-            TypeAnnotation::Object(..) => {
+            TypeAnnotation::Object(..) | TypeAnnotation::Map(..) | TypeAnnotation::Union(..) => {
                 let cxx_type = self.as_cxx_type(cxx_ns)?;
                 format!("{cxx_type}{{}}")
             }
-            TypeAnnotation::Nullable(..) => {
+            TypeAnnotation::Nullable(type_annotation) => {
                 let cxx_type = self.as_cxx_type(cxx_ns)?;
-                let default_val = self.as_cxx_default_val(cxx_ns)?;
-                formatdoc! {
-                    r#"
-                    {cxx_type} {{
-                        val: {default_val},
-                        null: true,
-                    }}
-                    "#,
-                }
+                let default_val = type_annotation.as_cxx_default_val(cxx_ns)?;
+                format!("{cxx_type}{{true, {default_val}}}")
             }
             _ => {
                 return Err(anyhow::anyhow!(
@@ -200,13 +206,24 @@ impl TypeAnnotation {
             | TypeAnnotation::Number
             | TypeAnnotation::String
             | TypeAnnotation::ArrayBuffer
+            | TypeAnnotation::BigInt
+            | TypeAnnotation::BigUint64
             | TypeAnnotation::Array(..)
             | TypeAnnotation::Enum(..)
             | TypeAnnotation::Object(..)
+            | TypeAnnotation::Map(..)
+            | TypeAnnotation::Union(..)
+            | TypeAnnotation::StringLiteral(..)
             | TypeAnnotation::Nullable(..) => format!(
                 "react::bridging::fromJs<{}>(rt, {ident}, callInvoker)",
                 self.as_cxx_type(cxx_ns)?,
             ),
+            // `rust::Vec<T>` is shared with the arrays (eg. `rust::Vec<double>` of `number[]`)
+            TypeAnnotation::TypedArray(typed_array) => format!(
+                "react::TypedArrayBridging<{}>::fromJs(rt, {ident}, \"{}\")",
+                typed_array.as_cxx_element_type(),
+                typed_array.name(),
+            ),
             _ => {
                 return Err(anyhow::anyhow!(
                     "[as_cxx_from_js] Unsupported type annotation: {:?}",
@@ -229,10 +246,27 @@ impl TypeAnnotation {
             | TypeAnnotation::Number
             | TypeAnnotation::String
             | TypeAnnotation::ArrayBuffer
+            | TypeAnnotation::BigInt
+            | TypeAnnotation::BigUint64
             | TypeAnnotation::Array(..)
             | TypeAnnotation::Enum(..)
             | TypeAnnotation::Object(..)
+            | TypeAnnotation::Map(..)
+            | TypeAnnotation::Union(..)
             | TypeAnnotation::Nullable(..) => format!("react::bridging::toJs(rt, {})", ident),
+            // `rust::Vec<T>` is shared with the arrays and `ArrayBuffer` (eg. `rust::Vec<uint8_t>`)
+            TypeAnnotation::TypedArray(typed_array) => format!(
+                "react::TypedArrayBridging<{}>::toJs(rt, {ident}, \"{}\")",
+                typed_array.as_cxx_element_type(),
+                typed_array.name(),
+            ),
+            // Always emits the literal value (eg. the discriminant of the union variants)
+            TypeAnnotation::StringLiteral(value) => {
+                format!(
+                    "jsi::Value(rt, jsi::String::createFromUtf8(rt, {}))",
+                    cxx_string_literal(value)
+                )
+            }
             TypeAnnotation::Promise(..) => {
                 format!("react::bridging::toJs(rt, {})", ident)
             }
@@ -249,6 +283,95 @@ impl TypeAnnotation {
     }
 }
 
+impl FunctionTypeAnnotation {
+    /// Generates the callback handle that retains the JS function.
+    ///
+    /// Each call is dispatched to the JS thread through the `callInvoker`,
+    /// and the function is released on the JS thread when the handle is dropped.
+    ///
+    /// # Generated Code
+    ///
+    /// ```cpp
+    /// std::make_shared<craby::mymodule::callbacks::NumberCallback>(
+    ///   [arg0$fn, callInvoker](double val0) {
+    ///     callInvoker->invokeAsync([arg0$fn, val0](jsi::Runtime &rt) {
+    ///       arg0$fn->call(rt, react::bridging::toJs(rt, val0));
+    ///     });
+    ///   },
+    ///   [arg0$fn, callInvoker]() mutable {
+    ///     callInvoker->invokeAsync([fn = std::move(arg0$fn)](jsi::Runtime &) {});
+    ///   })
+    /// ```
+    pub fn as_cxx_callback(
+        &self,
+        cxx_ns: &CxxNamespace,
+        callback_name: &str,
+        fn_var: &str,
+    ) -> Result<String, anyhow::Error> {
+        let vals = (0..self.params.len())
+            .map(|idx| format!("val{idx}"))
+            .collect::<Vec<_>>();
+
+        let params_sig = self
+            .params
+            .iter()
+            .zip(&vals)
+            .map(|(param, val)| -> Result<String, anyhow::Error> {
+                Ok(format!("{} {val}", param.as_cxx_type(cxx_ns)?))
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .join(", ");
+
+        let call_args = self
+            .params
+            .iter()
+            .zip(&vals)
+            .map(|(param, val)| -> Result<String, anyhow::Error> {
+                Ok(format!(", {}", param.as_cxx_to_js(val)?.expr))
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .join("");
+
+        let captures = std::iter::once(fn_var.to_string())
+            .chain(vals)
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        Ok(formatdoc! {
+            r#"
+            std::make_shared<{cxx_ns}::callbacks::{callback_name}>(
+              [{fn_var}, callInvoker]({params_sig}) {{
+                callInvoker->invokeAsync([{captures}](jsi::Runtime &rt) {{
+                  {fn_var}->call(rt{call_args});
+                }});
+              }},
+              [{fn_var}, callInvoker]() mutable {{
+                callInvoker->invokeAsync([fn = std::move({fn_var})](jsi::Runtime &) {{}});
+              }})"#,
+        })
+    }
+}
+
+impl TypedArrayType {
+    /// Returns the C++ element type of the typed array.
+    ///
+    /// eg. `uint8_t` (Uint8Array), `float` (Float32Array)
+    pub fn as_cxx_element_type(&self) -> &'static str {
+        match self {
+            TypedArrayType::Int8 => "int8_t",
+            TypedArrayType::Uint8 => "uint8_t",
+            TypedArrayType::Int16 => "int16_t",
+            TypedArrayType::Uint16 => "uint16_t",
+            TypedArrayType::Int32 => "int32_t",
+            TypedArrayType::Uint32 => "uint32_t",
+            TypedArrayType::Float32 => "float",
+            TypedArrayType::Float64 => "double",
+            TypedArrayType::BigInt64 => "int64_t",
+            TypedArrayType::BigUint64 => "uint64_t",
+        }
+    }
+}
+
 impl Method {
     /// Converts schema Method to C++ TurboModule method implementation.
     ///
@@ -297,7 +420,38 @@ impl Method {
 
             // `rust::Str` holds a reference to `std::string`.
             // To avoid dangling pointers, the converted `std::string` is retained within the scope for the lifetime of the reference.
-            let from_js = if let TypeAnnotation::String = &param.type_annotation {
+            let from_js = if let TypeAnnotation::AbortSignal = &param.type_annotation {
+                // The controller is aborted by the listener of the JS `AbortSignal` (see the Promise statements)
+                format!("{cxx_ns}::utils::intoShared({cxx_ns}::bridging::createAbortController())")
+            } else if param.optional {
+                // Missing or `undefined` trailing arguments are treated as `null`
+                //
+                // ```cpp
+                // auto arg1 = count > 1 && !args[1].isUndefined()
+                //   ? react::bridging::fromJs<T>(rt, args[1], callInvoker)
+                //   : craby::mymodule::bridging::NullableNumber{true, 0.0};
+                // ```
+                formatdoc! {
+                    r#"
+                    count > {idx} && !{arg_ref}.isUndefined()
+                      ? {from_js}
+                      : {default_val}"#,
+                    from_js = param.type_annotation.as_cxx_from_js(cxx_ns, &arg_ref)?.expr,
+                    default_val = param.type_annotation.as_cxx_default_val(cxx_ns)?,
+                }
+            } else if let TypeAnnotation::Function(function_type) = &param.type_annotation {
+                // Retain the JS function for the callback handle
+                let fn_var = format!("{arg_var}$fn");
+                args_decls.push(format!(
+                    "auto {fn_var} = std::make_shared<jsi::Function>({arg_ref}.asObject(rt).asFunction(rt));"
+                ));
+
+                function_type.as_cxx_callback(
+                    cxx_ns,
+                    &param.type_annotation.as_rs_struct_name()?,
+                    &fn_var,
+                )?
+            } else if let TypeAnnotation::String = &param.type_annotation {
                 // Capture the converted `std::string` within the scope of the reference
                 let str_var = format!("{arg_var}$raw");
                 args_decls.push(format!("auto {str_var} = {arg_ref}.asString(rt).utf8(rt);",));
@@ -311,15 +465,45 @@ impl Method {
             args_decls.push(format!("auto {arg_var} = {from_js};"));
         }
 
+        // The serial methods are invoked in the single thread queue of the `@serial` tag
+        //
+        // ```cpp
+        // thisModule.serialQueues_.at("storage")->enqueue(/* ... */);
+        // ```
+        let thread_pool = match &self.serial {
+            Some(queue) => format!("serialQueues_.at(\"{queue}\")"),
+            None => "threadPool_".to_string(),
+        };
+
         let invoke_stmts = match &self.ret_type {
+            // Returns the constants cached at the module construction without invoking the FFI function
+            //
+            // ```cpp
+            // return react::bridging::toJs(rt, thisModule.constants_);
+            // ```
+            ret_type if self.is_constants() => {
+                format!(
+                    "return {};",
+                    ret_type.as_cxx_to_js("thisModule.constants_")?.expr
+                )
+            }
             TypeAnnotation::Promise(resolve_type) => {
                 let mut bind_args = Vec::with_capacity(args.len() + 2);
                 bind_args.push(RESERVED_ARG_NAME_MODULE.to_string());
                 bind_args.push("promise".to_string());
                 bind_args.extend(args.clone());
 
-                args.insert(0, format!("*{}", RESERVED_ARG_NAME_MODULE));
-                let fn_args = args.join(", ");
+                // The controller is passed by reference (`&AbortController`)
+                let fn_args = std::iter::once(format!("*{RESERVED_ARG_NAME_MODULE}"))
+                    .chain(self.params.iter().zip(&args).map(|(param, arg)| {
+                        if param.type_annotation.is_abort_signal() {
+                            format!("*{arg}")
+                        } else {
+                            arg.clone()
+                        }
+                    }))
+                    .collect::<Vec<_>>()
+                    .join(", ");
 
                 let ret_stmts = if let TypeAnnotation::Void = &**resolve_type {
                     formatdoc! {
@@ -344,14 +528,44 @@ impl Method {
                 } else {
                     resolve_type.as_cxx_type(cxx_ns)?
                 };
-                let ret = self.ret_type.as_cxx_to_js("promise")?.expr;
+                // The promise is settled on the JS thread to reject with the typed error,
+                // and rejected with an `AbortError` when the signal is aborted
+                //
+                // ```cpp
+                // promise.listen(rt, args[1], arg1);
+                // ```
+                let listen_stmt = self
+                    .params
+                    .iter()
+                    .position(|param| param.type_annotation.is_abort_signal())
+                    .map(|idx| {
+                        let listen_stmt =
+                            format!("promise.listen(rt, {}, {});", cxx_arg_ref(idx), args[idx]);
+                        if self.params[idx].optional {
+                            formatdoc! {
+                                r#"
+
+                                if (count > {idx}) {{
+                                  {listen_stmt}
+                                }}"#,
+                            }
+                        } else {
+                            format!("\n{listen_stmt}")
+                        }
+                    })
+                    .unwrap_or_default();
 
                 // Create a promise object and invoke the FFI function in a separate thread
                 formatdoc! {
                     r#"
-                    react::AsyncPromise<{ret_type}> promise(rt, callInvoker);
-
-                    thisModule.threadPool_->enqueue([{bind_args}]() mutable {{
+                    {cxx_ns}::utils::Promise<{ret_type}> promise(
+                      callInvoker,
+                      [](jsi::Runtime &rt, {ret_type} &ret) -> jsi::Value {{
+                        return {to_js};
+                      }});
+                    auto jsPromise = promise.get(rt);{listen_stmt}
+
+                    thisModule.{thread_pool}->enqueue([{bind_args}]() mutable {{
                       try {{
                     {ret_stmts}
                       }} catch (const jsi::JSError &err) {{
@@ -361,7 +575,48 @@ impl Method {
                       }}
                     }});
 
-                    return {ret};"#,
+                    return jsPromise;"#,
+                    to_js = resolve_type.as_cxx_to_js("ret")?.expr,
+                }
+            }
+            TypeAnnotation::Stream(item_type) => {
+                let mut bind_args = Vec::with_capacity(args.len() + 2);
+                bind_args.push(RESERVED_ARG_NAME_MODULE.to_string());
+                bind_args.push(RESERVED_ARG_NAME_STREAM.to_string());
+                bind_args.extend(args.clone());
+
+                args.insert(0, format!("*{RESERVED_ARG_NAME_MODULE}"));
+                args.push(RESERVED_ARG_NAME_STREAM.to_string());
+                let fn_args = args.join(", ");
+
+                let bind_args = bind_args.join(", ");
+                let stream_type = self.ret_type.as_rs_struct_name()?;
+                let item = item_type.as_cxx_to_js("item")?.expr;
+                let item_type = item_type.as_cxx_type(cxx_ns)?;
+
+                // Create the async iterator that pulls the items from the stream,
+                // and invoke the FFI function that pushes the items in a separate thread
+                formatdoc! {
+                    r#"
+                    auto {stream} = std::make_shared<{cxx_ns}::streams::{stream_type}>();
+                    thisModule.streams_.add({stream});
+
+                    auto iterator = {cxx_ns}::utils::createAsyncIterator(
+                      rt, {stream}, callInvoker,
+                      [](jsi::Runtime &rt, {item_type} &item) -> jsi::Value {{
+                        return {item};
+                      }});
+
+                    thisModule.{thread_pool}->enqueue([{bind_args}]() mutable {{
+                      try {{
+                        {cxx_ns}::bridging::{fn_name}({fn_args});
+                      }} catch (const std::exception &err) {{
+                        {stream}->error(rust::Str({cxx_ns}::utils::errorMessage(err)));
+                      }}
+                    }});
+
+                    return iterator;"#,
+                    stream = RESERVED_ARG_NAME_STREAM,
                 }
             }
             _ => {
@@ -391,16 +646,93 @@ impl Method {
 
         let args_decls = args_decls.join("\n");
         let args_count = self.params.len();
+        let min_args_count = self.min_args_count();
 
         // ```cpp
         // MethodMetadata{{1, &CxxMyTestModule::myFunc}}
         // ```
         let metadata = formatdoc! {
             r#"
-            MethodMetadata{{{args_count}, &{cxx_mod}::{fn_name}}}"#,
+            MethodMetadata{{{min_args_count}, &{cxx_mod}::{fn_name}}}"#,
+        };
+
+        let args_count_check = if min_args_count == args_count {
+            // ```cpp
+            // if (2 != count) {
+            //   throw jsi::JSError(rt, "Expected 2 arguments");
+            // }
+            // ```
+            formatdoc! {
+                r#"
+                if ({args_count} != count) {{
+                  throw jsi::JSError(rt, "Expected {args_count} argument{plural}");
+                }}"#,
+                plural = if args_count > 1 { "s" } else { "" },
+            }
+        } else {
+            // Trailing optional arguments can be omitted
+            //
+            // ```cpp
+            // if (count < 1 || 2 < count) {
+            //   throw jsi::JSError(rt, "Expected 1 to 2 arguments");
+            // }
+            // ```
+            let cond = if min_args_count == 0 {
+                format!("{args_count} < count")
+            } else {
+                format!("count < {min_args_count} || {args_count} < count")
+            };
+
+            formatdoc! {
+                r#"
+                if ({cond}) {{
+                  throw jsi::JSError(rt, "Expected {min_args_count} to {args_count} arguments");
+                }}"#,
+            }
         };
+        let args_count_check = indent_str(&args_count_check, 4);
 
         let invoke_stmts = indent_str([args_decls, invoke_stmts].join("\n").trim(), 4);
+
+        // Warn only once when the deprecated method is called
+        //
+        // ```cpp
+        // static std::once_flag deprecationFlag;
+        // std::call_once(deprecationFlag, [&rt] {
+        //   craby::mymodule::utils::warn(rt, std::string(CxxMyTestModule::kModuleName) + ".myFunc() is deprecated: Use `otherFunc` instead");
+        // });
+        // ```
+        let deprecation_stmts = match &self.deprecated {
+            Some(reason) => {
+                let message = if reason.is_empty() {
+                    format!(".{fn_name}() is deprecated")
+                } else {
+                    format!(".{fn_name}() is deprecated: {reason}")
+                };
+                let stmts = formatdoc! {
+                    r#"
+                    static std::once_flag deprecationFlag;
+                    std::call_once(deprecationFlag, [&rt] {{
+                      {cxx_ns}::utils::warn(rt, std::string({cxx_mod}::kModuleName) + {message});
+                    }});"#,
+                    message = cxx_string_literal(&message),
+                };
+                format!("\n\n{}", indent_str(&stmts, 2))
+            }
+            None => String::new(),
+        };
+
+        // The synchronous method of the `@throws` tag throws the JS error with the error code
+        //
+        // ```cpp
+        // throw jsi::JSError(rt, craby::mymodule::utils::createError(rt, craby::mymodule::utils::errorMessage(err)));
+        // ```
+        let js_error = if self.is_fallible_sync() {
+            format!("{cxx_ns}::utils::createError(rt, {cxx_ns}::utils::errorMessage(err))")
+        } else {
+            format!("{cxx_ns}::utils::errorMessage(err)")
+        };
+
         let impl_func = formatdoc! {
             r#"
             jsi::Value {cxx_mod}::{fn_name}(jsi::Runtime &rt,
@@ -409,21 +741,18 @@ impl Method {
                                             size_t count) {{
               auto &thisModule = static_cast<{cxx_mod} &>(turboModule);
               auto callInvoker = thisModule.callInvoker_;
-              auto it_ = thisModule.module_;
+              auto it_ = thisModule.module_;{deprecation_stmts}
 
               try {{
-                if ({args_count} != count) {{
-                  throw jsi::JSError(rt, "Expected {args_count} argument{plural}");
-                }}
+            {args_count_check}
 
             {invoke_stmts}
               }} catch (const jsi::JSError &err) {{
                 throw err;
               }} catch (const std::exception &err) {{
-                throw jsi::JSError(rt, {cxx_ns}::utils::errorMessage(err));
+                throw jsi::JSError(rt, {js_error});
               }}
             }}"#,
-            plural = if args_count > 1 { "s" } else { "" },
         };
 
         Ok(CxxMethod {
@@ -470,7 +799,7 @@ impl Schema {
         let cxx_ns = CxxNamespace::from(project_name);
         let mut bridging_templates = BTreeMap::new();
         let mut enum_bridging_templates = BTreeMap::new();
-        let mut nullable_bridging_templates = self.collect_nullable_types(project_name)?;
+        let mut generated_bridging_templates = self.collect_generated_types(project_name)?;
 
         for type_annotation in &self.aliases {
             let alias_spec = type_annotation.as_object().unwrap();
@@ -500,20 +829,18 @@ impl Schema {
                 ordered_templates.push(template);
             }
 
-            if let Some(template) =
-                nullable_bridging_templates.remove(&format!("{cxx_ns}::bridging::{name}"))
-            {
+            if let Some(template) = generated_bridging_templates.remove(name) {
                 ordered_templates.push(template);
             }
         });
 
         ordered_templates.extend(bridging_templates.into_values());
-        ordered_templates.extend(nullable_bridging_templates.into_values());
+        ordered_templates.extend(generated_bridging_templates.into_values());
 
         Ok(ordered_templates)
     }
 
-    /// Collects all nullable types from schema to generate bridging templates.
+    /// Collects all generated types (nullable, nested array, map and union types) from schema to generate bridging templates.
     ///
     /// # Generated Code
     ///
@@ -540,63 +867,39 @@ impl Schema {
     ///   }
     /// };
     /// ```
-    pub fn collect_nullable_types(
+    pub fn collect_generated_types(
         &self,
         project_name: &str,
     ) -> Result<BTreeMap<String, String>, anyhow::Error> {
         let cxx_ns = CxxNamespace::from(project_name);
         let mut templates = BTreeMap::new();
 
-        for method in &self.methods {
-            for param in &method.params {
-                if let nullable_type @ TypeAnnotation::Nullable(inner_type_annotation) =
-                    &param.type_annotation
-                {
-                    let key = nullable_type.as_cxx_type(&cxx_ns)?;
-                    if let BTreeMapEntry::Vacant(e) = templates.entry(key) {
-                        let bridging_template = CxxBridgingTemplate::try_into_nullable_template(
+        for type_annotation in self.collect_rs_struct_types() {
+            let key = type_annotation.as_rs_generated_struct_name()?;
+            if let BTreeMapEntry::Vacant(e) = templates.entry(key) {
+                let bridging_template = match type_annotation {
+                    TypeAnnotation::Nullable(inner_type_annotation) => {
+                        CxxBridgingTemplate::try_into_nullable_template(
                             &cxx_ns,
-                            nullable_type,
+                            type_annotation,
                             inner_type_annotation,
                         )?
-                        .into_code();
-                        e.insert(bridging_template);
                     }
-                }
-            }
-
-            if let nullable_type @ TypeAnnotation::Nullable(inner_type_annotation) =
-                &method.ret_type
-            {
-                let key = nullable_type.as_cxx_type(&cxx_ns)?;
-                if let BTreeMapEntry::Vacant(e) = templates.entry(key) {
-                    let bridging_template = CxxBridgingTemplate::try_into_nullable_template(
+                    TypeAnnotation::Map(value_type) => CxxBridgingTemplate::try_into_map_template(
                         &cxx_ns,
-                        nullable_type,
-                        inner_type_annotation,
-                    )?
-                    .into_code();
-                    e.insert(bridging_template);
-                }
-            }
-        }
-
-        for type_annotation in &self.aliases {
-            for prop in &type_annotation.as_object().unwrap().props {
-                if let nullable_type @ TypeAnnotation::Nullable(inner_type_annotation) =
-                    &prop.type_annotation
-                {
-                    let key = nullable_type.as_cxx_type(&cxx_ns)?;
-                    if let BTreeMapEntry::Vacant(e) = templates.entry(key) {
-                        let bridging_template = CxxBridgingTemplate::try_into_nullable_template(
+                        type_annotation,
+                        value_type,
+                    )?,
+                    TypeAnnotation::Union(union_spec) => {
+                        CxxBridgingTemplate::try_into_union_template(
                             &cxx_ns,
-                            nullable_type,
-                            inner_type_annotation,
+                            type_annotation,
+                            union_spec,
                         )?
-                        .into_code();
-                        e.insert(bridging_template);
                     }
-                }
+                    _ => CxxBridgingTemplate::try_into_array_template(&cxx_ns, type_annotation)?,
+                };
+                e.insert(bridging_template.into_code());
             }
         }
 
@@ -612,7 +915,7 @@ pub mod template {
         common::IntoCode,
         parser::types::{
             EnumMemberValue as ParserEnumMemberValue, EnumTypeAnnotation, ObjectTypeAnnotation,
-            TypeAnnotation,
+            TypeAnnotation, UnionTypeAnnotation,
         },
         types::CxxNamespace,
         utils::indent_str,
@@ -710,28 +1013,62 @@ pub mod template {
             for prop in &obj.props {
                 let ident = format!("obj${}", camel_case(&prop.name));
                 let converted_ident = format!("_{}", ident);
+                let value_ident = format!("value.{}", snake_case(&prop.name));
                 let from_js = prop.type_annotation.as_cxx_from_js(cxx_ns, &ident)?;
-                let to_js = prop
-                    .type_annotation
-                    .as_cxx_to_js(&format!("value.{}", snake_case(&prop.name)))?;
+                let to_js = prop.type_annotation.as_cxx_to_js(&value_ident)?;
 
                 // ```cpp
                 // auto obj$name = obj.getProperty(rt, "name");
                 // ```
                 let get_prop = format!("auto {} = obj.getProperty(rt, \"{}\");", ident, prop.name);
 
-                // ```cpp
-                // obj.setProperty(rt, "name", _obj$name);
-                // ```
-                let set_prop = format!(
-                    "obj.setProperty(rt, \"{}\", {});",
-                    prop.name, converted_ident
-                );
+                let set_prop = if prop.optional {
+                    // Omit the property when the optional value is absent
+                    //
+                    // ```cpp
+                    // if (!value.name.null) {
+                    //   obj.setProperty(rt, "name", _obj$name);
+                    // }
+                    // ```
+                    formatdoc! {
+                        r#"
+                        if (!{value_ident}.null) {{
+                          obj.setProperty(rt, "{name}", {converted_ident});
+                        }}"#,
+                        name = prop.name,
+                    }
+                } else {
+                    // ```cpp
+                    // obj.setProperty(rt, "name", _obj$name);
+                    // ```
+                    format!(
+                        "obj.setProperty(rt, \"{}\", {});",
+                        prop.name, converted_ident
+                    )
+                };
 
-                // ```cpp
-                // auto _obj$name = react::bridging::fromJs<T>(rt, value.name, callInvoker);
-                // ```
-                let from_js_stmt = format!("auto {} = {};", converted_ident, from_js.expr);
+                let from_js_stmt = if prop.optional {
+                    // Missing key (`undefined`) of the optional property is treated as `null`
+                    //
+                    // ```cpp
+                    // auto _obj$name = obj$name.isUndefined()
+                    //   ? craby::mymodule::bridging::NullableNumber{true, 0.0}
+                    //   : react::bridging::fromJs<T>(rt, obj$name, callInvoker);
+                    // ```
+                    formatdoc! {
+                        r#"
+                        auto {converted_ident} = {ident}.isUndefined()
+                          ? {default_val}
+                          : {from_js};"#,
+                        default_val = prop.type_annotation.as_cxx_default_val(cxx_ns)?,
+                        from_js = from_js.expr,
+                    }
+                } else {
+                    // ```cpp
+                    // auto _obj$name = react::bridging::fromJs<T>(rt, value.name, callInvoker);
+                    // ```
+                    format!("auto {} = {};", converted_ident, from_js.expr)
+                };
 
                 // ```cpp
                 // auto _obj$name = react::bridging::toJs(rt, value.name);
@@ -990,9 +1327,10 @@ pub mod template {
             nullable_type_annotation: &TypeAnnotation,
             type_annotation: &TypeAnnotation,
         ) -> Result<CxxBridgingTemplate, anyhow::Error> {
-            let origin_namespace = type_annotation.as_cxx_type(cxx_ns)?;
             let default_value = type_annotation.as_cxx_default_val(cxx_ns)?;
             let nullable_type_namespace = nullable_type_annotation.as_cxx_type(cxx_ns)?;
+            let from_js = type_annotation.as_cxx_from_js(cxx_ns, "value")?.expr;
+            let to_js = type_annotation.as_cxx_to_js("value.val")?.expr;
 
             let from_js_impl = formatdoc! {
                 r#"
@@ -1000,7 +1338,7 @@ pub mod template {
                   return {nullable_type_namespace}{{true, {default_value}}};
                 }}
 
-                auto val = react::bridging::fromJs<{origin_namespace}>(rt, value, callInvoker);
+                auto val = {from_js};
                 auto ret = {nullable_type_namespace}{{false, val}};
 
                 return ret;"#,
@@ -1012,7 +1350,7 @@ pub mod template {
                   return jsi::Value::null();
                 }}
 
-                return react::bridging::toJs(rt, value.val);"#,
+                return {to_js};"#,
             };
 
             Ok(CxxBridgingTemplate {
@@ -1021,6 +1359,258 @@ pub mod template {
                 to_js: to_js_impl,
             })
         }
+
+        /// Generates C++ bridging template for map types (converted from/to the plain JS object).
+        ///
+        /// # Generated Code
+        ///
+        /// ```cpp
+        /// template <>
+        /// struct Bridging<craby::mymodule::bridging::NumberMap> {
+        ///   static craby::mymodule::bridging::NumberMap fromJs(jsi::Runtime &rt, const jsi::Value& value, std::shared_ptr<CallInvoker> callInvoker) {
+        ///     auto obj = value.asObject(rt);
+        ///     auto keys = obj.getPropertyNames(rt);
+        ///     auto size = keys.size(rt);
+        ///     auto ret = craby::mymodule::bridging::NumberMap{};
+        ///
+        ///     for (size_t i = 0; i < size; i++) {
+        ///       auto key = keys.getValueAtIndex(rt, i).asString(rt);
+        ///       auto val = obj.getProperty(rt, jsi::PropNameID::forString(rt, key));
+        ///       ret.keys.push_back(rust::String(key.utf8(rt)));
+        ///       ret.vals.push_back(react::bridging::fromJs<double>(rt, val, callInvoker));
+        ///     }
+        ///
+        ///     return ret;
+        ///   }
+        ///
+        ///   static jsi::Value toJs(jsi::Runtime &rt, craby::mymodule::bridging::NumberMap value) {
+        ///     jsi::Object obj = jsi::Object(rt);
+        ///
+        ///     for (size_t i = 0; i < value.keys.size(); i++) {
+        ///       auto key = jsi::PropNameID::forUtf8(rt, std::string(value.keys[i]));
+        ///       obj.setProperty(rt, key, react::bridging::toJs(rt, value.vals[i]));
+        ///     }
+        ///
+        ///     return jsi::Value(rt, obj);
+        ///   }
+        /// };
+        /// ```
+        pub fn try_into_map_template(
+            cxx_ns: &CxxNamespace,
+            map_type_annotation: &TypeAnnotation,
+            value_type_annotation: &TypeAnnotation,
+        ) -> Result<CxxBridgingTemplate, anyhow::Error> {
+            let map_type_namespace = map_type_annotation.as_cxx_type(cxx_ns)?;
+            let value_namespace = value_type_annotation.as_cxx_element_type(cxx_ns)?;
+            // The wrapped values are converted by the bridging template of the wrapper struct
+            let to_js = if value_type_annotation.is_rs_wrapped_element() {
+                "react::bridging::toJs(rt, value.vals[i])".to_string()
+            } else {
+                value_type_annotation.as_cxx_to_js("value.vals[i]")?.expr
+            };
+
+            let from_js_impl = formatdoc! {
+                r#"
+                auto obj = value.asObject(rt);
+                auto keys = obj.getPropertyNames(rt);
+                auto size = keys.size(rt);
+                auto ret = {map_type_namespace}{{}};
+
+                for (size_t i = 0; i < size; i++) {{
+                  auto key = keys.getValueAtIndex(rt, i).asString(rt);
+                  auto val = obj.getProperty(rt, jsi::PropNameID::forString(rt, key));
+                  ret.keys.push_back(rust::String(key.utf8(rt)));
+                  ret.vals.push_back(react::bridging::fromJs<{value_namespace}>(rt, val, callInvoker));
+                }}
+
+                return ret;"#,
+            };
+
+            let to_js_impl = formatdoc! {
+                r#"
+                jsi::Object obj = jsi::Object(rt);
+
+                for (size_t i = 0; i < value.keys.size(); i++) {{
+                  auto key = jsi::PropNameID::forUtf8(rt, std::string(value.keys[i]));
+                  obj.setProperty(rt, key, {to_js});
+                }}
+
+                return jsi::Value(rt, obj);"#,
+            };
+
+            Ok(CxxBridgingTemplate {
+                namespace: map_type_namespace,
+                from_js: from_js_impl,
+                to_js: to_js_impl,
+            })
+        }
+
+        /// Generates C++ bridging template for discriminated union types.
+        ///
+        /// The variant is selected by the discriminant property value.
+        ///
+        /// # Generated Code
+        ///
+        /// ```cpp
+        /// template <>
+        /// struct Bridging<craby::mymodule::bridging::ShapeUnion> {
+        ///   static craby::mymodule::bridging::ShapeUnion fromJs(jsi::Runtime &rt, const jsi::Value& value, std::shared_ptr<CallInvoker> callInvoker) {
+        ///     auto obj = value.asObject(rt);
+        ///     auto tag = obj.getProperty(rt, "kind").asString(rt).utf8(rt);
+        ///     auto ret = craby::mymodule::bridging::ShapeUnion{};
+        ///
+        ///     if (tag == "circle") {
+        ///       ret.tag_ = 0;
+        ///       ret.circle = react::bridging::fromJs<craby::mymodule::bridging::Circle>(rt, value, callInvoker);
+        ///     } else if (tag == "square") {
+        ///       ret.tag_ = 1;
+        ///       ret.square = react::bridging::fromJs<craby::mymodule::bridging::Square>(rt, value, callInvoker);
+        ///     } else {
+        ///       throw jsi::JSError(rt, "Invalid union value (Shape)");
+        ///     }
+        ///
+        ///     return ret;
+        ///   }
+        ///
+        ///   static jsi::Value toJs(jsi::Runtime &rt, craby::mymodule::bridging::ShapeUnion value) {
+        ///     switch (value.tag_) {
+        ///       case 0:
+        ///         return react::bridging::toJs(rt, value.circle);
+        ///       case 1:
+        ///         return react::bridging::toJs(rt, value.square);
+        ///       default:
+        ///         throw jsi::JSError(rt, "Invalid union value (Shape)");
+        ///     }
+        ///   }
+        /// };
+        /// ```
+        pub fn try_into_union_template(
+            cxx_ns: &CxxNamespace,
+            union_type_annotation: &TypeAnnotation,
+            union_spec: &UnionTypeAnnotation,
+        ) -> Result<CxxBridgingTemplate, anyhow::Error> {
+            let union_type_namespace = union_type_annotation.as_cxx_type(cxx_ns)?;
+            let error_stmt = format!(
+                "throw jsi::JSError(rt, \"Invalid union value ({})\");",
+                union_spec.name
+            );
+            let mut from_js_branches = vec![];
+            let mut to_js_cases = vec![];
+
+            for (idx, variant) in union_spec.variants.iter().enumerate() {
+                let tag = union_spec
+                    .tag(variant)
+                    .ok_or_else(|| anyhow::anyhow!("Invalid union variant: {:?}", variant))?;
+                let field = snake_case(&variant.as_rs_struct_name()?);
+                let from_js = variant.as_cxx_from_js(cxx_ns, "value")?.expr;
+                let to_js = variant.as_cxx_to_js(&format!("value.{field}"))?.expr;
+                let keyword = if idx == 0 { "if" } else { "} else if" };
+
+                // ```cpp
+                // if (tag == "circle") {
+                //   ret.tag_ = 0;
+                //   ret.circle = react::bridging::fromJs<Circle>(rt, value, callInvoker);
+                // ```
+                from_js_branches.push(formatdoc! {
+                    r#"
+                    {keyword} (tag == {tag:?}) {{
+                      ret.tag_ = {idx};
+                      ret.{field} = {from_js};"#,
+                });
+
+                // ```cpp
+                // case 0:
+                //   return react::bridging::toJs(rt, value.circle);
+                // ```
+                to_js_cases.push(formatdoc! {
+                    r#"
+                    case {idx}:
+                      return {to_js};"#,
+                });
+            }
+
+            let from_js_branches = from_js_branches.join("\n");
+            let to_js_cases = indent_str(&to_js_cases.join("\n"), 2);
+
+            let from_js_impl = formatdoc! {
+                r#"
+                auto obj = value.asObject(rt);
+                auto tag = obj.getProperty(rt, "{discriminant}").asString(rt).utf8(rt);
+                auto ret = {union_type_namespace}{{}};
+
+                {from_js_branches}
+                }} else {{
+                  {error_stmt}
+                }}
+
+                return ret;"#,
+                discriminant = union_spec.discriminant,
+            };
+
+            let to_js_impl = formatdoc! {
+                r#"
+                switch (value.tag_) {{
+                {to_js_cases}
+                  default:
+                    {error_stmt}
+                }}"#,
+            };
+
+            Ok(CxxBridgingTemplate {
+                namespace: union_type_namespace,
+                from_js: from_js_impl,
+                to_js: to_js_impl,
+            })
+        }
+
+        /// Generates C++ bridging template for nested array types (wrapped array struct).
+        ///
+        /// The binary types in the arrays or maps are wrapped in the same way (eg. `Uint8ArrayItem`).
+        ///
+        /// # Generated Code
+        ///
+        /// ```cpp
+        /// template <>
+        /// struct Bridging<craby::mymodule::bridging::NumberArray> {
+        ///   static craby::mymodule::bridging::NumberArray fromJs(jsi::Runtime &rt, const jsi::Value& value, std::shared_ptr<CallInvoker> callInvoker) {
+        ///     auto val = react::bridging::fromJs<rust::Vec<double>>(rt, value, callInvoker);
+        ///     auto ret = craby::mymodule::bridging::NumberArray{val};
+        ///
+        ///     return ret;
+        ///   }
+        ///
+        ///   static jsi::Value toJs(jsi::Runtime &rt, craby::mymodule::bridging::NumberArray value) {
+        ///     return react::bridging::toJs(rt, value.val);
+        ///   }
+        /// };
+        /// ```
+        pub fn try_into_array_template(
+            cxx_ns: &CxxNamespace,
+            array_type_annotation: &TypeAnnotation,
+        ) -> Result<CxxBridgingTemplate, anyhow::Error> {
+            let array_type_namespace = format!(
+                "{cxx_ns}::bridging::{}",
+                array_type_annotation.as_rs_generated_struct_name()?
+            );
+            let from_js = array_type_annotation.as_cxx_from_js(cxx_ns, "value")?.expr;
+            let to_js = array_type_annotation.as_cxx_to_js("value.val")?.expr;
+
+            let from_js_impl = formatdoc! {
+                r#"
+                auto val = {from_js};
+                auto ret = {array_type_namespace}{{val}};
+
+                return ret;"#,
+            };
+
+            let to_js_impl = format!("return {to_js};");
+
+            Ok(CxxBridgingTemplate {
+                namespace: array_type_namespace,
+                from_js: from_js_impl,
+                to_js: to_js_impl,
+            })
+        }
     }
 
     /// Generates C++ argument reference expression.
@@ -2,18 +2,22 @@ use std::collections::{
     btree_map::Entry as BTreeMapEntry, hash_map::Entry as HashMapEntry, BTreeMap,
 };
 
-use craby_common::utils::string::{camel_case, pascal_case, snake_case};
+use craby_common::{
+    config::PanicStrategy,
+    utils::string::{camel_case, pascal_case, snake_case},
+};
 use indoc::formatdoc;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
     common::IntoCode,
-    constants::specs::RESERVED_ARG_NAME_MODULE,
+    constants::specs::{RESERVED_ARG_NAME_MODULE, RESERVED_ARG_NAME_STREAM},
     parser::types::{
-        EnumTypeAnnotation, Method, ObjectTypeAnnotation, Param, RefTypeAnnotation, TypeAnnotation,
+        EnumTypeAnnotation, FunctionTypeAnnotation, Method, ObjectTypeAnnotation, Param,
+        RefTypeAnnotation, TypeAnnotation, TypedArrayType, UnionTypeAnnotation,
     },
     platform::rust::template::{
-        collect_alias_default_impls, RsDefaultImpl, RsNullableStruct, RsStruct,
+        collect_alias_default_impls, try_into_generated_struct, RsDefaultImpl, RsStruct,
     },
     types::Schema,
     utils::indent_str,
@@ -108,27 +112,40 @@ impl TypeAnnotation {
     /// bool                          // Boolean
     /// f64                           // Number
     /// String                        // String
+    /// i64                           // BigInt
+    /// u64                           // BigUint64
     /// Vec<f64>                      // Array<Number>
+    /// Vec<f32>                      // Float32Array
     /// MyEnum                        // Enum
     /// MyStruct                      // Object
+    /// Vec<NumberArray>              // Array<Array<Number>>
+    /// Vec<Uint8ArrayItem>           // Array<Uint8Array>
+    /// NumberMap                     // Map<Number>
     /// NullableNumber                // Nullable<Number>
+    /// MyUnionUnion                  // Union
+    /// SharedPtr<NumberCallback>     // Function (eg. `(p: number) => void`)
     /// Result<f64, anyhow::Error>    // Promise<Number>
+    /// SharedPtr<NumberStream>       // Stream (eg. `AsyncIterable<number>`)
     /// ```
     pub fn as_rs_type(&self) -> Result<RsType, anyhow::Error> {
         let rs_type = match self {
             TypeAnnotation::Void => "()".to_string(),
             TypeAnnotation::Boolean => "bool".to_string(),
             TypeAnnotation::Number => "f64".to_string(),
-            TypeAnnotation::String => "String".to_string(),
+            TypeAnnotation::String | TypeAnnotation::StringLiteral(..) => "String".to_string(),
             TypeAnnotation::ArrayBuffer => "Vec<u8>".to_string(),
+            TypeAnnotation::BigInt => "i64".to_string(),
+            TypeAnnotation::BigUint64 => "u64".to_string(),
+            TypeAnnotation::TypedArray(typed_array) => {
+                format!("Vec<{}>", typed_array.as_rs_element_type())
+            }
             TypeAnnotation::Array(element_type) => {
-                if let TypeAnnotation::Array(..) = &**element_type {
-                    return Err(anyhow::anyhow!(
-                        "Nested array type is not supported: {:?}",
-                        element_type
-                    ));
+                // `Vec<Vec<T>>` is not supported by cxx, so the inner array is wrapped with a generated struct.
+                if element_type.is_rs_wrapped_element() {
+                    format!("Vec<{}>", element_type.as_rs_generated_struct_name()?)
+                } else {
+                    format!("Vec<{}>", element_type.as_rs_type()?.into_code())
                 }
-                format!("Vec<{}>", element_type.as_rs_type()?.into_code())
             }
             TypeAnnotation::Object(ObjectTypeAnnotation { name, .. }) => name.clone(),
             TypeAnnotation::Enum(EnumTypeAnnotation { name, .. }) => name.clone(),
@@ -138,47 +155,12 @@ impl TypeAnnotation {
                     resolve_type.as_rs_type()?.into_code()
                 )
             }
-            TypeAnnotation::Nullable(type_annotation) => match &**type_annotation {
-                TypeAnnotation::Boolean => "NullableBoolean".to_string(),
-                TypeAnnotation::Number => "NullableNumber".to_string(),
-                TypeAnnotation::String => "NullableString".to_string(),
-                TypeAnnotation::Object(ObjectTypeAnnotation { name, .. }) => {
-                    format!("Nullable{name}")
-                }
-                TypeAnnotation::Enum(EnumTypeAnnotation { name, .. }) => {
-                    format!("Nullable{name}")
-                }
-                TypeAnnotation::Ref(RefTypeAnnotation { name, .. }) => {
-                    format!("Nullable{name}")
-                }
-                TypeAnnotation::ArrayBuffer => "NullableArrayBuffer".to_string(),
-                TypeAnnotation::Array(element_type) => match &**element_type {
-                    TypeAnnotation::Boolean => "NullableBooleanArray".to_string(),
-                    TypeAnnotation::Number => "NullableNumberArray".to_string(),
-                    TypeAnnotation::String => "NullableStringArray".to_string(),
-                    TypeAnnotation::Object(ObjectTypeAnnotation { name, .. }) => {
-                        format!("Nullable{name}Array")
-                    }
-                    TypeAnnotation::Enum(EnumTypeAnnotation { name, .. }) => {
-                        format!("Nullable{name}Array")
-                    }
-                    TypeAnnotation::Ref(RefTypeAnnotation { name, .. }) => {
-                        format!("Nullable{name}Array")
-                    }
-                    _ => {
-                        return Err(anyhow::anyhow!(
-                        "[as_rs_type] Unsupported type annotation for nullable array type: {:?}",
-                        element_type
-                    ))
-                    }
-                },
-                _ => {
-                    return Err(anyhow::anyhow!(
-                        "[as_rs_type] Unsupported type annotation for nullable type: {:?}",
-                        type_annotation
-                    ))
-                }
-            },
+            TypeAnnotation::Nullable(..) | TypeAnnotation::Map(..) | TypeAnnotation::Union(..) => {
+                self.as_rs_struct_name()?
+            }
+            TypeAnnotation::Function(..) | TypeAnnotation::Stream(..) => {
+                format!("SharedPtr<{}>", self.as_rs_struct_name()?)
+            }
             _ => {
                 return Err(anyhow::anyhow!(
                     "[as_rs_type] Unsupported type annotation: {:?}",
@@ -190,6 +172,109 @@ impl TypeAnnotation {
         Ok(RsType(rs_type))
     }
 
+    /// Returns the name of the type that used for the generated struct names.
+    ///
+    /// # Generated Code Examples
+    ///
+    /// ```rust,ignore
+    /// Number                        // Number
+    /// Float32Array                  // Float32Array
+    /// MyStruct                      // Object
+    /// NumberArray                   // Array<Number>
+    /// NumberNullableArray           // Array<Nullable<Number>>
+    /// NullableNumber                // Nullable<Number>
+    /// NullableNumberArray           // Nullable<Array<Number>>
+    /// NumberMap                     // Map<Number>
+    /// MyUnionUnion                  // Union
+    /// NumberStringCallback          // Function (eg. `(a: number, b: string) => void`)
+    /// VoidCallback                  // Function (eg. `() => void`)
+    /// NumberStream                  // Stream (eg. `AsyncIterable<number>`)
+    /// ```
+    pub fn as_rs_struct_name(&self) -> Result<String, anyhow::Error> {
+        let name = match self {
+            TypeAnnotation::Boolean => "Boolean".to_string(),
+            TypeAnnotation::Number => "Number".to_string(),
+            TypeAnnotation::String | TypeAnnotation::StringLiteral(..) => "String".to_string(),
+            TypeAnnotation::ArrayBuffer => "ArrayBuffer".to_string(),
+            TypeAnnotation::BigInt => "BigInt".to_string(),
+            TypeAnnotation::BigUint64 => "BigUint64".to_string(),
+            TypeAnnotation::TypedArray(typed_array) => typed_array.name().to_string(),
+            TypeAnnotation::Object(ObjectTypeAnnotation { name, .. }) => name.clone(),
+            TypeAnnotation::Enum(EnumTypeAnnotation { name, .. }) => name.clone(),
+            TypeAnnotation::Ref(RefTypeAnnotation { name, .. }) => name.clone(),
+            TypeAnnotation::Array(element_type) => match &**element_type {
+                TypeAnnotation::Nullable(type_annotation) => {
+                    format!("{}NullableArray", type_annotation.as_rs_struct_name()?)
+                }
+                _ => format!("{}Array", element_type.as_rs_struct_name()?),
+            },
+            TypeAnnotation::Map(value_type) => match &**value_type {
+                TypeAnnotation::Nullable(type_annotation) => {
+                    format!("{}NullableMap", type_annotation.as_rs_struct_name()?)
+                }
+                _ => format!("{}Map", value_type.as_rs_struct_name()?),
+            },
+            TypeAnnotation::Nullable(type_annotation) => {
+                format!("Nullable{}", type_annotation.as_rs_struct_name()?)
+            }
+            TypeAnnotation::Union(UnionTypeAnnotation { name, .. }) => format!("{name}Union"),
+            TypeAnnotation::Function(FunctionTypeAnnotation { params }) => {
+                if params.is_empty() {
+                    "VoidCallback".to_string()
+                } else {
+                    let param_names = params
+                        .iter()
+                        .map(|param| param.as_rs_struct_name())
+                        .collect::<Result<Vec<_>, _>>()?;
+                    format!("{}Callback", param_names.join(""))
+                }
+            }
+            TypeAnnotation::Stream(item_type) => {
+                format!("{}Stream", item_type.as_rs_struct_name()?)
+            }
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "[as_rs_struct_name] Unsupported type annotation: {:?}",
+                    self
+                ))
+            }
+        };
+
+        Ok(name)
+    }
+
+    /// Returns `true` if the array element (or map value) is wrapped with a generated struct.
+    ///
+    /// The nested arrays and the binary types (`Vec<T>`) are wrapped since cxx does not support `Vec<Vec<T>>`.
+    pub fn is_rs_wrapped_element(&self) -> bool {
+        matches!(
+            self,
+            TypeAnnotation::Array(..)
+                | TypeAnnotation::ArrayBuffer
+                | TypeAnnotation::TypedArray(..)
+        )
+    }
+
+    /// Returns the name of the generated struct of the type.
+    ///
+    /// # Generated Code Examples
+    ///
+    /// ```rust,ignore
+    /// NumberArray                   // Array<Number>
+    /// ArrayBufferItem               // ArrayBuffer (array element or map value)
+    /// Uint8ArrayItem                // Uint8Array (array element or map value)
+    /// NullableNumber                // Nullable<Number>
+    /// ```
+    pub fn as_rs_generated_struct_name(&self) -> Result<String, anyhow::Error> {
+        match self {
+            // The binary types are aliased with `Vec<T>` (eg. `ArrayBuffer`), so the wrapper is suffixed
+            TypeAnnotation::ArrayBuffer | TypeAnnotation::TypedArray(..) => {
+                Ok(format!("{}Item", self.as_rs_struct_name()?))
+            }
+            _ => self.as_rs_struct_name(),
+        }
+    }
+
     /// Converts TypeAnnotation to Rust FFI bridge type for cxx extern.
     ///
     /// # Generated Code Examples
@@ -220,24 +305,30 @@ impl TypeAnnotation {
     /// Number           // Number (aliased f64)
     /// String           // String
     /// ArrayBuffer      // ArrayBuffer (aliased Vec<u8>)
+    /// BigInt           // BigInt (aliased i64)
+    /// BigUint64        // BigUint64 (aliased u64)
+    /// Float32Array     // Float32Array (aliased Vec<f32>)
     /// Array<Number>    // Array<Number>
     /// Promise<Number>  // Promise<Number>
     /// Nullable<Number> // Nullable<Number>
+    /// Map<Number>      // Map<Number>
+    /// MyUnion          // Union
+    /// Callback<Number> // Function (eg. `(p: number) => void`)
+    /// Callback<(Number, String)> // Function (eg. `(a: number, b: string) => void`)
+    /// Stream<Number>   // Stream (eg. `AsyncIterable<number>`)
+    /// AbortSignal      // AbortSignal
     /// ```
     pub fn as_rs_impl_type(&self) -> Result<RsImplType, anyhow::Error> {
         let rs_type = match self {
             TypeAnnotation::Void => "Void".to_string(),
             TypeAnnotation::Boolean => "Boolean".to_string(),
             TypeAnnotation::Number => "Number".to_string(),
-            TypeAnnotation::String => "String".to_string(),
+            TypeAnnotation::String | TypeAnnotation::StringLiteral(..) => "String".to_string(),
             TypeAnnotation::ArrayBuffer => "ArrayBuffer".to_string(),
+            TypeAnnotation::BigInt => "BigInt".to_string(),
+            TypeAnnotation::BigUint64 => "BigUint64".to_string(),
+            TypeAnnotation::TypedArray(typed_array) => typed_array.name().to_string(),
             TypeAnnotation::Array(element_type) => {
-                if let TypeAnnotation::Array { .. } = &**element_type {
-                    return Err(anyhow::anyhow!(
-                        "Nested array type is not supported: {:?}",
-                        element_type
-                    ));
-                }
                 format!("Array<{}>", element_type.as_rs_impl_type()?.into_code())
             }
             TypeAnnotation::Object(ObjectTypeAnnotation { name, .. }) => name.clone(),
@@ -249,7 +340,29 @@ impl TypeAnnotation {
                 let type_annotation = type_annotation.as_rs_impl_type()?.into_code();
                 format!("Nullable<{type_annotation}>")
             }
-            TypeAnnotation::Ref(..) => unreachable!(),
+            TypeAnnotation::Map(value_type) => {
+                format!("Map<{}>", value_type.as_rs_impl_type()?.into_code())
+            }
+            TypeAnnotation::Union(UnionTypeAnnotation { name, .. }) => name.clone(),
+            TypeAnnotation::Function(FunctionTypeAnnotation { params }) => {
+                let params = params
+                    .iter()
+                    .map(|param| param.as_rs_impl_type().map(|t| t.into_code()))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                // Multiple parameters are passed as a tuple
+                match params.as_slice() {
+                    [param] => format!("Callback<{param}>"),
+                    _ => format!("Callback<({})>", params.join(", ")),
+                }
+            }
+            TypeAnnotation::Stream(item_type) => {
+                format!("Stream<{}>", item_type.as_rs_impl_type()?.into_code())
+            }
+            TypeAnnotation::AbortSignal => "AbortSignal".to_string(),
+            TypeAnnotation::Ref(RefTypeAnnotation { name, .. }) => {
+                anyhow::bail!("Unresolved type reference: {}", name)
+            }
         };
         Ok(RsImplType(rs_type))
     }
@@ -261,27 +374,35 @@ impl TypeAnnotation {
     /// ```rust,ignore
     /// false                         // Boolean
     /// 0.0                           // Number
+    /// 0                             // BigInt
     /// String::default()             // String
+    /// String::from("foo")           // StringLiteral
     /// Vec::default()                // Array
     /// MyEnum::default()             // Enum
     /// MyStruct::default()           // Object
     /// NullableNumber::default()     // Nullable<Number>
+    /// NumberMap::default()          // Map<Number>
+    /// MyUnionUnion::default()       // Union
     /// ```
     pub fn as_rs_default_val(&self) -> Result<String, anyhow::Error> {
         let default_val = match self {
             TypeAnnotation::Boolean => "false".to_string(),
             TypeAnnotation::Number => "0.0".to_string(),
+            TypeAnnotation::BigInt | TypeAnnotation::BigUint64 => "0".to_string(),
             TypeAnnotation::String => "String::default()".to_string(),
-            TypeAnnotation::ArrayBuffer | TypeAnnotation::Array(..) => "Vec::default()".to_string(),
+            TypeAnnotation::StringLiteral(value) => format!("String::from({value:?})"),
+            TypeAnnotation::ArrayBuffer
+            | TypeAnnotation::TypedArray(..)
+            | TypeAnnotation::Array(..) => "Vec::default()".to_string(),
             TypeAnnotation::Enum(EnumTypeAnnotation { name, .. }) => {
                 format!("{name}::default()")
             }
             TypeAnnotation::Object(ObjectTypeAnnotation { name, .. }) => {
                 format!("{name}::default()")
             }
-            TypeAnnotation::Nullable(..) => {
-                let nullable_type = self.as_rs_type()?.into_code();
-                format!("{nullable_type}::default()")
+            TypeAnnotation::Nullable(..) | TypeAnnotation::Map(..) | TypeAnnotation::Union(..) => {
+                let struct_type = self.as_rs_type()?.into_code();
+                format!("{struct_type}::default()")
             }
             _ => {
                 return Err(anyhow::anyhow!(
@@ -293,6 +414,148 @@ impl TypeAnnotation {
 
         Ok(default_val)
     }
+
+    /// Returns `true` if the FFI bridge type differs from the implementation type.
+    ///
+    /// eg. `NullableNumber` (bridge) and `Nullable<Number>` (implementation)
+    pub fn is_rs_convertible(&self) -> bool {
+        match self {
+            TypeAnnotation::Nullable(..)
+            | TypeAnnotation::Map(..)
+            | TypeAnnotation::Union(..)
+            | TypeAnnotation::Function(..)
+            | TypeAnnotation::Stream(..)
+            | TypeAnnotation::AbortSignal => true,
+            TypeAnnotation::Array(element_type) => {
+                element_type.is_rs_wrapped_element() || element_type.is_rs_convertible()
+            }
+            TypeAnnotation::Promise(resolved_type) => resolved_type.is_rs_convertible(),
+            _ => false,
+        }
+    }
+
+    /// Generates the expression that converts the value between the FFI bridge type and the implementation type.
+    ///
+    /// # Generated Code Examples
+    ///
+    /// ```rust,ignore
+    /// val                                        // Number
+    /// val.into()                                 // Nullable<Number>
+    /// val.into_iter().map(Into::into).collect()  // Array<Array<Number>>, Array<Uint8Array>, Array<Nullable<Number>>
+    /// val.map(|val| val.into())                  // Promise<Nullable<Number>>
+    /// Callback::new(move |val_0: Number| val.call(val_0)) // Function (eg. `(p: number) => void`)
+    /// Stream::new(move |event: StreamEvent<Number>| match event { ... }) // Stream (eg. `AsyncIterable<number>`)
+    /// val.0.clone()                              // AbortSignal
+    /// ```
+    pub fn as_rs_convert_expr(&self, ident: &str) -> Result<String, anyhow::Error> {
+        if !self.is_rs_convertible() {
+            return Ok(ident.to_string());
+        }
+
+        let expr = match self {
+            TypeAnnotation::Array(..) => format!("{ident}.into_iter().map(Into::into).collect()"),
+            TypeAnnotation::Promise(resolved_type) => {
+                format!(
+                    "{ident}.map(|val| {})",
+                    resolved_type.as_rs_convert_expr("val")?
+                )
+            }
+            TypeAnnotation::Function(FunctionTypeAnnotation { params }) => {
+                let vals = (0..params.len())
+                    .map(|idx| format!("val_{idx}"))
+                    .collect::<Vec<_>>();
+                let call_args = params
+                    .iter()
+                    .zip(&vals)
+                    .map(|(param, val)| param.as_rs_convert_expr(val))
+                    .collect::<Result<Vec<_>, _>>()?
+                    .join(", ");
+                let impl_types = params
+                    .iter()
+                    .map(|param| param.as_rs_impl_type().map(|t| t.into_code()))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                // The closure parameter types are annotated since they cannot be inferred from the tuple pattern
+                let closure_args = match (vals.as_slice(), impl_types.as_slice()) {
+                    ([], _) => "()".to_string(),
+                    ([val], [impl_type]) => format!("{val}: {impl_type}"),
+                    _ => format!("({}): ({})", vals.join(", "), impl_types.join(", ")),
+                };
+
+                format!("Callback::new(move |{closure_args}| {ident}.call({call_args}))")
+            }
+            TypeAnnotation::Stream(item_type) => {
+                let impl_type = item_type.as_rs_impl_type()?.into_code();
+                let item = item_type.as_rs_convert_expr("val")?;
+
+                format!(
+                    "Stream::new(move |event: StreamEvent<{impl_type}>| match event {{ \
+                    StreamEvent::Item(val) => {ident}.send({item}), \
+                    StreamEvent::Close => {ident}.close(), \
+                    StreamEvent::Error(message) => {ident}.error(&message) }})"
+                )
+            }
+            TypeAnnotation::AbortSignal => format!("{ident}.0.clone()"),
+            _ => format!("{ident}.into()"),
+        };
+
+        Ok(expr)
+    }
+
+    /// Collects the types that require a generated FFI struct (nullable, nested array, binary element, map and union types).
+    ///
+    /// The inner types are collected before the outer types.
+    pub fn collect_rs_struct_types<'a>(&'a self, types: &mut Vec<&'a TypeAnnotation>) {
+        match self {
+            TypeAnnotation::Nullable(type_annotation) => {
+                type_annotation.collect_rs_struct_types(types);
+                types.push(self);
+            }
+            TypeAnnotation::Array(element_type) => {
+                element_type.collect_rs_struct_types(types);
+                if element_type.is_rs_wrapped_element() {
+                    types.push(element_type);
+                }
+            }
+            TypeAnnotation::Map(value_type) => {
+                value_type.collect_rs_struct_types(types);
+                if value_type.is_rs_wrapped_element() {
+                    types.push(value_type);
+                }
+                types.push(self);
+            }
+            TypeAnnotation::Promise(resolved_type) | TypeAnnotation::Stream(resolved_type) => {
+                resolved_type.collect_rs_struct_types(types)
+            }
+            TypeAnnotation::Union(..) => types.push(self),
+            TypeAnnotation::Function(FunctionTypeAnnotation { params }) => {
+                for param in params {
+                    param.collect_rs_struct_types(types);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl TypedArrayType {
+    /// Returns the Rust element type of the typed array.
+    ///
+    /// eg. `u8` (Uint8Array), `f32` (Float32Array)
+    pub fn as_rs_element_type(&self) -> &'static str {
+        match self {
+            TypedArrayType::Int8 => "i8",
+            TypedArrayType::Uint8 => "u8",
+            TypedArrayType::Int16 => "i16",
+            TypedArrayType::Uint16 => "u16",
+            TypedArrayType::Int32 => "i32",
+            TypedArrayType::Uint32 => "u32",
+            TypedArrayType::Float32 => "f32",
+            TypedArrayType::Float64 => "f64",
+            TypedArrayType::BigInt64 => "i64",
+            TypedArrayType::BigUint64 => "u64",
+        }
+    }
 }
 
 impl Method {
@@ -303,18 +566,33 @@ impl Method {
     /// ```rust,ignore
     /// fn multiply(&mut self, a: Number, b: Number) -> Number
     /// fn add_async(&mut self, a: Number, b: Number) -> Promise<Number>
+    /// fn read_chunks(&mut self, path: &str, stream: Stream<ArrayBuffer>)
+    /// fn read_sync(&mut self, path: &str) -> Result<String> // `@throws`
     /// ```
     pub fn try_into_impl_sig(&self) -> Result<String, anyhow::Error> {
-        let return_type = self.ret_type.as_rs_impl_type()?.into_code();
-        let params_sig = std::iter::once("&mut self".to_string())
+        let mut params_sig = std::iter::once("&mut self".to_string())
             .chain(
                 self.params
                     .iter()
                     .map(|param| param.try_into_impl_sig())
                     .collect::<Result<Vec<_>, _>>()?,
             )
-            .collect::<Vec<_>>()
-            .join(", ");
+            .collect::<Vec<_>>();
+
+        // The items are pushed through the trailing stream handle instead of the return value
+        let return_type = match &self.ret_type {
+            TypeAnnotation::Stream(..) => {
+                let stream_type = self.ret_type.as_rs_impl_type()?.into_code();
+                params_sig.push(format!("{RESERVED_ARG_NAME_STREAM}: {stream_type}"));
+                "()".to_string()
+            }
+            // The synchronous method of the `@throws` tag returns the error instead of panicking
+            ret_type if self.is_fallible_sync() => {
+                format!("Result<{}>", ret_type.as_rs_impl_type()?.into_code())
+            }
+            ret_type => ret_type.as_rs_impl_type()?.into_code(),
+        };
+        let params_sig = params_sig.join(", ");
 
         let fn_name = snake_case(&self.name);
         let ret_annotation = if return_type == "()" {
@@ -325,6 +603,51 @@ impl Method {
 
         Ok(format!("fn {fn_name}({params_sig}){ret_annotation}"))
     }
+
+    /// Returns `true` if the synchronous method is tagged with `@throws`.
+    pub fn is_fallible_sync(&self) -> bool {
+        self.throws.is_some() && !matches!(self.ret_type, TypeAnnotation::Promise(..))
+    }
+
+    /// Returns the deprecation attribute if the method is tagged with `@deprecated`.
+    ///
+    /// # Generated Code
+    ///
+    /// ```rust,ignore
+    /// #[deprecated(note = "Use `multiply` instead")]
+    /// ```
+    pub fn as_rs_deprecated_attr(&self) -> Option<String> {
+        self.deprecated.as_ref().map(|reason| {
+            if reason.is_empty() {
+                "#[deprecated]".to_string()
+            } else {
+                format!("#[deprecated(note = {reason:?})]")
+            }
+        })
+    }
+
+    /// Returns the default body of the deprecated method if the method returns a `Result`.
+    ///
+    /// The deprecated `Promise` and `@throws` methods may be dropped from the implementation,
+    /// and reject with the error when called. Other deprecated methods are still required.
+    ///
+    /// # Generated Code
+    ///
+    /// ```rust,ignore
+    /// Err(anyhow::anyhow!("`multiplyAsync` is deprecated and not implemented"))
+    /// ```
+    pub fn as_rs_deprecated_default_body(&self) -> Option<String> {
+        self.deprecated.as_ref()?;
+
+        if matches!(self.ret_type, TypeAnnotation::Promise(..)) || self.is_fallible_sync() {
+            Some(format!(
+                "Err(anyhow::anyhow!(\"`{}` is deprecated and not implemented\"))",
+                self.name
+            ))
+        } else {
+            None
+        }
+    }
 }
 
 impl Param {
@@ -336,12 +659,14 @@ impl Param {
     /// a: f64
     /// name: String
     /// items: Vec<MyStruct>
+    /// signal: &AbortController
     /// ```
     pub fn try_into_cxx_sig(&self) -> Result<String, anyhow::Error> {
-        let param_type = if let TypeAnnotation::String = &self.type_annotation {
-            "&str".to_string()
-        } else {
-            self.type_annotation.as_rs_type()?.into_code()
+        let param_type = match &self.type_annotation {
+            TypeAnnotation::String => "&str".to_string(),
+            // The controller is owned by the C++ side to abort the signal
+            TypeAnnotation::AbortSignal => "&AbortController".to_string(),
+            type_annotation => type_annotation.as_rs_type()?.into_code(),
         };
         Ok(format!("{}: {}", snake_case(&self.name), param_type))
     }
@@ -366,6 +691,85 @@ impl Param {
 }
 
 impl Schema {
+    /// Collects all types that require a generated FFI struct (nullable, nested array, map and union types)
+    /// from the methods, alias types and signal payloads.
+    pub fn collect_rs_struct_types(&self) -> Vec<&TypeAnnotation> {
+        let mut types = vec![];
+
+        for method_spec in &self.methods {
+            for param in &method_spec.params {
+                param.type_annotation.collect_rs_struct_types(&mut types);
+            }
+            method_spec.ret_type.collect_rs_struct_types(&mut types);
+        }
+
+        for type_annotation in &self.aliases {
+            for prop in &type_annotation.as_object().unwrap().props {
+                prop.type_annotation.collect_rs_struct_types(&mut types);
+            }
+        }
+
+        for signal in &self.signals {
+            if let Some(payload_type) = &signal.payload_type {
+                payload_type.collect_rs_struct_types(&mut types);
+            }
+        }
+
+        let mut ids = FxHashSet::default();
+        types.retain(|type_annotation| ids.insert(type_annotation.to_id()));
+        types
+    }
+
+    /// Collects the stream types of the method return types.
+    pub fn collect_stream_types(&self) -> Vec<&TypeAnnotation> {
+        let mut types = self
+            .methods
+            .iter()
+            .map(|method_spec| &method_spec.ret_type)
+            .filter(|type_annotation| type_annotation.as_stream().is_some())
+            .collect::<Vec<_>>();
+
+        let mut ids = FxHashSet::default();
+        types.retain(|type_annotation| ids.insert(type_annotation.to_id()));
+        types
+    }
+
+    /// Collects the error code types of the `@throws` tags.
+    pub fn collect_error_code_types(&self) -> Vec<&TypeAnnotation> {
+        let mut types = self
+            .methods
+            .iter()
+            .filter_map(|method_spec| method_spec.throws.as_ref())
+            .collect::<Vec<_>>();
+
+        let mut ids = FxHashSet::default();
+        types.retain(|type_annotation| ids.insert(type_annotation.to_id()));
+        types
+    }
+
+    /// Returns `true` if any method has the `AbortSignal` parameter.
+    pub fn has_abort_signal(&self) -> bool {
+        self.methods
+            .iter()
+            .flat_map(|method_spec| &method_spec.params)
+            .any(|param| param.type_annotation.is_abort_signal())
+    }
+
+    /// Collects the callback function types of the method parameters.
+    pub fn collect_callback_types(&self) -> Vec<&TypeAnnotation> {
+        let mut types = self
+            .methods
+            .iter()
+            .flat_map(|method_spec| &method_spec.params)
+            .map(|param| &param.type_annotation)
+            .filter(|type_annotation| type_annotation.as_function().is_some())
+            .collect::<Vec<_>>();
+
+        let mut ids = FxHashSet::default();
+        types.retain(|type_annotation| ids.insert(type_annotation.to_id()));
+        types
+    }
+
     /// Generates complete Rust FFI bridge including externs, structs, enums, and implementations.
     ///
     /// # Generated Code
@@ -391,8 +795,17 @@ impl Schema {
     ///         ret
     ///     })
     /// }
+    ///
+    /// // Implementation (`panic = "abort"`):
+    /// fn my_module_multiply(it_: &mut MyModule, a: f64, b: f64) -> Result<f64> {
+    ///     let ret = it_.multiply(a, b);
+    ///     Ok(ret)
+    /// }
     /// ```
-    pub fn as_rs_cxx_bridge(&self) -> Result<RsCxxBridge, anyhow::Error> {
+    pub fn as_rs_cxx_bridge(
+        &self,
+        panic_strategy: PanicStrategy,
+    ) -> Result<RsCxxBridge, anyhow::Error> {
         let module_name = pascal_case(&self.module_name);
         let snake_module_name = snake_case(&self.module_name);
 
@@ -407,45 +820,44 @@ impl Schema {
             fn create_{snake_module_name}(id: usize, data_path: &str) -> Box<{module_name}>;"#,
         });
 
+        // The panics are reported by the panic hook instead of `catch_unwind`
+        let set_hook_stmt = match panic_strategy {
+            PanicStrategy::Unwind => "",
+            PanicStrategy::Abort => "\n    craby::panic::set_hook(report_panic);",
+        };
+
         func_impls.push(formatdoc! {
             r#"
-            fn create_{snake_module_name}(id: usize, data_path: &str) -> Box<{module_name}> {{
+            fn create_{snake_module_name}(id: usize, data_path: &str) -> Box<{module_name}> {{{set_hook_stmt}
                 let ctx = Context::new(id, data_path);
                 Box::new({module_name}::new(ctx))
             }}"#,
         });
 
-        // Collect extern function signatures and implementations
-        for method_spec in &self.methods {
-            // Collect nullable parameters
-            for param in &method_spec.params {
-                if param.type_annotation.is_nullable() {
-                    let id = param.type_annotation.to_id();
-                    if let HashMapEntry::Vacant(e) = struct_defs.entry(id) {
-                        let nullable = RsNullableStruct::try_from(&param.type_annotation)?;
-                        e.insert(nullable.definition);
-                        type_impls.push(nullable.implementation);
-                    }
-                }
-            }
-
-            // Collect nullable return type
-            if method_spec.ret_type.is_nullable() {
-                let id = method_spec.ret_type.to_id();
-                if let HashMapEntry::Vacant(e) = struct_defs.entry(id) {
-                    let nullable = RsNullableStruct::try_from(&method_spec.ret_type)?;
-                    e.insert(nullable.definition);
-                    type_impls.push(nullable.implementation);
-                }
+        // Collect generated structs (nullable and nested array types)
+        for type_annotation in self.collect_rs_struct_types() {
+            if let HashMapEntry::Vacant(e) = struct_defs.entry(type_annotation.to_id()) {
+                let generated_struct = try_into_generated_struct(type_annotation)?;
+                e.insert(generated_struct.definition);
+                type_impls.push(generated_struct.implementation);
             }
+        }
 
-            let ret_type = method_spec.ret_type.as_rs_type()?.into_code();
-            let ret_type = match method_spec.ret_type {
+        // Collect extern function signatures and implementations
+        for method_spec in &self.methods {
+            // The stream methods push the items through the trailing stream handle and return nothing
+            let void_type = TypeAnnotation::Void;
+            let ret_type_annotation = match &method_spec.ret_type {
+                TypeAnnotation::Stream(..) => &void_type,
+                ret_type => ret_type,
+            };
+            let ret_type = ret_type_annotation.as_rs_type()?.into_code();
+            let ret_type = match ret_type_annotation {
                 TypeAnnotation::Promise(_) => ret_type,
                 _ => format!("Result<{ret_type}, anyhow::Error>"),
             };
-            let ret_extern_type = method_spec.ret_type.as_rs_bridge_type()?.into_code();
-            let ret_extern_type = match method_spec.ret_type {
+            let ret_extern_type = ret_type_annotation.as_rs_bridge_type()?.into_code();
+            let ret_extern_type = match ret_type_annotation {
                 TypeAnnotation::Promise(_) => ret_extern_type,
                 _ => format!("Result<{ret_extern_type}>"),
             };
@@ -455,7 +867,7 @@ impl Schema {
                 .iter()
                 .map(|param| param.try_into_cxx_sig())
                 .collect::<Result<Vec<_>, _>>()
-                .map(|mut params| {
+                .and_then(|mut params| {
                     params.insert(
                         0,
                         format!(
@@ -463,23 +875,47 @@ impl Schema {
                             pascal_case(&self.module_name)
                         ),
                     );
-                    params.join(", ")
+                    if let TypeAnnotation::Stream(..) = &method_spec.ret_type {
+                        params.push(format!(
+                            "{RESERVED_ARG_NAME_STREAM}: {}",
+                            method_spec.ret_type.as_rs_type()?.into_code()
+                        ));
+                    }
+                    Ok(params.join(", "))
                 })?;
 
             let mod_name = snake_case(&self.module_name);
             let fn_name = snake_case(&method_spec.name);
-            let fn_args = method_spec
+            let mut fn_args = method_spec
                 .params
                 .iter()
                 .map(|param| {
-                    let name = snake_case(&param.name);
-                    if let TypeAnnotation::Nullable(..) = &param.type_annotation {
-                        format!("{name}.into()")
-                    } else {
-                        name
-                    }
+                    param
+                        .type_annotation
+                        .as_rs_convert_expr(&snake_case(&param.name))
                 })
-                .collect::<Vec<_>>();
+                .collect::<Result<Vec<_>, _>>()?;
+
+            // The stream handle is kept outside of `catch_panic!`, so the panic rejects the iteration
+            // with its message instead of completing it when the handle is dropped while unwinding
+            let mut stream_stmt = String::new();
+            let mut stream_err = String::new();
+            if let TypeAnnotation::Stream(..) = &method_spec.ret_type {
+                let stream = method_spec
+                    .ret_type
+                    .as_rs_convert_expr(RESERVED_ARG_NAME_STREAM)?;
+
+                match panic_strategy {
+                    PanicStrategy::Unwind => {
+                        stream_stmt = format!("let {RESERVED_ARG_NAME_STREAM} = {stream};\n    ");
+                        stream_err = format!(
+                            ".map_err(|err| {{ {RESERVED_ARG_NAME_STREAM}.error(err.to_string()); err }})"
+                        );
+                        fn_args.push(format!("{RESERVED_ARG_NAME_STREAM}.clone()"));
+                    }
+                    PanicStrategy::Abort => fn_args.push(stream),
+                }
+            }
 
             let cxx_extern_fn_name = camel_case(&method_spec.name);
             let prefixed_fn_name = format!("{mod_name}_{fn_name}");
@@ -491,31 +927,57 @@ impl Schema {
                 fn {prefixed_fn_name}({params_sig}){ret_extern_annotation};"#,
             };
 
-            let ret = if let TypeAnnotation::Nullable(..) = &method_spec.ret_type {
-                "ret.into()"
+            // The error of the fallible method is encoded with the error code
+            let fallible = method_spec.is_fallible_sync()
+                || matches!(ret_type_annotation, TypeAnnotation::Promise(_));
+            let ret = if method_spec.is_fallible_sync() {
+                TypeAnnotation::Promise(Box::new(ret_type_annotation.clone()))
+                    .as_rs_convert_expr("ret")?
             } else {
-                "ret"
+                ret_type_annotation.as_rs_convert_expr("ret")?
             };
 
+            // Calling the deprecated method is intended here
+            let allow_attr = if method_spec.deprecated.is_some() {
+                "#[allow(deprecated)]\n"
+            } else {
+                ""
+            };
             let fn_args = fn_args.join(", ");
-            let impl_func = match method_spec.ret_type {
-                TypeAnnotation::Promise(_) => formatdoc! {
+            let impl_func = match (panic_strategy, fallible) {
+                (PanicStrategy::Unwind, true) => formatdoc! {
                     r#"
-                    fn {prefixed_fn_name}({params_sig}){ret_annotation} {{
+                    {allow_attr}fn {prefixed_fn_name}({params_sig}){ret_annotation} {{
                         craby::catch_panic!({{
                             let ret = {it}.{fn_name}({fn_args});
                             {ret}
-                        }}).and_then(|r| r)
+                        }}).and_then(|r| r).map_err(craby::error::encode)
                     }}"#,
                     it = RESERVED_ARG_NAME_MODULE,
                 },
-                _ => formatdoc! {
+                (PanicStrategy::Unwind, false) => formatdoc! {
                     r#"
-                    fn {prefixed_fn_name}({params_sig}){ret_annotation} {{
-                        craby::catch_panic!({{
+                    {allow_attr}fn {prefixed_fn_name}({params_sig}){ret_annotation} {{
+                        {stream_stmt}craby::catch_panic!({{
                             let ret = {it}.{fn_name}({fn_args});
                             {ret}
-                        }})
+                        }}){stream_err}
+                    }}"#,
+                    it = RESERVED_ARG_NAME_MODULE,
+                },
+                (PanicStrategy::Abort, true) => formatdoc! {
+                    r#"
+                    {allow_attr}fn {prefixed_fn_name}({params_sig}){ret_annotation} {{
+                        let ret = {it}.{fn_name}({fn_args});
+                        {ret}.map_err(craby::error::encode)
+                    }}"#,
+                    it = RESERVED_ARG_NAME_MODULE,
+                },
+                (PanicStrategy::Abort, false) => formatdoc! {
+                    r#"
+                    {allow_attr}fn {prefixed_fn_name}({params_sig}){ret_annotation} {{
+                        let ret = {it}.{fn_name}({fn_args});
+                        Ok({ret})
                     }}"#,
                     it = RESERVED_ARG_NAME_MODULE,
                 },
@@ -532,16 +994,6 @@ impl Schema {
                 let obj = type_annotation.as_object().unwrap();
                 e.insert(RsStruct::try_from(obj)?.into_code());
 
-                for prop in &obj.props {
-                    if prop.type_annotation.is_nullable() {
-                        let id = prop.type_annotation.to_id();
-                        if let HashMapEntry::Vacant(e) = struct_defs.entry(id) {
-                            let nullable = RsNullableStruct::try_from(&prop.type_annotation)?;
-                            e.insert(nullable.definition);
-                        }
-                    }
-                }
-
                 // Collect default implementations for the alias type
                 let mut type_impls_map = BTreeMap::new();
                 collect_alias_default_impls(id, obj, &mut type_impls_map)?;
@@ -564,15 +1016,16 @@ impl Schema {
                 let members = enum_schema
                     .members
                     .iter()
-                    .map(|m| format!("{},", m.name))
+                    .map(|m| format!("{}{},", m.doc.to_comment("///"), m.name))
                     .collect::<Vec<_>>();
 
                 let members = indent_str(&members.join("\n"), 4);
                 formatdoc! {
                     r#"
-                    enum {name} {{
+                    {doc}enum {name} {{
                     {members}
                     }}"#,
+                    doc = enum_schema.doc.to_comment("///"),
                     name = enum_schema.name,
                 }
             })
@@ -611,26 +1064,10 @@ impl Schema {
         &self,
         type_impls: &mut BTreeMap<u64, String>,
     ) -> Result<(), anyhow::Error> {
-        // Collect extern function signatures and implementations
-        for method_spec in &self.methods {
-            for param in &method_spec.params {
-                // Collect nullable parameters
-                if param.type_annotation.is_nullable() {
-                    let id = param.type_annotation.to_id();
-                    if let BTreeMapEntry::Vacant(e) = type_impls.entry(id) {
-                        let nullable = RsNullableStruct::try_from(&param.type_annotation)?;
-                        e.insert(nullable.implementation);
-                    }
-                }
-            }
-
-            // Collect nullable return type
-            if method_spec.ret_type.is_nullable() {
-                let id = method_spec.ret_type.to_id();
-                if let BTreeMapEntry::Vacant(e) = type_impls.entry(id) {
-                    let nullable = RsNullableStruct::try_from(&method_spec.ret_type)?;
-                    e.insert(nullable.implementation);
-                }
+        // Collect generated structs (nullable and nested array types)
+        for type_annotation in self.collect_rs_struct_types() {
+            if let BTreeMapEntry::Vacant(e) = type_impls.entry(type_annotation.to_id()) {
+                e.insert(try_into_generated_struct(type_annotation)?.implementation);
             }
         }
 
@@ -656,9 +1093,9 @@ impl Schema {
 }
 
 pub mod template {
-    use std::collections::{btree_map::Entry as BTreeMapEntry, BTreeMap};
+    use std::collections::BTreeMap;
 
-    use craby_common::utils::string::snake_case;
+    use craby_common::utils::string::{pascal_case, snake_case};
     use indoc::formatdoc;
 
     use crate::{
@@ -700,7 +1137,8 @@ pub mod template {
                 // baz: bool,
                 // ```
                 props.push(format!(
-                    "{}: {},",
+                    "{}{}: {},",
+                    prop.doc.to_comment("///"),
                     snake_case(&prop.name),
                     prop.type_annotation.as_rs_bridge_type()?.into_code()
                 ));
@@ -709,10 +1147,11 @@ pub mod template {
             let props = indent_str(&props.join("\n"), 4);
             let struct_def = formatdoc! {
                 r#"
-                #[derive(Clone)]
+                {doc}#[derive(Clone)]
                 struct {name} {{
                 {props}
                 }}"#,
+                doc = obj.doc.to_comment("///"),
                 name = obj.name,
             };
 
@@ -720,6 +1159,12 @@ pub mod template {
         }
     }
 
+    /// Rust struct definition and implementations for the generated types.
+    pub struct RsGeneratedStruct {
+        pub definition: String,
+        pub implementation: String,
+    }
+
     /// Rust struct definition for nullable types.
     pub struct RsNullableStruct {
         pub definition: String,
@@ -735,6 +1180,15 @@ pub mod template {
                 let base_type = type_annotation.as_rs_type()?.into_code();
                 let rs_impl_type = type_annotation.as_rs_impl_type()?.into_code();
                 let default_val = type_annotation.as_rs_default_val()?;
+                let into_impl_val = type_annotation.as_rs_convert_expr("val.val")?;
+                let into_bridge_val = if type_annotation.is_rs_convertible() {
+                    format!(
+                        "val.map(|val| {}).unwrap_or({default_val})",
+                        type_annotation.as_rs_convert_expr("val")?
+                    )
+                } else {
+                    format!("val.unwrap_or({default_val})")
+                };
 
                 let struct_def = formatdoc! {
                     r#"
@@ -758,7 +1212,7 @@ pub mod template {
 
                     impl From<{struct_type}> for Nullable<{rs_impl_type}> {{
                         fn from(val: {struct_type}) -> Self {{
-                            Nullable::new(if val.null {{ None }} else {{ Some(val.val) }})
+                            Nullable::new(if val.null {{ None }} else {{ Some({into_impl_val}) }})
                         }}
                     }}
     
@@ -767,7 +1221,7 @@ pub mod template {
                             let val = val.into_value();
                             let null = val.is_none();
                             {struct_type} {{
-                                val: val.unwrap_or({default_val}),
+                                val: {into_bridge_val},
                                 null,
                             }}
                         }}
@@ -784,6 +1238,353 @@ pub mod template {
         }
     }
 
+    /// Rust struct definition for nested array types (and the binary types in the arrays or maps).
+    ///
+    /// `Vec<Vec<T>>` is not supported by cxx, so the inner array is wrapped with this struct.
+    ///
+    /// # Generated Code
+    ///
+    /// ```rust,ignore
+    /// #[derive(Clone)]
+    /// struct NumberArray {
+    ///     val: Vec<f64>,
+    /// }
+    ///
+    /// impl From<NumberArray> for Array<Number> {
+    ///     fn from(val: NumberArray) -> Self {
+    ///         val.val
+    ///     }
+    /// }
+    ///
+    /// impl From<Array<Number>> for NumberArray {
+    ///     fn from(val: Array<Number>) -> Self {
+    ///         NumberArray { val }
+    ///     }
+    /// }
+    /// ```
+    pub struct RsArrayStruct {
+        pub definition: String,
+        pub implementation: String,
+    }
+
+    impl TryFrom<&TypeAnnotation> for RsArrayStruct {
+        type Error = anyhow::Error;
+
+        fn try_from(array_type: &TypeAnnotation) -> Result<Self, Self::Error> {
+            if array_type.is_rs_wrapped_element() {
+                let struct_type = array_type.as_rs_generated_struct_name()?;
+                let base_type = array_type.as_rs_type()?.into_code();
+                let rs_impl_type = array_type.as_rs_impl_type()?.into_code();
+                let into_impl_val = array_type.as_rs_convert_expr("val.val")?;
+                let into_bridge_field = if array_type.is_rs_convertible() {
+                    format!("val: {}", array_type.as_rs_convert_expr("val")?)
+                } else {
+                    "val".to_string()
+                };
+
+                let struct_def = formatdoc! {
+                    r#"
+                    #[derive(Clone)]
+                    struct {struct_type} {{
+                        val: {base_type},
+                    }}"#,
+                };
+
+                let struct_impl = formatdoc! {
+                    r#"
+                    impl From<{struct_type}> for {rs_impl_type} {{
+                        fn from(val: {struct_type}) -> Self {{
+                            {into_impl_val}
+                        }}
+                    }}
+
+                    impl From<{rs_impl_type}> for {struct_type} {{
+                        fn from(val: {rs_impl_type}) -> Self {{
+                            {struct_type} {{ {into_bridge_field} }}
+                        }}
+                    }}"#,
+                };
+
+                return Ok(RsArrayStruct {
+                    definition: struct_def,
+                    implementation: struct_impl,
+                });
+            }
+
+            anyhow::bail!("Not an array type: {:?}", array_type);
+        }
+    }
+
+    /// Rust struct definition for map types.
+    ///
+    /// The entries are stored as the key/value vectors since cxx does not support `HashMap`.
+    ///
+    /// # Generated Code
+    ///
+    /// ```rust,ignore
+    /// #[derive(Clone)]
+    /// struct NumberMap {
+    ///     keys: Vec<String>,
+    ///     vals: Vec<f64>,
+    /// }
+    ///
+    /// impl Default for NumberMap {
+    ///     fn default() -> Self {
+    ///         NumberMap {
+    ///             keys: Vec::default(),
+    ///             vals: Vec::default(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// impl From<NumberMap> for Map<Number> {
+    ///     fn from(val: NumberMap) -> Self {
+    ///         val.keys.into_iter().zip(val.vals).collect()
+    ///     }
+    /// }
+    ///
+    /// impl From<Map<Number>> for NumberMap {
+    ///     fn from(val: Map<Number>) -> Self {
+    ///         let (keys, vals) = val.into_iter().unzip();
+    ///         NumberMap { keys, vals }
+    ///     }
+    /// }
+    /// ```
+    pub struct RsMapStruct {
+        pub definition: String,
+        pub implementation: String,
+    }
+
+    impl TryFrom<&TypeAnnotation> for RsMapStruct {
+        type Error = anyhow::Error;
+
+        fn try_from(map_type: &TypeAnnotation) -> Result<Self, Self::Error> {
+            if let TypeAnnotation::Map(value_type) = map_type {
+                let struct_type = map_type.as_rs_struct_name()?;
+                let rs_impl_type = map_type.as_rs_impl_type()?.into_code();
+                let vals_type = TypeAnnotation::Array(value_type.clone());
+                let base_type = vals_type.as_rs_type()?.into_code();
+                let (into_impl_vals, into_bridge_entries) = if vals_type.is_rs_convertible() {
+                    (
+                        "val.vals.into_iter().map(Into::into)",
+                        "val.into_iter().map(|(key, val)| (key, val.into())).unzip()",
+                    )
+                } else {
+                    ("val.vals", "val.into_iter().unzip()")
+                };
+
+                let struct_def = formatdoc! {
+                    r#"
+                    #[derive(Clone)]
+                    struct {struct_type} {{
+                        keys: Vec<String>,
+                        vals: {base_type},
+                    }}"#,
+                };
+
+                let struct_impl = formatdoc! {
+                    r#"
+                    impl Default for {struct_type} {{
+                        fn default() -> Self {{
+                            {struct_type} {{
+                                keys: Vec::default(),
+                                vals: Vec::default(),
+                            }}
+                        }}
+                    }}
+
+                    impl From<{struct_type}> for {rs_impl_type} {{
+                        fn from(val: {struct_type}) -> Self {{
+                            val.keys.into_iter().zip({into_impl_vals}).collect()
+                        }}
+                    }}
+
+                    impl From<{rs_impl_type}> for {struct_type} {{
+                        fn from(val: {rs_impl_type}) -> Self {{
+                            let (keys, vals) = {into_bridge_entries};
+                            {struct_type} {{ keys, vals }}
+                        }}
+                    }}"#,
+                };
+
+                return Ok(RsMapStruct {
+                    definition: struct_def,
+                    implementation: struct_impl,
+                });
+            }
+
+            anyhow::bail!("Not a map type: {:?}", map_type);
+        }
+    }
+
+    /// Rust struct definition for discriminated union types.
+    ///
+    /// cxx does not support data-carrying enums, so the variants are stored as the struct fields
+    /// with the tag that indicates the active variant. The user-facing enum is generated in `generated.rs`.
+    ///
+    /// # Generated Code
+    ///
+    /// ```rust,ignore
+    /// // ffi.rs
+    /// #[derive(Clone)]
+    /// struct ShapeUnion {
+    ///     tag_: u8,
+    ///     circle: Circle,
+    ///     square: Square,
+    /// }
+    ///
+    /// // generated.rs
+    /// #[derive(Clone)]
+    /// pub enum Shape {
+    ///     Circle(Circle),
+    ///     Square(Square),
+    /// }
+    ///
+    /// impl From<ShapeUnion> for Shape {
+    ///     fn from(val: ShapeUnion) -> Self {
+    ///         match val.tag_ {
+    ///             0 => Shape::Circle(val.circle),
+    ///             _ => Shape::Square(val.square),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// impl From<Shape> for ShapeUnion {
+    ///     fn from(val: Shape) -> Self {
+    ///         match val {
+    ///             Shape::Circle(circle) => ShapeUnion { tag_: 0, circle, ..Default::default() },
+    ///             Shape::Square(square) => ShapeUnion { tag_: 1, square, ..Default::default() },
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub struct RsUnionStruct {
+        pub definition: String,
+        pub implementation: String,
+    }
+
+    impl TryFrom<&TypeAnnotation> for RsUnionStruct {
+        type Error = anyhow::Error;
+
+        fn try_from(union_type: &TypeAnnotation) -> Result<Self, Self::Error> {
+            if let TypeAnnotation::Union(union_spec) = union_type {
+                let struct_type = union_type.as_rs_struct_name()?;
+                let enum_type = &union_spec.name;
+                let last_idx = union_spec.variants.len().saturating_sub(1);
+
+                let mut fields = vec!["tag_: u8,".to_string()];
+                let mut default_fields = vec!["tag_: 0,".to_string()];
+                let mut members = vec![];
+                let mut into_impl_arms = vec![];
+                let mut into_bridge_arms = vec![];
+
+                for (idx, variant) in union_spec.variants.iter().enumerate() {
+                    let variant_type = variant.as_rs_type()?.into_code();
+                    let member = pascal_case(&variant_type);
+                    let field = snake_case(&variant_type);
+                    let pattern = if idx == last_idx {
+                        "_".to_string()
+                    } else {
+                        idx.to_string()
+                    };
+
+                    fields.push(format!("{field}: {variant_type},"));
+                    default_fields.push(format!("{field}: {},", variant.as_rs_default_val()?));
+                    members.push(format!("{member}({variant_type}),"));
+                    into_impl_arms
+                        .push(format!("{pattern} => {enum_type}::{member}(val.{field}),"));
+                    into_bridge_arms.push(format!(
+                        "{enum_type}::{member}({field}) => {struct_type} {{ tag_: {idx}, {field}, ..Default::default() }},"
+                    ));
+                }
+
+                let fields = indent_str(&fields.join("\n"), 4);
+                let default_fields = indent_str(&default_fields.join("\n"), 12);
+                let members = indent_str(&members.join("\n"), 4);
+                let into_impl_arms = indent_str(&into_impl_arms.join("\n"), 12);
+                let into_bridge_arms = indent_str(&into_bridge_arms.join("\n"), 12);
+
+                let struct_def = formatdoc! {
+                    r#"
+                    #[derive(Clone)]
+                    struct {struct_type} {{
+                    {fields}
+                    }}"#,
+                };
+
+                let struct_impl = formatdoc! {
+                    r#"
+                    #[derive(Clone)]
+                    pub enum {enum_type} {{
+                    {members}
+                    }}
+
+                    impl Default for {struct_type} {{
+                        fn default() -> Self {{
+                            {struct_type} {{
+                    {default_fields}
+                            }}
+                        }}
+                    }}
+
+                    impl From<{struct_type}> for {enum_type} {{
+                        fn from(val: {struct_type}) -> Self {{
+                            match val.tag_ {{
+                    {into_impl_arms}
+                            }}
+                        }}
+                    }}
+
+                    impl From<{enum_type}> for {struct_type} {{
+                        fn from(val: {enum_type}) -> Self {{
+                            match val {{
+                    {into_bridge_arms}
+                            }}
+                        }}
+                    }}"#,
+                };
+
+                return Ok(RsUnionStruct {
+                    definition: struct_def,
+                    implementation: struct_impl,
+                });
+            }
+
+            anyhow::bail!("Not a union type: {:?}", union_type);
+        }
+    }
+
+    /// Generates the struct definition and implementations for the generated types.
+    pub fn try_into_generated_struct(
+        type_annotation: &TypeAnnotation,
+    ) -> Result<RsGeneratedStruct, anyhow::Error> {
+        let (definition, implementation) = match type_annotation {
+            TypeAnnotation::Array(..)
+            | TypeAnnotation::ArrayBuffer
+            | TypeAnnotation::TypedArray(..) => {
+                let array = RsArrayStruct::try_from(type_annotation)?;
+                (array.definition, array.implementation)
+            }
+            TypeAnnotation::Map(..) => {
+                let map = RsMapStruct::try_from(type_annotation)?;
+                (map.definition, map.implementation)
+            }
+            TypeAnnotation::Union(..) => {
+                let union = RsUnionStruct::try_from(type_annotation)?;
+                (union.definition, union.implementation)
+            }
+            _ => {
+                let nullable = RsNullableStruct::try_from(type_annotation)?;
+                (nullable.definition, nullable.implementation)
+            }
+        };
+
+        Ok(RsGeneratedStruct {
+            definition,
+            implementation,
+        })
+    }
+
     /// Default implementation for struct types.
     ///
     /// # Generated Code
@@ -875,16 +1676,6 @@ pub mod template {
         obj: &ObjectTypeAnnotation,
         type_impls: &mut BTreeMap<u64, String>,
     ) -> Result<(), anyhow::Error> {
-        for prop in &obj.props {
-            if prop.type_annotation.is_nullable() {
-                let id = prop.type_annotation.to_id();
-                if let BTreeMapEntry::Vacant(e) = type_impls.entry(id) {
-                    let nullable = RsNullableStruct::try_from(&prop.type_annotation)?;
-                    e.insert(nullable.implementation);
-                }
-            }
-        }
-
         type_impls.insert(id, RsDefaultImpl::try_from(obj)?.into_code());
         Ok(())
     }
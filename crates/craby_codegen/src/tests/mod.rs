@@ -1,5 +1,7 @@
 use std::path::PathBuf;
 
+use craby_common::config::{PanicStrategy, ThreadPoolConfig};
+
 use crate::{parser::native_spec_parser::try_parse_schema, types::CodegenContext};
 
 pub fn get_codegen_context() -> CodegenContext {
@@ -8,7 +10,9 @@ pub fn get_codegen_context() -> CodegenContext {
         import type { NativeModule, Signal } from 'craby-modules';
         import { NativeModuleRegistry } from 'craby-modules';
 
+        /** Object with the various property types */
         export interface TestObject {
+            /** String property */
             foo: string;
             bar: number;
             baz: boolean;
@@ -16,17 +20,47 @@ pub fn get_codegen_context() -> CodegenContext {
             camelCase: number;
             PascalCase: number;
             snake_case: number;
+            matrix: number[][];
         }
 
         export type SubObject = {
             a: string | null;
             b: number;
             c: boolean;
+            d?: number;
         };
 
         export type MaybeNumber = number | null;
 
+        export type Constants = {
+            version: string;
+            maxCount: number;
+        };
+
+        export type Circle = {
+            kind: 'circle';
+            radius: number;
+        };
+
+        export type Square = {
+            kind: 'square';
+            size: number;
+        };
+
+        export type Shape = Circle | Square;
+
+        export type Mode = 'fast' | 'accurate';
+
+        export type Page<T> = {
+            items: T[];
+            cursor: string | null;
+        };
+
+        /**
+         * String enum
+         */
         export enum MyEnum {
+            /** The `foo` value */
             Foo = 'foo',
             Bar = 'bar',
             Baz = 'baz',
@@ -37,19 +71,68 @@ pub fn get_codegen_context() -> CodegenContext {
             On = 1,
         }
 
+        export enum ErrorCode {
+            NotFound = 'NOT_FOUND',
+            PermissionDenied = 'PERMISSION_DENIED',
+        }
+
         export interface Spec extends NativeModule {
+            getConstants(): Constants;
+            /**
+             * Returns the given number
+             *
+             * @param arg The number
+             * @returns The same number
+             */
             numericMethod(arg: number): number;
             booleanMethod(arg: boolean): boolean;
             stringMethod(arg: string): string;
             objectMethod(arg: TestObject): TestObject;
             arrayBufferMethod(arg: ArrayBuffer): ArrayBuffer;
+            bigintMethod(arg: bigint): bigint;
+            typedArrayMethod(arg0: Uint8Array, arg1: Float32Array): Int32Array;
+            bigUint64Method(arg: BigUint64): BigUint64;
+            wideTypedArrayMethod(arg0: Float64Array, arg1: BigInt64Array): BigUint64Array;
+            bytesCallbackMethod(callback: (bytes: Uint8Array) => void): void;
+            bytesStreamMethod(): AsyncIterable<Uint8Array>;
             arrayMethod(arg: number[]): number[];
+            nestedArrayMethod(arg: number[][]): string[][];
+            objectArrayMethod(arg: SubObject[]): Array<SubObject | null>;
+            nullableArrayMethod(arg: (number | null)[]): MyEnum[];
+            mapMethod(arg: Record<string, number>): { [key: string]: SubObject | null };
+            unionMethod(arg: Shape): Shape | null;
             enumMethod(arg0: MyEnum, arg1: SwitchState): string;
+            literalEnumMethod(arg: Mode): Mode;
             nullableMethod(arg: number | null): MaybeNumber;
             promiseMethod(arg: number): Promise<number>;
+            genericMethod(arg: Page<SubObject>): Page<number>;
+            callbackMethod(arg: number, onProgress: (progress: number) => void, onDone: () => void): Promise<void>;
+            objectCallbackMethod(callback: (obj: SubObject | null, message: string) => void): void;
+            streamMethod(arg: number): AsyncIterable<number>;
+            objectStreamMethod(): AsyncIterable<SubObject | null>;
+            abortableMethod(arg: number, signal: AbortSignal): Promise<string>;
+            optionalAbortableMethod(signal?: AbortSignal): Promise<void>;
+            /**
+             * @throws ErrorCode
+             */
+            throwingMethod(path: string): Promise<string>;
+            /**
+             * @throws ErrorCode
+             */
+            syncThrowingMethod(path: string): string;
+            optionalMethod(arg0: number, arg1?: string): number;
+            /**
+             * @deprecated Use `camelMethod` instead
+             */
+            deprecatedMethod(arg: number): Promise<number>;
+            /**
+             * @deprecated
+             */
+            deprecatedSyncMethod(arg: number): number;
             camelMethod(firstArg: number, secondArg: number): number;
             PascalMethod(FirstArg: number, SecondArg: number): number;
             snakeMethod(first_arg: number, second_arg: number): number;
+            /** Emitted without payload */
             onSignal: Signal;
         }
 
@@ -63,5 +146,151 @@ pub fn get_codegen_context() -> CodegenContext {
         root: PathBuf::from("."),
         schemas,
         android_package_name: "rs.craby.testmodule".to_string(),
+        panic_strategy: PanicStrategy::Unwind,
+        thread_pool: ThreadPoolConfig::default(),
+    }
+}
+
+/// Codegen context of the modules sharing the same types (eg. imported from the same module)
+pub fn get_shared_types_codegen_context() -> CodegenContext {
+    let schemas = try_parse_schema(
+        "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export type Shared = {
+            value: number | null;
+            kind: Kind;
+        };
+
+        export enum Kind {
+            Foo = 0,
+            Bar = 1,
+        }
+
+        export interface FooSpec extends NativeModule {
+            foo(arg: Shared): Shared | null;
+        }
+
+        export interface BarSpec extends NativeModule {
+            bar(arg: Shared | null): Kind;
+        }
+
+        export const Foo = NativeModuleRegistry.getEnforcing<FooSpec>('FooModule');
+        export const Bar = NativeModuleRegistry.getEnforcing<BarSpec>('BarModule');
+        ",
+    )
+    .unwrap();
+
+    CodegenContext {
+        project_name: "test_module".to_string(),
+        root: PathBuf::from("."),
+        schemas,
+        android_package_name: "rs.craby.testmodule".to_string(),
+        panic_strategy: PanicStrategy::Unwind,
+        thread_pool: ThreadPoolConfig::default(),
+    }
+}
+
+/// Codegen context of the module exchanging the arrays and maps of the binary types
+pub fn get_binary_arrays_codegen_context() -> CodegenContext {
+    let schemas = try_parse_schema(
+        "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface Spec extends NativeModule {
+            buffers(arg: ArrayBuffer[]): Uint8Array[];
+            nested(arg: Float32Array[][]): Record<string, ArrayBuffer>;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('BinaryModule');
+        ",
+    )
+    .unwrap();
+
+    CodegenContext {
+        project_name: "test_module".to_string(),
+        root: PathBuf::from("."),
+        schemas,
+        android_package_name: "rs.craby.testmodule".to_string(),
+        panic_strategy: PanicStrategy::Unwind,
+        thread_pool: ThreadPoolConfig::default(),
+    }
+}
+
+/// Codegen context of the modules emitting the signals (including the same signal names)
+pub fn get_signals_codegen_context() -> CodegenContext {
+    let schemas = try_parse_schema(
+        "
+        import type { NativeModule, Signal } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export type Progress = {
+            value: number;
+        };
+
+        export interface FooSpec extends NativeModule {
+            foo(): void;
+            onReady: Signal;
+            /**
+             * @throttle 16ms
+             * @latestOnly
+             */
+            onProgress: Signal<Progress>;
+        }
+
+        export interface BarSpec extends NativeModule {
+            bar(): void;
+            onReady: Signal;
+            /** @buffer 8 */
+            onMessage: Signal<string>;
+        }
+
+        export const Foo = NativeModuleRegistry.getEnforcing<FooSpec>('FooModule');
+        export const Bar = NativeModuleRegistry.getEnforcing<BarSpec>('BarModule');
+        ",
+    )
+    .unwrap();
+
+    CodegenContext {
+        project_name: "test_module".to_string(),
+        root: PathBuf::from("."),
+        schemas,
+        android_package_name: "rs.craby.testmodule".to_string(),
+        panic_strategy: PanicStrategy::Unwind,
+        thread_pool: ThreadPoolConfig::default(),
+    }
+}
+
+/// Codegen context of the module invoking the methods in the serial queues
+pub fn get_serial_codegen_context() -> CodegenContext {
+    let schemas = try_parse_schema(
+        "
+        import type { NativeModule } from 'craby-modules';
+        import { NativeModuleRegistry } from 'craby-modules';
+
+        export interface Spec extends NativeModule {
+            /** @serial storage */
+            save(value: string): Promise<void>;
+            /** @serial storage */
+            load(): Promise<string>;
+            /** @serial */
+            watch(): AsyncIterable<string>;
+            fetch(): Promise<string>;
+        }
+
+        export default NativeModuleRegistry.getEnforcing<Spec>('SerialModule');
+        ",
+    )
+    .unwrap();
+
+    CodegenContext {
+        project_name: "test_module".to_string(),
+        root: PathBuf::from("."),
+        schemas,
+        android_package_name: "rs.craby.testmodule".to_string(),
+        panic_strategy: PanicStrategy::Unwind,
+        thread_pool: ThreadPoolConfig::default(),
     }
 }
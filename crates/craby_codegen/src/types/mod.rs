@@ -1,7 +1,10 @@
 use std::{fmt::Display, hash::Hasher, path::PathBuf};
 
 use crate::parser::types::{Method, Signal, TypeAnnotation};
-use craby_common::utils::string::{flat_case, pascal_case};
+use craby_common::{
+    config::{PanicStrategy, ThreadPoolConfig},
+    utils::string::{flat_case, pascal_case},
+};
 use log::debug;
 use serde::Serialize;
 use xxhash_rust::xxh3::Xxh3;
@@ -11,6 +14,8 @@ pub struct CodegenContext {
     pub root: PathBuf,
     pub schemas: Vec<Schema>,
     pub android_package_name: String,
+    pub panic_strategy: PanicStrategy,
+    pub thread_pool: ThreadPoolConfig,
 }
 
 #[derive(Debug, Serialize)]
@@ -20,6 +25,9 @@ pub struct Schema {
     pub aliases: Vec<TypeAnnotation>,
     // `TypeAnnotation::EnumTypeAnnotation`
     pub enums: Vec<TypeAnnotation>,
+    // `TypeAnnotation::UnionTypeAnnotation`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub unions: Vec<TypeAnnotation>,
     pub methods: Vec<Method>,
     pub signals: Vec<Signal>,
 }
@@ -1,8 +1,9 @@
 use std::collections::{BTreeMap, BTreeSet};
 
 use crate::{
-    common::IntoCode,
-    parser::types::{EnumTypeAnnotation, ObjectTypeAnnotation, TypeAnnotation},
+    parser::types::{
+        EnumTypeAnnotation, ObjectTypeAnnotation, TypeAnnotation, UnionTypeAnnotation,
+    },
     types::Schema,
 };
 
@@ -20,6 +21,63 @@ pub fn indent_str(str: &str, indent_size: usize) -> String {
         .join("\n")
 }
 
+/// Returns the C++ string literal of the given string (eg. `"Use \"bar\" instead"`).
+///
+/// The control characters are escaped with the octal escape sequence, since the hex escape sequence
+/// consumes the following hex digits (eg. `\x1fab`). The other characters are kept as UTF-8.
+pub fn cxx_string_literal(str: &str) -> String {
+    let mut literal = String::with_capacity(str.len() + 2);
+    literal.push('"');
+    for c in str.chars() {
+        match c {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            c if c.is_ascii_control() => literal.push_str(&format!("\\{:03o}", c as u32)),
+            c => literal.push(c),
+        }
+    }
+    literal.push('"');
+    literal
+}
+
+/// Collects the names of the types that the given type directly depends on.
+fn collect_deps(
+    type_annotation: &TypeAnnotation,
+    deps: &mut Vec<String>,
+) -> Result<(), anyhow::Error> {
+    match type_annotation {
+        TypeAnnotation::Object(ObjectTypeAnnotation { name, .. })
+        | TypeAnnotation::Enum(EnumTypeAnnotation { name, .. }) => deps.push(name.clone()),
+        TypeAnnotation::Nullable(..) | TypeAnnotation::Map(..) | TypeAnnotation::Union(..) => {
+            deps.push(type_annotation.as_rs_struct_name()?)
+        }
+        TypeAnnotation::Array(element_type) => collect_element_deps(element_type, deps)?,
+        TypeAnnotation::Promise(resolved_type) => collect_deps(resolved_type, deps)?,
+        _ => (),
+    }
+
+    Ok(())
+}
+
+/// Collects the dependencies of the array element (or map value) type.
+///
+/// Nested arrays and binary types are wrapped with a generated struct.
+fn collect_element_deps(
+    element_type: &TypeAnnotation,
+    deps: &mut Vec<String>,
+) -> Result<(), anyhow::Error> {
+    if element_type.is_rs_wrapped_element() {
+        deps.push(element_type.as_rs_generated_struct_name()?);
+    } else {
+        collect_deps(element_type, deps)?;
+    }
+
+    Ok(())
+}
+
 pub fn calc_deps_order(schema: &Schema) -> Result<Vec<String>, anyhow::Error> {
     let mut dependencies = BTreeMap::new();
     let mut visited = BTreeSet::new();
@@ -28,54 +86,31 @@ pub fn calc_deps_order(schema: &Schema) -> Result<Vec<String>, anyhow::Error> {
 
     for type_annotation in &schema.aliases {
         let alias_spec = type_annotation.as_object().unwrap();
-
-        dependencies.insert(alias_spec.name.clone(), vec![]);
+        let mut deps = vec![];
 
         for prop in &alias_spec.props {
-            match &prop.type_annotation {
-                TypeAnnotation::Object(ObjectTypeAnnotation {
-                    name: alias_name, ..
-                }) => {
-                    dependencies
-                        .get_mut(&alias_spec.name)
-                        .unwrap()
-                        .push(alias_name.clone());
-                }
-                TypeAnnotation::Enum(EnumTypeAnnotation {
-                    name: enum_name, ..
-                }) => {
-                    dependencies
-                        .get_mut(&alias_spec.name)
-                        .unwrap()
-                        .push(enum_name.clone());
-                }
-                nullable @ TypeAnnotation::Nullable(type_annotation) => {
-                    let rs_type = nullable.as_rs_bridge_type()?.into_code();
-                    dependencies.entry(rs_type.clone()).or_insert(vec![]);
-
-                    match &**type_annotation {
-                        TypeAnnotation::Object(ObjectTypeAnnotation {
-                            name: alias_name, ..
-                        }) => {
-                            dependencies
-                                .get_mut(&rs_type)
-                                .unwrap()
-                                .push(alias_name.clone());
-                        }
-                        TypeAnnotation::Enum(EnumTypeAnnotation {
-                            name: enum_name, ..
-                        }) => {
-                            dependencies
-                                .get_mut(&rs_type)
-                                .unwrap()
-                                .push(enum_name.clone());
-                        }
-                        _ => (),
-                    }
+            collect_deps(&prop.type_annotation, &mut deps)?;
+        }
+
+        dependencies.insert(alias_spec.name.clone(), deps);
+    }
+
+    // Generated types (nullable, nested array, map and union types)
+    for type_annotation in schema.collect_rs_struct_types() {
+        let mut deps = vec![];
+
+        match type_annotation {
+            TypeAnnotation::Nullable(type_annotation) => collect_deps(type_annotation, &mut deps)?,
+            TypeAnnotation::Map(value_type) => collect_element_deps(value_type, &mut deps)?,
+            TypeAnnotation::Union(UnionTypeAnnotation { variants, .. }) => {
+                for variant in variants {
+                    collect_deps(variant, &mut deps)?;
                 }
-                _ => (),
             }
+            _ => collect_deps(type_annotation, &mut deps)?,
         }
+
+        dependencies.insert(type_annotation.as_rs_generated_struct_name()?, deps);
     }
 
     fn visit(
@@ -135,4 +170,15 @@ mod tests {
         assert_eq!(indent_str("Hello\nWorld", 2), "  Hello\n  World");
         assert_eq!(indent_str("Hello\nWorld", 4), "    Hello\n    World");
     }
+
+    #[test]
+    fn test_cxx_string_literal() {
+        assert_eq!(cxx_string_literal("Hello"), r#""Hello""#);
+        assert_eq!(
+            cxx_string_literal(r#"Use "bar" \ baz"#),
+            r#""Use \"bar\" \\ baz""#
+        );
+        assert_eq!(cxx_string_literal("a\nb\u{1f}c"), r#""a\nb\037c""#);
+        assert_eq!(cxx_string_literal("→ 🦀"), "\"→ 🦀\"");
+    }
 }
@@ -30,6 +30,7 @@ pub fn load_config(project_root: &Path) -> Result<CompleteConfig, anyhow::Error>
         project: config.project,
         android: config.android,
         ios: config.ios,
+        thread_pool: config.thread_pool,
         source_dir,
     })
 }
@@ -82,5 +83,77 @@ fn validate_config(config: &Config) -> Result<(), anyhow::Error> {
         ));
     }
 
+    if let Some(name) = &config.thread_pool.name {
+        if name.is_empty() || !name.chars().all(is_thread_name_char) {
+            anyhow::bail!(format!(
+                "Invalid thread pool name: {} (Expected: alphanumeric characters, `_` or `-`)",
+                name
+            ));
+        }
+    }
+
     Ok(())
 }
+
+fn is_thread_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '-'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_config(thread_pool: &str) -> Config {
+        toml::from_str(&format!(
+            r#"
+            [project]
+            name = "test-module"
+            source_dir = "crates"
+
+            [android]
+            package_name = "rs.craby.testmodule"
+
+            [ios]
+
+            {thread_pool}
+            "#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_validate_thread_pool_name() {
+        let cases = [
+            "",
+            "[thread_pool]\nsize = 4",
+            "[thread_pool]\nname = \"worker\"",
+            "[thread_pool]\nname = \"my_pool-1\"",
+        ];
+
+        for thread_pool in cases {
+            assert!(
+                validate_config(&parse_config(thread_pool)).is_ok(),
+                "{thread_pool}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_thread_pool_name() {
+        let cases = [
+            "[thread_pool]\nname = \"\"",
+            "[thread_pool]\nname = \"my pool\"",
+            "[thread_pool]\nname = \"pool.1\"",
+            "[thread_pool]\nname = \"pool/1\"",
+            "[thread_pool]\nname = \"풀\"",
+        ];
+
+        for thread_pool in cases {
+            let err = validate_config(&parse_config(thread_pool)).unwrap_err();
+            assert!(
+                err.to_string().starts_with("Invalid thread pool name: "),
+                "{thread_pool}"
+            );
+        }
+    }
+}
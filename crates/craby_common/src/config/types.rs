@@ -23,12 +23,79 @@ pub struct Config {
     pub project: ProjectConfig,
     pub android: AndroidConfig,
     pub ios: IosConfig,
+    #[serde(default)]
+    pub thread_pool: ThreadPoolConfig,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ProjectConfig {
     pub name: String,
     pub source_dir: String,
+    #[serde(default)]
+    pub panic: PanicStrategy,
+}
+
+/// Panic strategy of the Rust library (same as the `panic` option of the Cargo profile)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PanicStrategy {
+    /// Panics are caught by `catch_unwind` and thrown to JavaScript
+    #[default]
+    Unwind,
+    /// Errors are propagated with `Result`, and panics are reported by the panic hook before aborting
+    Abort,
+}
+
+/// Worker thread pool of the Promise and AsyncIterable methods (per module instance)
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct ThreadPoolConfig {
+    #[serde(default)]
+    pub size: ThreadPoolSize,
+    /// Thread name prefix of the workers (default: the module name)
+    pub name: Option<String>,
+}
+
+/// Number of the worker threads (`"auto"` or a positive integer)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(try_from = "ThreadPoolSizeValue", into = "ThreadPoolSizeValue")]
+pub enum ThreadPoolSize {
+    /// Number of the hardware threads (`std::thread::hardware_concurrency`)
+    Auto,
+    Fixed(usize),
+}
+
+impl Default for ThreadPoolSize {
+    fn default() -> Self {
+        ThreadPoolSize::Fixed(10)
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(untagged)]
+enum ThreadPoolSizeValue {
+    Fixed(usize),
+    Named(String),
+}
+
+impl TryFrom<ThreadPoolSizeValue> for ThreadPoolSize {
+    type Error = String;
+
+    fn try_from(value: ThreadPoolSizeValue) -> Result<Self, Self::Error> {
+        match value {
+            ThreadPoolSizeValue::Fixed(size) if size > 0 => Ok(ThreadPoolSize::Fixed(size)),
+            ThreadPoolSizeValue::Named(name) if name == "auto" => Ok(ThreadPoolSize::Auto),
+            _ => Err("thread pool size must be \"auto\" or a positive integer".to_string()),
+        }
+    }
+}
+
+impl From<ThreadPoolSize> for ThreadPoolSizeValue {
+    fn from(size: ThreadPoolSize) -> Self {
+        match size {
+            ThreadPoolSize::Auto => ThreadPoolSizeValue::Named("auto".to_string()),
+            ThreadPoolSize::Fixed(size) => ThreadPoolSizeValue::Fixed(size),
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -49,4 +116,75 @@ pub struct CompleteConfig {
     pub source_dir: PathBuf,
     pub android: AndroidConfig,
     pub ios: IosConfig,
+    pub thread_pool: ThreadPoolConfig,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_config(thread_pool: &str) -> Result<Config, toml::de::Error> {
+        toml::from_str(&format!(
+            r#"
+            [project]
+            name = "test-module"
+            source_dir = "crates"
+
+            [android]
+            package_name = "rs.craby.testmodule"
+
+            [ios]
+
+            {thread_pool}
+            "#
+        ))
+    }
+
+    #[test]
+    fn test_thread_pool_size() {
+        let cases = [
+            ("", ThreadPoolSize::Fixed(10)),
+            ("[thread_pool]", ThreadPoolSize::Fixed(10)),
+            ("[thread_pool]\nsize = \"auto\"", ThreadPoolSize::Auto),
+            ("[thread_pool]\nsize = 1", ThreadPoolSize::Fixed(1)),
+            ("[thread_pool]\nsize = 4", ThreadPoolSize::Fixed(4)),
+        ];
+
+        for (thread_pool, size) in cases {
+            let config = parse_config(thread_pool).unwrap();
+            assert_eq!(config.thread_pool.size, size, "{thread_pool}");
+        }
+    }
+
+    #[test]
+    fn test_invalid_thread_pool_size() {
+        let cases = [
+            "[thread_pool]\nsize = 0",
+            "[thread_pool]\nsize = -1",
+            "[thread_pool]\nsize = 1.5",
+            "[thread_pool]\nsize = \"Auto\"",
+            "[thread_pool]\nsize = \"max\"",
+            "[thread_pool]\nsize = \"\"",
+        ];
+
+        for thread_pool in cases {
+            assert!(parse_config(thread_pool).is_err(), "{thread_pool}");
+        }
+
+        let err = parse_config("[thread_pool]\nsize = 0").unwrap_err();
+        assert!(err
+            .message()
+            .contains("thread pool size must be \"auto\" or a positive integer"));
+    }
+
+    #[test]
+    fn test_thread_pool_size_serialize() {
+        for size in [ThreadPoolSize::Auto, ThreadPoolSize::Fixed(4)] {
+            let config = ThreadPoolConfig { size, name: None };
+            let config = toml::to_string(&config).unwrap();
+            let config = toml::from_str::<ThreadPoolConfig>(&config).unwrap();
+
+            assert_eq!(config.size, size);
+        }
+    }
 }
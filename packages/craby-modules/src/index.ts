@@ -4,6 +4,11 @@ type NativeModule = {};
 
 type Signal<T = void> = (handler: (data: T) => void) => () => void;
 
+/**
+ * Unsigned 64-bit integer (`u64` in Rust)
+ */
+type BigUint64 = bigint;
+
 /**
  * Android JNI initialization workaround
  *
@@ -43,4 +48,4 @@ export const NativeModuleRegistry: NativeModuleRegistry = {
   },
 };
 
-export type { NativeModule, Signal };
+export type { BigUint64, NativeModule, Signal };